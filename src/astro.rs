@@ -0,0 +1,166 @@
+/// Julian Day of the J2000.0 epoch (2000-01-01 12:00:00 TT), the reference point for most astronomical series
+pub const JULIAN_DAY_J2000: f64 = 2451545.0;
+
+/// Number of days in a Julian century, used to express time arguments of astronomical polynomials
+pub const DAYS_PER_JULIAN_CENTURY: f64 = 36525.0;
+
+///
+/// Julian centuries since J2000.0 for a given Julian Day
+///
+pub fn julian_centuries(jd: f64) -> f64 {
+  (jd - JULIAN_DAY_J2000) / DAYS_PER_JULIAN_CENTURY
+}
+
+///
+/// Normalise an angle in degrees to the range 0º <= angle < 360º
+///
+pub(crate) fn normalize_degrees(deg: f64) -> f64 {
+  deg.rem_euclid(360.0)
+}
+
+/// Greenwich Mean Sidereal Time in hours (0 to < 24) for a Julian Day in UT1 (Meeus, Astronomical Algorithms, 12.4)
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let sidereal_hours = gmst(2460258.488768587);
+/// ```
+///
+pub fn gmst(jd_ut1: f64) -> f64 {
+  let t = julian_centuries(jd_ut1);
+  let deg = 280.46061837
+    + 360.98564736629 * (jd_ut1 - JULIAN_DAY_J2000)
+    + 0.000387933 * t * t
+    - t * t * t / 38_710_000.0;
+  normalize_degrees(deg) / 15.0
+}
+
+/// Local Mean Sidereal Time in hours (0 to < 24) for a Julian Day in UT1 and a longitude in degrees
+/// East of Greenwich => positive longitude, West of Greenwich => negative longitude
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Local sidereal time in Mauna Kea, Hawaii (155.47ºW)
+/// let sidereal_hours = lst(2460258.488768587, -155.47);
+/// ```
+///
+pub fn lst(jd_ut1: f64, longitude_deg: f64) -> f64 {
+  (gmst(jd_ut1) + longitude_deg / 15.0).rem_euclid(24.0)
+}
+
+///
+/// Mean obliquity of the ecliptic in degrees (Meeus 22.2)
+///
+pub(crate) fn mean_obliquity_of_ecliptic(t: f64) -> f64 {
+  23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0
+}
+
+///
+/// Geometric mean longitude of the Sun in degrees
+///
+pub(crate) fn sun_mean_longitude(t: f64) -> f64 {
+  normalize_degrees(280.46646 + t * (36000.76983 + t * 0.0003032))
+}
+
+///
+/// Mean anomaly of the Sun in degrees
+///
+pub(crate) fn sun_mean_anomaly(t: f64) -> f64 {
+  357.52911 + t * (35999.05029 - 0.0001537 * t)
+}
+
+///
+/// Eccentricity of the Earth's orbit
+///
+pub(crate) fn earth_orbit_eccentricity(t: f64) -> f64 {
+  0.016708634 - t * (0.000042037 + 0.0000001267 * t)
+}
+
+///
+/// Equation of the centre of the Sun in degrees
+///
+pub(crate) fn sun_equation_of_center(t: f64) -> f64 {
+  let m = sun_mean_anomaly(t).to_radians();
+  m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+    + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+    + (3.0 * m).sin() * 0.000289
+}
+
+///
+/// Geometric (true) longitude of the Sun in degrees, referred to the mean equinox of date
+///
+pub(crate) fn sun_true_longitude(t: f64) -> f64 {
+  sun_mean_longitude(t) + sun_equation_of_center(t)
+}
+
+///
+/// Distance from the Earth to the Sun in astronomical units
+///
+pub(crate) fn sun_distance_au(t: f64) -> f64 {
+  let e = earth_orbit_eccentricity(t);
+  let true_anomaly = (sun_mean_anomaly(t) + sun_equation_of_center(t)).to_radians();
+  1.000001018 * (1.0 - e * e) / (1.0 + e * true_anomaly.cos())
+}
+
+///
+/// Apparent longitude of the Sun in degrees for a time in Julian centuries since J2000.0 (NOAA solar calculator)
+///
+pub(crate) fn sun_apparent_longitude(t: f64) -> f64 {
+  let omega = (125.04 - 1934.136 * t).to_radians();
+  sun_true_longitude(t) - 0.00569 - 0.00478 * omega.sin()
+}
+
+/// Equation of time in minutes for a Julian Day, i.e. apparent solar time minus mean solar time,
+/// using the low-precision solar model of the NOAA solar calculator (accurate to within a few seconds)
+/// Positive values mean a sundial is ahead of the clock, e.g. about +16.4 minutes in early November
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let minutes = equation_of_time(2460258.488768587);
+/// ```
+///
+pub fn equation_of_time(jd: f64) -> f64 {
+  let t = julian_centuries(jd);
+  let l0 = sun_mean_longitude(t).to_radians();
+  let m = sun_mean_anomaly(t).to_radians();
+  let e = earth_orbit_eccentricity(t);
+  let y = (mean_obliquity_of_ecliptic(t).to_radians() / 2.0).tan().powi(2);
+  let eot = y * (2.0 * l0).sin()
+    - 2.0 * e * m.sin()
+    + 4.0 * e * y * m.sin() * (2.0 * l0).cos()
+    - 0.5 * y * y * (4.0 * l0).sin()
+    - 1.25 * e * e * (2.0 * m).sin();
+  eot.to_degrees() * 4.0
+}
+
+///
+/// Julian Day at 00:00:00 UTC of the calendar day that contains the given Julian Day
+///
+pub(crate) fn utc_midnight_jd(jd: f64) -> f64 {
+  (jd - 0.5).floor() + 0.5
+}
+
+/// Julian Day of local apparent noon (solar transit) on the UTC calendar date containing date_jd
+/// for a longitude in degrees (east positive, west negative).
+/// The equation of time is evaluated at the approximate transit and then refined once.
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Solar noon in Greenwich on 2023-11-03
+/// let noon_jd = solar_noon_jd(2460251.5, 0.0);
+/// ```
+///
+pub fn solar_noon_jd(date_jd: f64, longitude_deg: f64) -> f64 {
+  let midnight = utc_midnight_jd(date_jd);
+  let mean_noon = midnight + (720.0 - 4.0 * longitude_deg) / 1440.0;
+  let approx_minutes = 720.0 - 4.0 * longitude_deg - equation_of_time(mean_noon);
+  let minutes = 720.0 - 4.0 * longitude_deg - equation_of_time(midnight + approx_minutes / 1440.0);
+  midnight + minutes / 1440.0
+}
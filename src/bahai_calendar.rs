@@ -0,0 +1,158 @@
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::astro::solar_noon_jd;
+use crate::seasons::march_equinox_jd;
+use crate::is_supported_jd;
+
+/// Years between the Badíʿ era (1 BE began on 21 March 1844) and the Gregorian year in which each Badíʿ year begins
+const BAHAI_YEAR_OFFSET: i64 = 1843;
+
+/// First Badíʿ year (2015 CE) in which Naw-Rúz follows the astronomical rule
+pub const BAHAI_ASTRONOMICAL_START_YEAR: i32 = 172;
+
+/// Longitude of Tehran in degrees
+const TEHRAN_LONGITUDE_DEG: f64 = 51.42;
+
+/// Iran Standard Time offset from UTC in days (UTC+3:30)
+const IRAN_STANDARD_TIME_OFFSET_DAYS: f64 = 3.5 / 24.0;
+
+/// Hour angle of sunset in Tehran (35.69ºN) at the equinox as a fraction of a day
+const TEHRAN_EQUINOX_SUNSET_HOUR_ANGLE_DAYS: f64 = 91.03 / 360.0;
+
+/// Month index used for the intercalary days of Ayyám-i-Há, which fall between Mulk (18) and ʿAlá (19)
+pub const AYYAM_I_HA: u32 = 0;
+
+///
+/// Julian Day Number of Naw-Rúz (1 Bahá) of a Badíʿ year. Before 172 BE this is 21 March.
+/// From 172 BE it is the day on which the March equinox occurs, where days begin at sunset in Tehran
+///
+fn naw_ruz_jdn(year: i64) -> i64 {
+  let gregorian_year = year + BAHAI_YEAR_OFFSET;
+  if year < BAHAI_ASTRONOMICAL_START_YEAR as i64 {
+    return gregorian_to_jdn(gregorian_year, 3, 21);
+  }
+  let equinox = march_equinox_jd(gregorian_year as i32);
+  let local_jdn = jd_to_jdn(equinox + IRAN_STANDARD_TIME_OFFSET_DAYS);
+  let sunset = solar_noon_jd(local_jdn as f64 - 0.5, TEHRAN_LONGITUDE_DEG) + TEHRAN_EQUINOX_SUNSET_HOUR_ANGLE_DAYS;
+  if equinox < sunset { local_jdn } else { local_jdn + 1 }
+}
+
+///
+/// Number of intercalary days of Ayyám-i-Há (4 or 5) in a Badíʿ year
+///
+pub fn bahai_ayyam_i_ha_days(year: i32) -> u32 {
+  (naw_ruz_jdn(year as i64 + 1) - naw_ruz_jdn(year as i64) - 361) as u32
+}
+
+/// Convert a Badíʿ date to the Julian Day at 00:00 UTC of the Gregorian day on which it mostly falls.
+/// Months run from 1 (Bahá) to 19 (ʿAlá) with 19 days each and the Ayyám-i-Há days use month AYYAM_I_HA (0).
+/// Returns None if the month or day is out of range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Naw-Rúz 181 BE fell on 20 March 2024
+/// assert_eq!(bahai_to_julian_day(181, 1, 1), Some(2460389.5));
+/// ```
+///
+pub fn bahai_to_julian_day(year: i32, month: u32, day: u32) -> Option<f64> {
+  let offset = match month {
+    1..=18 if (1..=19).contains(&day) => (month - 1) * 19 + day - 1,
+    AYYAM_I_HA if day >= 1 && day <= bahai_ayyam_i_ha_days(year) => 342 + day - 1,
+    19 if (1..=19).contains(&day) => 342 + bahai_ayyam_i_ha_days(year) + day - 1,
+    _ => return None,
+  };
+  Some((naw_ruz_jdn(year as i64) + offset as i64) as f64 - 0.5)
+}
+
+/// Convert a Julian Day to a Badíʿ date (year, month, day) for the UTC calendar day containing it,
+/// where the Ayyám-i-Há days use month AYYAM_I_HA (0). Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_bahai(2460389.5), Some((181, 1, 1)));
+/// ```
+///
+pub fn julian_day_to_bahai(jd: f64) -> Option<(i32, u32, u32)> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let jdn = jd_to_jdn(jd);
+  let (gregorian_year, _, _) = jdn_to_gregorian(jdn);
+  let mut year = gregorian_year - BAHAI_YEAR_OFFSET;
+  if naw_ruz_jdn(year) > jdn {
+    year -= 1;
+  }
+  let day_of_year = (jdn - naw_ruz_jdn(year)) as u32;
+  let year = i32::try_from(year).ok()?;
+  let ayyam_i_ha_days = bahai_ayyam_i_ha_days(year);
+  let (month, day) = if day_of_year < 342 {
+    (day_of_year / 19 + 1, day_of_year % 19 + 1)
+  } else if day_of_year < 342 + ayyam_i_ha_days {
+    (AYYAM_I_HA, day_of_year - 342 + 1)
+  } else {
+    (19, day_of_year - 342 - ayyam_i_ha_days + 1)
+  };
+  Some((year, month, day))
+}
+
+///
+/// Month of a Badíʿ year by position, from 1 (Bahá) to 20 (ʿAlá) with Ayyám-i-Há as the 19th,
+/// converted to the month numbering of bahai_to_julian_day()
+///
+fn position_to_bahai_month(position: u32) -> Option<u32> {
+  match position {
+    1..=18 => Some(position),
+    19 => Some(AYYAM_I_HA),
+    20 => Some(19),
+    _ => None,
+  }
+}
+
+///
+/// Position from 1 to 20 of a month numbered as in bahai_to_julian_day()
+///
+fn bahai_month_to_position(month: u32) -> u32 {
+  match month {
+    AYYAM_I_HA => 19,
+    19 => 20,
+    _ => month,
+  }
+}
+
+///
+/// Badíʿ calendar, see bahai_to_julian_day(). As with other calendars months are numbered by position in the year,
+/// from 1 (Bahá) to 20 (ʿAlá) with the Ayyám-i-Há days as the 19th month
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BahaiCalendar;
+
+impl Calendar for BahaiCalendar {
+  /// Number of months including Ayyám-i-Há
+  fn months_in_year(&self, _year: i32) -> u32 {
+    20
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    match position_to_bahai_month(month)? {
+      AYYAM_I_HA => Some(bahai_ayyam_i_ha_days(year)),
+      _ => Some(19),
+    }
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    bahai_ayyam_i_ha_days(year) == 5
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    bahai_to_julian_day(year, position_to_bahai_month(month)?, day)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    let (year, month, day) = julian_day_to_bahai(jd)?;
+    Some((year, bahai_month_to_position(month), day))
+  }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeSet;
+use chrono::Weekday;
+use crate::civil::*;
+use crate::weekdays::count_weekday_in_range;
+
+///
+/// Weekdays treated as non-working days. Other combinations can be built with Weekend::new()
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weekend {
+  /// Whether each weekday from Sunday to Saturday is a weekend day, matching the weekday index
+  pub days: [bool; 7],
+}
+
+impl Weekend {
+  /// Saturday and Sunday, as in most of Europe and the Americas
+  pub const SATURDAY_SUNDAY: Weekend = Weekend { days: [true, false, false, false, false, false, true] };
+
+  /// Friday and Saturday, as in much of the Middle East
+  pub const FRIDAY_SATURDAY: Weekend = Weekend { days: [false, false, false, false, false, true, true] };
+
+  pub fn new(weekdays: &[Weekday]) -> Self {
+    let mut days = [false; 7];
+    for weekday in weekdays {
+      days[weekday.num_days_from_sunday() as usize] = true;
+    }
+    Weekend { days }
+  }
+
+  /// Whether the weekday index (Sun = 0 ... Sat = 6) falls on the weekend
+  pub fn contains_index(&self, weekday_index: u8) -> bool {
+    self.days.get(weekday_index as usize).copied().unwrap_or(false)
+  }
+}
+
+impl Default for Weekend {
+  fn default() -> Self {
+    Weekend::SATURDAY_SUNDAY
+  }
+}
+
+/// Number of weekend days from the UTC calendar day containing start_jd up to but excluding the day containing end_jd
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // September 2024
+/// assert_eq!(weekend_days_between(2460554.5, 2460584.5, &Weekend::SATURDAY_SUNDAY), 9);
+/// assert_eq!(weekend_days_between(2460554.5, 2460584.5, &Weekend::FRIDAY_SATURDAY), 8);
+/// ```
+///
+pub fn weekend_days_between(start_jd: f64, end_jd: f64, weekend: &Weekend) -> u64 {
+  [Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat].into_iter()
+    .filter(|weekday| weekend.days[weekday.num_days_from_sunday() as usize])
+    .map(|weekday| count_weekday_in_range(start_jd, end_jd, weekday, 0))
+    .sum()
+}
+
+/// Number of working days from the UTC calendar day containing start_jd up to but excluding the day containing end_jd
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(business_days_between(2460554.5, 2460584.5, &Weekend::SATURDAY_SUNDAY), 21);
+/// ```
+///
+pub fn business_days_between(start_jd: f64, end_jd: f64, weekend: &Weekend) -> u64 {
+  let days = ((end_jd + 0.5).floor() - (start_jd + 0.5).floor()).max(0.0) as u64;
+  days - weekend_days_between(start_jd, end_jd, weekend)
+}
+
+///
+/// Holidays as a set of Julian Day Numbers together with the weekend days, used to decide which UTC calendar days are business days
+///
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HolidayCalendar {
+  pub weekend: Weekend,
+  holidays: BTreeSet<i64>,
+}
+
+impl HolidayCalendar {
+  pub fn new(weekend: Weekend) -> Self {
+    HolidayCalendar { weekend, holidays: BTreeSet::new() }
+  }
+
+  /// Add the UTC calendar day containing a Julian Day as a holiday
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // Christmas Day and Boxing Day 2024
+  /// let calendar = HolidayCalendar::default().with_holiday(2460669.5).with_holiday(2460670.5);
+  /// assert!(calendar.is_holiday(2460669.75));
+  /// ```
+  pub fn with_holiday(mut self, jd: f64) -> Self {
+    self.add_holiday(jd);
+    self
+  }
+
+  /// Add the UTC calendar day containing a Julian Day as a holiday
+  pub fn add_holiday(&mut self, jd: f64) {
+    self.holidays.insert(jd_to_jdn(jd));
+  }
+
+  /// Whether the UTC calendar day containing a Julian Day is a listed holiday
+  pub fn is_holiday(&self, jd: f64) -> bool {
+    self.holidays.contains(&jd_to_jdn(jd))
+  }
+
+  /// Julian Day Numbers of the holidays in chronological order
+  pub fn holiday_jdns(&self) -> impl Iterator<Item = i64> + '_ {
+    self.holidays.iter().copied()
+  }
+}
+
+///
+/// Whether the UTC calendar day containing a Julian Day is neither a weekend day nor a holiday
+///
+pub fn is_business_day(jd: f64, calendar: &HolidayCalendar) -> bool {
+  !calendar.weekend.contains_index(jdn_weekday_index(jd_to_jdn(jd))) && !calendar.is_holiday(jd)
+}
+
+/// Move a Julian Day forward (or backward if negative) by a number of business days, keeping the time of day.
+/// Weekend days and holidays are skipped. The Julian Day is returned unchanged for zero days or if every weekday is a weekend day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let calendar = HolidayCalendar::default().with_holiday(2460669.5).with_holiday(2460670.5);
+/// // T+2 from Monday 23 December 2024 settles on Friday 27 December
+/// assert_eq!(add_business_days(2460667.5, 2, &calendar), 2460671.5);
+/// ```
+///
+pub fn add_business_days(jd: f64, days: i64, calendar: &HolidayCalendar) -> f64 {
+  if calendar.weekend.days.iter().all(|is_weekend| *is_weekend) {
+    return jd;
+  }
+  let step = days.signum() as f64;
+  let mut result = jd;
+  let mut remaining = days.abs();
+  while remaining > 0 {
+    result += step;
+    if is_business_day(result, calendar) {
+      remaining -= 1;
+    }
+  }
+  result
+}
@@ -0,0 +1,190 @@
+use crate::civil::*;
+use crate::julian_calendar::*;
+use crate::normalize_fuzzy_iso_string;
+use crate::ordinal_date::split_ordinal_date;
+use crate::is_supported_jd;
+
+///
+/// Calendar system with dates as (year, month, day), converted to and from Julian Days at 00:00 UTC.
+/// Implemented by all built-in year-month-day calendars and open to third-party calendars,
+/// so that conversions and formatting can be written generically over any calendar
+///
+pub trait Calendar {
+
+  ///
+  /// Number of months in a year, including any intercalary month.
+  /// Months are numbered from 1 to this number in the order they occur in the year
+  ///
+  fn months_in_year(&self, year: i32) -> u32;
+
+  ///
+  /// Number of days in a month, or None if the month does not exist in that year or the year is not supported
+  ///
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32>;
+
+  ///
+  /// Whether a year has an intercalary day or month
+  ///
+  fn is_leap_year(&self, year: i32) -> bool;
+
+  ///
+  /// Julian Day at 00:00 UTC of a date, or None if the date is invalid
+  ///
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64>;
+
+  ///
+  /// Date as (year, month, day) of the UTC calendar day containing a Julian Day, or None if not supported
+  ///
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)>;
+
+  ///
+  /// Number of days in a year, or None if the year is not supported
+  ///
+  fn days_in_year(&self, year: i32) -> Option<u32> {
+    (1..=self.months_in_year(year)).map(|month| self.days_in_month(year, month)).sum()
+  }
+
+  /// Format a Julian Day as YYYY-mm-dd HH:MM:SS in this calendar
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(ProlepticJulian.format_julian_day(2321156.75), Some("1642-12-25 06:00:00".to_string()));
+  /// ```
+  fn format_julian_day(&self, jd: f64) -> Option<String> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let (jdn, secs) = jd_to_jdn_and_seconds(jd);
+    let (year, month, day) = self.julian_day_to_date(jdn as f64)?;
+    Some(format_civil(year as i64, month, day, secs))
+  }
+
+  /// Parse an ISO-8601-like date-time string with the same fuzzy rules as iso_fuzzy_string_to_datetime(),
+  /// reading the date in this calendar, and return its Julian Day or None if it is not a valid date
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(PersianCalendar::default().parse_julian_day("1403-01-01 12:00"), Some(2460390.0));
+  /// // ordinal dates count days from the start of the year in this calendar
+  /// assert_eq!(ProlepticJulian.parse_julian_day("1900-060"), ProlepticJulian.date_to_julian_day(1900, 2, 29));
+  /// ```
+  fn parse_julian_day(&self, dt_str: &str) -> Option<f64> {
+    let (year, month, day, hour, minute, second) = parse_calendar_parts(dt_str, |year, day_of_year| {
+      let (year, month, day) = ordinal_to_calendar_date(self, i32::try_from(year).ok()?, day_of_year)?;
+      Some((year as i64, month, day))
+    })?;
+    if hour > 23 || minute > 59 || second > 59 {
+      return None;
+    }
+    let jd = self.date_to_julian_day(i32::try_from(year).ok()?, month, day)?;
+    Some(jd + (hour * 3600 + minute * 60 + second) as f64 / 86400.0)
+  }
+}
+
+/// Convert a date from one calendar to another, or None if the date is invalid in the source calendar
+/// or not supported by the target calendar
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(convert_calendar_date(&ProlepticGregorian, &CopticCalendar, 2023, 1, 7), Some((1739, 4, 29)));
+/// ```
+///
+pub fn convert_calendar_date(from: &impl Calendar, to: &impl Calendar, year: i32, month: u32, day: u32) -> Option<(i32, u32, u32)> {
+  to.julian_day_to_date(from.date_to_julian_day(year, month, day)?)
+}
+
+///
+/// Date in a calendar of a day of year counted from the first day of month 1, or None if the day is not in that year
+///
+pub(crate) fn ordinal_to_calendar_date<C: Calendar + ?Sized>(calendar: &C, year: i32, day_of_year: u32) -> Option<(i32, u32, u32)> {
+  if day_of_year < 1 || day_of_year > calendar.days_in_year(year)? {
+    return None;
+  }
+  let first_day = calendar.date_to_julian_day(year, 1, 1)?;
+  calendar.julian_day_to_date(first_day + (day_of_year - 1) as f64)
+}
+
+///
+/// Extract (year, month, day, hour, minute, second) from a fuzzy date-time string for a calendar-specific parser,
+/// resolving ordinal dates such as 1403-070 with the calendar's own year lengths rather than Gregorian ones
+///
+pub(crate) fn parse_calendar_parts(dt_str: &str, ordinal_to_date: impl Fn(i64, u32) -> Option<(i64, u32, u32)>) -> Option<(i64, u32, u32, u32, u32, u32)> {
+  match split_ordinal_date(dt_str) {
+    Some((year, day_of_year, time_part)) => {
+      let (year, month, day) = ordinal_to_date(year, day_of_year)?;
+      let (_, _, _, hour, minute, second) = parse_civil_parts(&normalize_fuzzy_iso_string(&format!("2000-01-01{}", time_part)))?;
+      Some((year, month, day, hour, minute, second))
+    }
+    None => parse_civil_parts(&normalize_fuzzy_iso_string(dt_str)),
+  }
+}
+
+///
+/// Proleptic Gregorian calendar with astronomical year numbering
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProlepticGregorian;
+
+impl Calendar for ProlepticGregorian {
+  fn months_in_year(&self, _year: i32) -> u32 {
+    12
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    (1..=12).contains(&month).then(|| month_length(month, is_gregorian_leap_year(year as i64)))
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    is_gregorian_leap_year(year as i64)
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    let month_days = self.days_in_month(year, month)?;
+    (day >= 1 && day <= month_days).then(|| gregorian_to_jdn(year as i64, month, day) as f64 - 0.5)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let (year, month, day) = jdn_to_gregorian(jd_to_jdn(jd));
+    Some((i32::try_from(year).ok()?, month, day))
+  }
+}
+
+///
+/// Proleptic Julian calendar with astronomical year numbering
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProlepticJulian;
+
+impl Calendar for ProlepticJulian {
+  fn months_in_year(&self, _year: i32) -> u32 {
+    12
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    (1..=12).contains(&month).then(|| month_length(month, is_julian_leap_year(year)))
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    is_julian_leap_year(year)
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    julian_calendar_to_julian_day(year, month, day)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    julian_day_to_julian_calendar(jd)
+  }
+}
@@ -0,0 +1,193 @@
+use chrono::format::ParseErrorKind;
+use chrono::NaiveDateTime;
+use crate::*;
+use crate::rounding::julian_day_to_unix_units_wide;
+
+///
+/// Errors from checked Julian Day conversions
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JulianDayError {
+  /// The result does not fit in the target integer type
+  Overflow,
+  /// The value is too large for an f64 Julian Day to resolve the input's unit, so a round trip would not be exact
+  PrecisionLoss,
+  /// The Julian Day is NaN or infinite
+  NonFinite,
+  /// The instant is outside the range of the target date-time type
+  OutOfRange,
+  /// The input string could not be read as a date-time
+  InvalidFormat,
+}
+
+/// Whether a Julian Day is a usable number, i.e. neither NaN nor infinite.
+/// Functions returning Option or Result reject non-finite Julian Days, while functions returning plain numbers cannot signal them
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert!(is_finite_jd(2460558.5));
+/// assert!(!is_finite_jd(f64::NAN));
+/// ```
+///
+pub fn is_finite_jd(jd: f64) -> bool {
+  jd.is_finite()
+}
+
+///
+/// Convert a Julian Day to whole units since the unix epoch, or an overflow error outside the i64 range
+///
+fn checked_julian_day_to_unix_units(jd: f64, units_per_day: i64) -> Result<i64, JulianDayError> {
+  if !is_finite_jd(jd) {
+    return Err(JulianDayError::NonFinite);
+  }
+  i64::try_from(julian_day_to_unix_units_wide(jd, units_per_day, RoundingMode::HalfEven)).map_err(|_| JulianDayError::Overflow)
+}
+
+/// Convert a Julian Day to a unix timestamp in milliseconds as julian_day_to_unix_millis() does,
+/// but return an overflow error instead of saturating outside the i64 range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(checked_julian_day_to_unix_millis(2460558.25), Ok(1_725_472_800_000));
+/// assert_eq!(checked_julian_day_to_unix_millis(2e11), Err(JulianDayError::Overflow));
+/// ```
+///
+pub fn checked_julian_day_to_unix_millis(jd: f64) -> Result<i64, JulianDayError> {
+  checked_julian_day_to_unix_units(jd, 86_400_000)
+}
+
+///
+/// Convert a Julian Day to a unix timestamp in microseconds, or an overflow error outside the i64 range
+///
+pub fn checked_julian_day_to_unix_micros(jd: f64) -> Result<i64, JulianDayError> {
+  checked_julian_day_to_unix_units(jd, 86_400_000_000)
+}
+
+///
+/// Convert a Julian Day to a unix timestamp in seconds, floored as julian_day_to_unixtime() does, or an overflow error outside the i64 range
+///
+pub fn checked_julian_day_to_unixtime(jd: f64) -> Result<i64, JulianDayError> {
+  if !is_finite_jd(jd) {
+    return Err(JulianDayError::NonFinite);
+  }
+  i64::try_from(julian_day_to_unix_units_wide(jd, 86_400_000, RoundingMode::HalfEven).div_euclid(1000)).map_err(|_| JulianDayError::Overflow)
+}
+
+/// Convert a unix timestamp in milliseconds to a Julian Day, or a precision error if the Julian Day
+/// cannot be converted back to the same millisecond, as happens beyond JD 4194304 (about 6770 CE)
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(checked_unix_millis_to_julian_day(1_725_472_800_000), Ok(2460558.25));
+/// assert_eq!(checked_unix_millis_to_julian_day(i64::MAX), Err(JulianDayError::PrecisionLoss));
+/// ```
+///
+pub fn checked_unix_millis_to_julian_day(ms: i64) -> Result<f64, JulianDayError> {
+  let jd = unix_millis_to_julian_day(ms);
+  if checked_julian_day_to_unix_millis(jd) == Ok(ms) {
+    Ok(jd)
+  } else {
+    Err(JulianDayError::PrecisionLoss)
+  }
+}
+
+/// Convert a unix timestamp in seconds to a Julian Day, or a precision error if the Julian Day cannot be converted back to the same second
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(checked_unixtime_to_julian_day(1_725_472_800), Ok(2460558.25));
+/// assert_eq!(checked_unixtime_to_julian_day(i64::MAX), Err(JulianDayError::PrecisionLoss));
+/// ```
+///
+pub fn checked_unixtime_to_julian_day(ts: i64) -> Result<f64, JulianDayError> {
+  let jd = unixtime_to_julian_day(ts);
+  if checked_julian_day_to_unixtime(jd) == Ok(ts) {
+    Ok(jd)
+  } else {
+    Err(JulianDayError::PrecisionLoss)
+  }
+}
+
+/// Convert a Julian Day to a chrono::NaiveDateTime as julian_day_to_datetime() does, with the reason for failure
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(checked_julian_day_to_datetime(f64::NAN), Err(JulianDayError::NonFinite));
+/// assert_eq!(checked_julian_day_to_datetime(1e12), Err(JulianDayError::OutOfRange));
+/// ```
+///
+pub fn checked_julian_day_to_datetime(jd: f64) -> Result<NaiveDateTime, JulianDayError> {
+  if !is_finite_jd(jd) {
+    return Err(JulianDayError::NonFinite);
+  }
+  julian_day_to_datetime(jd).map_err(|_| JulianDayError::OutOfRange)
+}
+
+///
+/// Convert an ISO-8601-like string to a Julian Day as datetime_to_julian_day() does, with a JulianDayError on failure
+///
+pub fn checked_datetime_to_julian_day(dt_str: &str) -> Result<f64, JulianDayError> {
+  fuzzy_string_to_julian_day(dt_str)
+}
+
+///
+/// Alias of checked_unixtime_to_julian_day()
+///
+pub fn try_unixtime_to_julian_day(ts: i64) -> Result<f64, JulianDayError> {
+  checked_unixtime_to_julian_day(ts)
+}
+
+///
+/// Alias of checked_julian_day_to_datetime()
+///
+pub fn try_julian_day_to_datetime(jd: f64) -> Result<NaiveDateTime, JulianDayError> {
+  checked_julian_day_to_datetime(jd)
+}
+
+///
+/// Alias of checked_datetime_to_julian_day()
+///
+pub fn try_datetime_to_julian_day(dt_str: &str) -> Result<f64, JulianDayError> {
+  checked_datetime_to_julian_day(dt_str)
+}
+
+/// Convert any string accepted by the fuzzy parser straight to a Julian Day, to the millisecond.
+/// Fails with OutOfRange if the date-time is well-formed but cannot be represented and InvalidFormat otherwise, including for blank strings
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(fuzzy_string_to_julian_day("4 Sep 2022 6pm"), Ok(2459827.25));
+/// assert_eq!(fuzzy_string_to_julian_day("2022-02-30"), Err(JulianDayError::OutOfRange));
+/// assert_eq!(fuzzy_string_to_julian_day("soon"), Err(JulianDayError::InvalidFormat));
+/// ```
+///
+pub fn fuzzy_string_to_julian_day(dt_str: &str) -> Result<f64, JulianDayError> {
+  fuzzy_string_to_julian_day_with(dt_str, &FuzzyParseOptions::default())
+}
+
+///
+/// Convert a string to a Julian Day as fuzzy_string_to_julian_day() with parser options
+///
+pub fn fuzzy_string_to_julian_day_with(dt_str: &str, options: &FuzzyParseOptions) -> Result<f64, JulianDayError> {
+  // the legacy normaliser completes a blank string to 2000-01-01
+  if dt_str.trim().is_empty() {
+    return Err(JulianDayError::InvalidFormat);
+  }
+  match iso_fuzzy_string_to_datetime_with(dt_str, options) {
+    Ok(dt) => Ok(unix_millis_to_julian_day(dt.and_utc().timestamp_millis())),
+    Err(error) if error.kind() == ParseErrorKind::OutOfRange => Err(JulianDayError::OutOfRange),
+    Err(_) => Err(JulianDayError::InvalidFormat),
+  }
+}
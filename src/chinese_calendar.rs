@@ -0,0 +1,341 @@
+use std::fmt;
+use crate::astro::*;
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::light_time::tai_minus_utc;
+use crate::moon::{next_new_moon_jd, SYNODIC_MONTH_DAYS};
+use crate::seasons::december_solstice_jd;
+use crate::is_supported_jd;
+
+/// First Chinese year (starting in Gregorian 1900) supported by the conversions
+pub const CHINESE_FIRST_YEAR: i32 = 1900;
+
+/// Last Chinese year (starting in Gregorian 2100) supported by the conversions
+pub const CHINESE_LAST_YEAR: i32 = 2100;
+
+/// Heavenly stems of the sexagenary cycle
+pub const HEAVENLY_STEMS: [&str; 10] = ["Jia", "Yi", "Bing", "Ding", "Wu", "Ji", "Geng", "Xin", "Ren", "Gui"];
+
+/// Earthly branches of the sexagenary cycle
+pub const EARTHLY_BRANCHES: [&str; 12] = ["Zi", "Chou", "Yin", "Mao", "Chen", "Si", "Wu", "Wei", "Shen", "You", "Xu", "Hai"];
+
+/// Zodiac animals matching the earthly branches
+pub const CHINESE_ZODIAC_ANIMALS: [&str; 12] = ["Rat", "Ox", "Tiger", "Rabbit", "Dragon", "Snake", "Horse", "Goat", "Monkey", "Rooster", "Dog", "Pig"];
+
+///
+/// Date in the Chinese lunisolar calendar, where the year is the Gregorian year in which the Chinese year begins
+/// and leap_month marks an intercalary month repeating the number of the preceding month
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChineseDate {
+  pub year: i32,
+  pub month: u32,
+  pub leap_month: bool,
+  pub day: u32,
+}
+
+impl ChineseDate {
+  /// Position of the year in the 60-year sexagenary cycle from 1 (Jiazi) to 60 (Guihai)
+  pub fn cycle_year(&self) -> u8 {
+    ((self.year - 4).rem_euclid(60) + 1) as u8
+  }
+
+  /// Heavenly stem of the year, e.g. "Jia"
+  pub fn stem(&self) -> &'static str {
+    HEAVENLY_STEMS[(self.year - 4).rem_euclid(10) as usize]
+  }
+
+  /// Earthly branch of the year, e.g. "Chen"
+  pub fn branch(&self) -> &'static str {
+    EARTHLY_BRANCHES[(self.year - 4).rem_euclid(12) as usize]
+  }
+
+  /// Zodiac animal of the year, e.g. "Dragon"
+  pub fn zodiac_animal(&self) -> &'static str {
+    CHINESE_ZODIAC_ANIMALS[(self.year - 4).rem_euclid(12) as usize]
+  }
+}
+
+impl fmt::Display for ChineseDate {
+  /// Format as the cyclical year name with the month and day, e.g. "Jia-Chen 1-1" or "Yi-Si 6L-1" for a leap month
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let leap = if self.leap_month { "L" } else { "" };
+    write!(f, "{}-{} {}{}-{}", self.stem(), self.branch(), self.month, leap, self.day)
+  }
+}
+
+///
+/// Offset of Chinese civil time from UTC in days: Beijing mean solar time (116º25'E) before 1929 and UTC+8 afterwards
+///
+fn beijing_offset_days(jd_utc: f64) -> f64 {
+  if jd_utc < 2425611.5 { 116.41667 / 360.0 } else { 8.0 / 24.0 }
+}
+
+///
+/// Approximate difference between Terrestrial Time and UTC in days
+///
+fn tt_minus_utc_days(jd_utc: f64) -> f64 {
+  (tai_minus_utc(jd_utc) + 32.184) / 86400.0
+}
+
+///
+/// Julian Day (UTC) at the start of a day in Chinese civil time
+///
+fn local_midnight_utc(jdn: i64) -> f64 {
+  let midnight = jdn as f64 - 0.5;
+  midnight - beijing_offset_days(midnight)
+}
+
+///
+/// Julian Day Number in Chinese civil time of a moment in UTC
+///
+fn local_jdn(jd_utc: f64) -> i64 {
+  jd_to_jdn(jd_utc + beijing_offset_days(jd_utc))
+}
+
+///
+/// Local day of the first new moon after a moment in UTC
+///
+fn new_moon_after(jd_utc: f64) -> i64 {
+  let dt = tt_minus_utc_days(jd_utc);
+  local_jdn(next_new_moon_jd(jd_utc + dt).expect("new moon search within the supported years") - dt)
+}
+
+///
+/// Local day of the first new moon on or after a local day
+///
+fn new_moon_on_or_after(jdn: i64) -> i64 {
+  new_moon_after(local_midnight_utc(jdn))
+}
+
+///
+/// Local day of the last new moon before a local day
+///
+fn new_moon_before(jdn: i64) -> i64 {
+  let mut new_moon = new_moon_on_or_after(jdn - 31);
+  loop {
+    let next = new_moon_on_or_after(new_moon + 1);
+    if next >= jdn {
+      return new_moon;
+    }
+    new_moon = next;
+  }
+}
+
+///
+/// Local day of the December solstice in a Gregorian year
+///
+fn winter_solstice_jdn(year: i64) -> i64 {
+  let solstice_tt = december_solstice_jd(year as i32);
+  local_jdn(solstice_tt - tt_minus_utc_days(solstice_tt))
+}
+
+///
+/// Local day of the last December solstice on or before a local day
+///
+fn winter_solstice_on_or_before(jdn: i64) -> i64 {
+  let (year, _, _) = jdn_to_gregorian(jdn);
+  let solstice = winter_solstice_jdn(year);
+  if solstice <= jdn { solstice } else { winter_solstice_jdn(year - 1) }
+}
+
+///
+/// Index of the last principal solar term (zhongqi, multiples of 30º in solar longitude) reached at the start of a local day
+///
+fn major_solar_term(jdn: i64) -> i64 {
+  let start = local_midnight_utc(jdn);
+  (normalize_degrees(sun_apparent_longitude(julian_centuries(start + tt_minus_utc_days(start)))) / 30.0).floor() as i64
+}
+
+///
+/// Whether the month starting on a local day contains no principal solar term
+///
+fn has_no_major_term(month_start: i64) -> bool {
+  major_solar_term(month_start) == major_solar_term(new_moon_on_or_after(month_start + 1))
+}
+
+///
+/// Whether a leap month occurs between the month starting on first_month_start and the month starting on month_start, inclusive
+///
+fn has_prior_leap_month(first_month_start: i64, month_start: i64) -> bool {
+  let mut start = month_start;
+  while start >= first_month_start {
+    if has_no_major_term(start) {
+      return true;
+    }
+    start = new_moon_before(start);
+  }
+  false
+}
+
+///
+/// Number of lunations between two month starts
+///
+fn lunations_between(from_jdn: i64, to_jdn: i64) -> i64 {
+  ((to_jdn - from_jdn) as f64 / SYNODIC_MONTH_DAYS).round() as i64
+}
+
+///
+/// Local day of the Chinese new year falling in the sui (solstice year) starting with the winter solstice on a local day.
+/// In a sui of 13 months the first month without a principal solar term is the leap month
+///
+fn new_year_in_sui(solstice: i64) -> i64 {
+  let next_month_11 = new_moon_before(winter_solstice_on_or_before(solstice + 370) + 1);
+  let month_12 = new_moon_on_or_after(solstice + 1);
+  let month_13 = new_moon_on_or_after(month_12 + 1);
+  if lunations_between(month_12, next_month_11) == 12 && (has_no_major_term(month_12) || has_no_major_term(month_13)) {
+    new_moon_on_or_after(month_13 + 1)
+  } else {
+    month_13
+  }
+}
+
+///
+/// Local day of the Chinese new year beginning in a Gregorian year
+///
+fn new_year_jdn(year: i32) -> i64 {
+  new_year_in_sui(winter_solstice_jdn(year as i64 - 1))
+}
+
+///
+/// Local days on which the months of a Chinese year begin, including any leap month
+///
+fn month_start_jdns(year: i32) -> Vec<i64> {
+  let next_new_year = new_year_jdn(year + 1);
+  let mut starts = vec![new_year_jdn(year)];
+  loop {
+    let next = new_moon_on_or_after(starts[starts.len() - 1] + 1);
+    if next >= next_new_year {
+      return starts;
+    }
+    starts.push(next);
+  }
+}
+
+///
+/// Chinese date of a local day, following the rules of the 1645 (Shixian) reform with the true Sun and Moon
+///
+fn jdn_to_chinese(jdn: i64) -> ChineseDate {
+  let solstice = winter_solstice_on_or_before(jdn);
+  let next_solstice = winter_solstice_on_or_before(solstice + 370);
+  let month_12 = new_moon_on_or_after(solstice + 1);
+  let next_month_11 = new_moon_before(next_solstice + 1);
+  let is_leap_sui = lunations_between(month_12, next_month_11) == 12;
+  let month_start = new_moon_before(jdn + 1);
+  let leap_offset = if is_leap_sui && has_prior_leap_month(month_12, month_start) { 1 } else { 0 };
+  let month = ((lunations_between(month_12, month_start) - leap_offset - 1).rem_euclid(12) + 1) as u32;
+  let leap_month = is_leap_sui && has_no_major_term(month_start) && !has_prior_leap_month(month_12, new_moon_before(month_start));
+  let (gregorian_year, _, _) = jdn_to_gregorian(jdn);
+  let year = if jdn < new_year_jdn(gregorian_year as i32) { gregorian_year - 1 } else { gregorian_year } as i32;
+  ChineseDate { year, month, leap_month, day: (jdn - month_start + 1) as u32 }
+}
+
+/// Julian Day at 00:00 UTC of the Chinese new year (1st day of the 1st month) beginning in a Gregorian year,
+/// or None outside the supported years 1900 to 2100. Dates are reckoned in Chinese civil time
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(chinese_new_year_jd(2024), Some(2460350.5)); // 2024-02-10
+/// ```
+///
+pub fn chinese_new_year_jd(year: i32) -> Option<f64> {
+  if !(CHINESE_FIRST_YEAR..=CHINESE_LAST_YEAR).contains(&year) {
+    return None;
+  }
+  Some(new_year_jdn(year) as f64 - 0.5)
+}
+
+/// Convert a Julian Day to a Chinese lunisolar date for the UTC calendar day containing it,
+/// or None outside the supported years 1900 to 2100
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let date = julian_day_to_chinese(2460350.5).unwrap();
+/// assert_eq!((date.year, date.month, date.leap_month, date.day), (2024, 1, false, 1));
+/// assert_eq!(date.zodiac_animal(), "Dragon");
+/// ```
+///
+pub fn julian_day_to_chinese(jd: f64) -> Option<ChineseDate> {
+  let jdn = jd_to_jdn(jd);
+  if jdn < new_year_jdn(CHINESE_FIRST_YEAR) || jdn >= new_year_jdn(CHINESE_LAST_YEAR + 1) {
+    return None;
+  }
+  Some(jdn_to_chinese(jdn))
+}
+
+/// Convert a Chinese lunisolar date to the Julian Day at 00:00 UTC, where leap_month selects the intercalary month.
+/// Returns None if the month does not exist in that year, the day exceeds its 29 or 30 days or the year is not supported
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 1st day of the leap 2nd month in 2023
+/// assert_eq!(chinese_to_julian_day(2023, 2, true, 1), Some(2460025.5)); // 2023-03-22
+/// ```
+///
+pub fn chinese_to_julian_day(year: i32, month: u32, leap_month: bool, day: u32) -> Option<f64> {
+  if !(CHINESE_FIRST_YEAR..=CHINESE_LAST_YEAR).contains(&year) || !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+    return None;
+  }
+  let mut month_start = new_year_jdn(year);
+  for _ in 0..13 {
+    let next_month_start = new_moon_on_or_after(month_start + 1);
+    let date = jdn_to_chinese(month_start);
+    if date.month == month && date.leap_month == leap_month {
+      let jdn = month_start + day as i64 - 1;
+      return if jdn < next_month_start { Some(jdn as f64 - 0.5) } else { None };
+    }
+    month_start = next_month_start;
+  }
+  None
+}
+
+///
+/// Chinese lunisolar calendar for the years 1900 to 2100 with months numbered by their position in the year,
+/// i.e. 1 to 12 or 1 to 13 in years with a leap month. Use julian_day_to_chinese() for traditional month numbers
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChineseCalendar;
+
+impl Calendar for ChineseCalendar {
+  fn months_in_year(&self, year: i32) -> u32 {
+    if (CHINESE_FIRST_YEAR..=CHINESE_LAST_YEAR).contains(&year) { month_start_jdns(year).len() as u32 } else { 0 }
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    if !(CHINESE_FIRST_YEAR..=CHINESE_LAST_YEAR).contains(&year) || month < 1 {
+      return None;
+    }
+    let starts = month_start_jdns(year);
+    let start = *starts.get(month as usize - 1)?;
+    let next = starts.get(month as usize).copied().unwrap_or_else(|| new_year_jdn(year + 1));
+    Some((next - start) as u32)
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    self.months_in_year(year) == 13
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    let month_days = self.days_in_month(year, month)?;
+    if day < 1 || day > month_days {
+      return None;
+    }
+    Some((month_start_jdns(year)[month as usize - 1] + day as i64 - 1) as f64 - 0.5)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let date = julian_day_to_chinese(jd)?;
+    let jdn = jd_to_jdn(jd);
+    let month = month_start_jdns(date.year).iter().filter(|start| **start <= jdn).count() as u32;
+    Some((date.year, month, date.day))
+  }
+}
@@ -0,0 +1,139 @@
+use chrono::{Datelike, NaiveDate};
+
+///
+/// Julian Day Number (the integer day count starting at noon) for a proleptic Gregorian calendar date
+/// with astronomical year numbering (Fliegel & Van Flandern)
+///
+pub(crate) fn gregorian_to_jdn(year: i64, month: u32, day: u32) -> i64 {
+  let a = (14 - month as i64) / 12;
+  let y = year + 4800 - a;
+  let m = month as i64 + 12 * a - 3;
+  day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400) - 32045
+}
+
+///
+/// Julian Day Number for a proleptic Julian calendar date with astronomical year numbering
+///
+pub(crate) fn julian_calendar_to_jdn(year: i64, month: u32, day: u32) -> i64 {
+  let a = (14 - month as i64) / 12;
+  let y = year + 4800 - a;
+  let m = month as i64 + 12 * a - 3;
+  day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083
+}
+
+///
+/// Proleptic Gregorian calendar date with astronomical year numbering for a Julian Day Number (Fliegel & Van Flandern)
+///
+pub(crate) fn jdn_to_gregorian(jdn: i64) -> (i64, u32, u32) {
+  let l = jdn + 68569;
+  let n = (4 * l).div_euclid(146097);
+  let l = l - (146097 * n + 3).div_euclid(4);
+  let i = (4000 * (l + 1)).div_euclid(1461001);
+  let l = l - (1461 * i).div_euclid(4) + 31;
+  let j = (80 * l).div_euclid(2447);
+  let day = l - (2447 * j).div_euclid(80);
+  let l = j.div_euclid(11);
+  let month = j + 2 - 12 * l;
+  let year = 100 * (n - 49) + i + l;
+  (year, month as u32, day as u32)
+}
+
+///
+/// Julian Day Number of the calendar day (starting at midnight UTC) that contains the given Julian Day
+///
+pub(crate) fn jd_to_jdn(jd: f64) -> i64 {
+  (jd + 0.5).floor() as i64
+}
+
+///
+/// Weekday index of a Julian Day Number where Sunday = 0, Monday = 1 and Saturday = 6
+///
+pub(crate) fn jdn_weekday_index(jdn: i64) -> u8 {
+  (jdn + 1).rem_euclid(7) as u8
+}
+
+///
+/// Proleptic Julian calendar date with astronomical year numbering for a Julian Day Number
+///
+pub(crate) fn jdn_to_julian_calendar(jdn: i64) -> (i64, u32, u32) {
+  let c = jdn + 32082;
+  let d = (4 * c + 3).div_euclid(1461);
+  let e = c - (1461 * d).div_euclid(4);
+  let m = (5 * e + 2).div_euclid(153);
+  let day = e - (153 * m + 2).div_euclid(5) + 1;
+  let month = m + 3 - 12 * m.div_euclid(10);
+  let year = d - 4800 + m.div_euclid(10);
+  (year, month as u32, day as u32)
+}
+
+///
+/// Number of days in a month given whether the year is a leap year
+///
+pub(crate) fn month_length(month: u32, is_leap: bool) -> u32 {
+  match month {
+    2 => if is_leap { 29 } else { 28 },
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+/// Julian Day Number of the day before 0001-01-01 (proleptic Gregorian), the base of chrono's day count from the Common Era
+const JDN_BEFORE_COMMON_ERA: i64 = 1721425;
+
+///
+/// Convert a Julian Day Number to a chrono::NaiveDate if within chrono's supported range
+///
+pub(crate) fn jdn_to_naive_date(jdn: i64) -> Option<NaiveDate> {
+  i32::try_from(jdn - JDN_BEFORE_COMMON_ERA).ok().and_then(NaiveDate::from_num_days_from_ce_opt)
+}
+
+///
+/// Julian Day Number of a chrono::NaiveDate
+///
+pub(crate) fn naive_date_to_jdn(date: &NaiveDate) -> i64 {
+  date.num_days_from_ce() as i64 + JDN_BEFORE_COMMON_ERA
+}
+
+///
+/// Split a Julian Day into the Julian Day Number of its UTC calendar day and the seconds elapsed since midnight
+///
+pub(crate) fn jd_to_jdn_and_seconds(jd: f64) -> (i64, u32) {
+  let total_secs = ((jd - 0.5) * 86400.0).round() as i64;
+  (total_secs.div_euclid(86400) + 1, total_secs.rem_euclid(86400) as u32)
+}
+
+///
+/// Extract (year, month, day, hour, minute, second) from a normalised YYYY-mm-dd HH:MM:SS string
+/// with an optional leading minus sign, without validating the ranges of the values
+///
+pub(crate) fn parse_civil_parts(normalized: &str) -> Option<(i64, u32, u32, u32, u32, u32)> {
+  let (sign, unsigned) = match normalized.strip_prefix('-') {
+    Some(rest) => (-1, rest),
+    None => (1, normalized),
+  };
+  let mut parts = unsigned.split([' ', '-', ':']).map(|part| part.trim().parse::<u32>().ok());
+  let year = parts.next()?? as i64 * sign;
+  let mut next = || parts.next().flatten();
+  Some((year, next()?, next()?, next()?, next()?, next()?))
+}
+
+///
+/// Format a civil date and time as YYYY-mm-dd HH:MM:SS with a minus sign for negative astronomical years
+///
+pub(crate) fn format_civil(year: i64, month: u32, day: u32, secs_of_day: u32) -> String {
+  let sign = if year < 0 { "-" } else { "" };
+  format!("{}{:04}-{:02}-{:02} {:02}:{:02}:{:02}", sign, year.abs(), month, day, secs_of_day / 3600, (secs_of_day / 60) % 60, secs_of_day % 60)
+}
+
+///
+/// Leap year rule of the Gregorian calendar with astronomical year numbering
+///
+pub(crate) fn is_gregorian_leap_year(year: i64) -> bool {
+  (year.rem_euclid(4) == 0 && year.rem_euclid(100) != 0) || year.rem_euclid(400) == 0
+}
+
+/// English month names from January to December
+pub(crate) const ENGLISH_MONTH_NAMES: [&str; 12] = [
+  "January", "February", "March", "April", "May", "June",
+  "July", "August", "September", "October", "November", "December",
+];
@@ -0,0 +1,95 @@
+use crate::civil::*;
+
+/// Nanoseconds in a day
+const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+/// Earliest Julian Day handled by julian_day_to_civil(), beyond which an f64 no longer resolves whole days
+pub const MIN_EXTENDED_JD: f64 = -1e15;
+
+/// Latest Julian Day handled by julian_day_to_civil(), beyond which an f64 no longer resolves whole days
+pub const MAX_EXTENDED_JD: f64 = 1e15;
+
+///
+/// Proleptic Gregorian date and UTC time of day with astronomical year numbering, independent of chrono's supported range
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDateTime {
+  pub year: i64,
+  pub month: u32,
+  pub day: u32,
+  pub hour: u32,
+  pub minute: u32,
+  pub second: u32,
+  pub nanosecond: u32,
+}
+
+/// Decompose a Julian Day into proleptic Gregorian date and time fields (Fliegel & Van Flandern) without chrono,
+/// so years beyond chrono's range of about ±262,000 years are supported.
+/// The time of day is rounded to the nearest nanosecond, although a 64-bit Julian Day in the current era
+/// only resolves about 40 microseconds. Returns None for NaN or infinite Julian Days and beyond MIN_EXTENDED_JD..=MAX_EXTENDED_JD
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let civil = julian_day_to_civil(0.0).unwrap();
+/// assert_eq!((civil.year, civil.month, civil.day, civil.hour), (-4713, 11, 24, 12));
+/// ```
+///
+pub fn julian_day_to_civil(jd: f64) -> Option<CivilDateTime> {
+  if !(MIN_EXTENDED_JD..=MAX_EXTENDED_JD).contains(&jd) {
+    return None;
+  }
+  let shifted = jd + 0.5;
+  let mut jdn = shifted.floor() as i64;
+  let mut nanos_of_day = ((shifted - shifted.floor()) * NANOS_PER_DAY as f64).round() as u64;
+  if nanos_of_day >= NANOS_PER_DAY {
+    jdn += 1;
+    nanos_of_day -= NANOS_PER_DAY;
+  }
+  let (year, month, day) = jdn_to_gregorian(jdn);
+  let secs_of_day = (nanos_of_day / 1_000_000_000) as u32;
+  Some(CivilDateTime {
+    year,
+    month,
+    day,
+    hour: secs_of_day / 3600,
+    minute: (secs_of_day / 60) % 60,
+    second: secs_of_day % 60,
+    nanosecond: (nanos_of_day % 1_000_000_000) as u32,
+  })
+}
+
+/// Julian Day for a proleptic Gregorian date and UTC time with astronomical year numbering, computed without chrono
+/// so years beyond chrono's range are supported. Returns None if any field is out of range
+/// or the date is beyond MIN_EXTENDED_JD..=MAX_EXTENDED_JD
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(civil_to_julian_day(2024, 9, 4, 18, 0, 0, 0), Some(2460558.25));
+/// assert_eq!(civil_to_julian_day(-4713, 11, 24, 12, 0, 0, 0), Some(0.0));
+/// assert_eq!(civil_to_julian_day(i64::MAX, 1, 1, 0, 0, 0, 0), None);
+/// ```
+///
+pub fn civil_to_julian_day(year: i64, month: u32, day: u32, hour: u32, minute: u32, second: u32, nanosecond: u32) -> Option<f64> {
+  if !(1..=12).contains(&month) || day < 1 || day > month_length(month, is_gregorian_leap_year(year))
+    || hour > 23 || minute > 59 || second > 59 || nanosecond > 999_999_999 {
+    return None;
+  }
+  // reject years far beyond the extended range before the day count could overflow
+  if year.unsigned_abs() > (MAX_EXTENDED_JD / 365.0) as u64 {
+    return None;
+  }
+  let secs_of_day = (hour * 3600 + minute * 60 + second) as f64 + nanosecond as f64 / 1e9;
+  let jd = gregorian_to_jdn(year, month, day) as f64 - 0.5 + secs_of_day / 86400.0;
+  (MIN_EXTENDED_JD..=MAX_EXTENDED_JD).contains(&jd).then_some(jd)
+}
+
+impl CivilDateTime {
+  /// Julian Day of the date and time, or None if any field is out of range
+  pub fn to_julian_day(&self) -> Option<f64> {
+    civil_to_julian_day(self.year, self.month, self.day, self.hour, self.minute, self.second, self.nanosecond)
+  }
+}
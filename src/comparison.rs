@@ -0,0 +1,155 @@
+use std::cmp::Ordering;
+use crate::rounding::{julian_day_to_unix_units, unix_units_to_julian_day};
+use crate::{is_supported_jd, RoundingMode, TimeUnit};
+
+///
+/// Tolerance for comparing Julian Days, stored in days but constructed from units of time
+/// so that seconds are not mistaken for fractions of a day
+///
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct JdTolerance {
+  days: f64,
+}
+
+impl JdTolerance {
+  pub const MILLISECOND: JdTolerance = JdTolerance { days: 1.0 / 86_400_000.0 };
+
+  pub const SECOND: JdTolerance = JdTolerance { days: 1.0 / 86_400.0 };
+
+  pub const MINUTE: JdTolerance = JdTolerance { days: 1.0 / 1_440.0 };
+
+  pub fn from_secs(secs: f64) -> Self {
+    JdTolerance { days: secs.abs() / 86_400.0 }
+  }
+
+  pub fn from_millis(millis: f64) -> Self {
+    JdTolerance { days: millis.abs() / 86_400_000.0 }
+  }
+
+  pub fn from_days(days: f64) -> Self {
+    JdTolerance { days: days.abs() }
+  }
+
+  /// Tolerance in days
+  pub fn days(&self) -> f64 {
+    self.days
+  }
+
+  /// Tolerance in seconds
+  pub fn secs(&self) -> f64 {
+    self.days * 86_400.0
+  }
+
+  /// Whether two Julian Days are within this tolerance of each other. NaN is never equal to anything
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert!(JdTolerance::SECOND.approx_eq(2460558.25, 2460558.25 + 0.5 / 86400.0));
+  /// assert!(!JdTolerance::MILLISECOND.approx_eq(2460558.25, 2460558.25 + 0.5 / 86400.0));
+  /// ```
+  pub fn approx_eq(&self, a: f64, b: f64) -> bool {
+    (a - b).abs() <= self.days
+  }
+}
+
+impl Default for JdTolerance {
+  fn default() -> Self {
+    JdTolerance::MILLISECOND
+  }
+}
+
+/// Whether two Julian Days are within a number of seconds of each other
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 2024-09-04 18:00:00 and 18:00:02
+/// assert!(jd_approx_eq(2460558.25, 2460558.25 + 2.0 / 86400.0, 5.0));
+/// assert!(!jd_approx_eq(2460558.25, 2460558.25 + 2.0 / 86400.0, 1.0));
+/// ```
+///
+pub fn jd_approx_eq(a: f64, b: f64, tolerance_secs: f64) -> bool {
+  JdTolerance::from_secs(tolerance_secs).approx_eq(a, b)
+}
+
+///
+/// Julian Day with a total order from f64::total_cmp, so it can be sorted and used as a BTreeMap key without
+/// partial_cmp().unwrap() panicking on NaN. NaN sorts after positive infinity, and -0.0 before 0.0
+///
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedJd(pub f64);
+
+impl OrderedJd {
+  pub fn value(&self) -> f64 {
+    self.0
+  }
+}
+
+impl PartialEq for OrderedJd {
+  fn eq(&self, other: &Self) -> bool {
+    self.0.total_cmp(&other.0) == Ordering::Equal
+  }
+}
+
+impl Eq for OrderedJd {}
+
+impl PartialOrd for OrderedJd {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for OrderedJd {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.0.total_cmp(&other.0)
+  }
+}
+
+impl From<f64> for OrderedJd {
+  fn from(jd: f64) -> Self {
+    OrderedJd(jd)
+  }
+}
+
+///
+/// Julian Day quantised to a whole number of units since the unix epoch, implementing Hash and Eq
+/// so instants derived from floats can be deduplicated and used as HashMap keys
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JdKey {
+  units: i64,
+  resolution: TimeUnit,
+}
+
+impl JdKey {
+  /// Key for a Julian Day rounded to the nearest unit of resolution, or None if it is NaN, infinite or unsupported
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let a = JdKey::new(2460558.25, TimeUnit::Millisecond).unwrap();
+  /// let b = JdKey::new(2460558.25 + 1e-10, TimeUnit::Millisecond).unwrap();
+  /// assert_eq!(a, b);
+  /// ```
+  pub fn new(jd: f64, resolution: TimeUnit) -> Option<Self> {
+    is_supported_jd(jd).then(|| JdKey { units: julian_day_to_unix_units(jd, resolution.per_day(), RoundingMode::HalfEven), resolution })
+  }
+
+  /// Whole units of resolution since 1970-01-01 00:00:00 UTC
+  pub fn units(&self) -> i64 {
+    self.units
+  }
+
+  pub fn resolution(&self) -> TimeUnit {
+    self.resolution
+  }
+
+  /// Julian Day of the quantised instant
+  pub fn to_jd(&self) -> f64 {
+    unix_units_to_julian_day(self.units, self.resolution.per_day())
+  }
+}
@@ -0,0 +1,202 @@
+use chrono::NaiveDate;
+use crate::civil::*;
+use crate::is_supported_jd;
+
+///
+/// Month and day of Easter Sunday in the Gregorian calendar (Anonymous Gregorian algorithm)
+///
+fn gregorian_easter_month_day(year: i32) -> (u32, u32) {
+  let a = year.rem_euclid(19);
+  let b = year.div_euclid(100);
+  let c = year.rem_euclid(100);
+  let d = b.div_euclid(4);
+  let e = b.rem_euclid(4);
+  let f = (b + 8).div_euclid(25);
+  let g = (b - f + 1).div_euclid(3);
+  let h = (19 * a + b - d - g + 15).rem_euclid(30);
+  let i = c.div_euclid(4);
+  let k = c.rem_euclid(4);
+  let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+  let m = (a + 11 * h + 22 * l).div_euclid(451);
+  let month = (h + l - 7 * m + 114).div_euclid(31);
+  let day = (h + l - 7 * m + 114).rem_euclid(31) + 1;
+  (month as u32, day as u32)
+}
+
+///
+/// Month and day of Easter Sunday in the Julian calendar (Meeus' Julian algorithm)
+///
+fn julian_easter_month_day(year: i32) -> (u32, u32) {
+  let a = year.rem_euclid(4);
+  let b = year.rem_euclid(7);
+  let c = year.rem_euclid(19);
+  let d = (19 * c + 15) % 30;
+  let e = (2 * a + 4 * b - d + 34) % 7;
+  let month = (d + e + 114) / 31;
+  let day = (d + e + 114) % 31 + 1;
+  (month as u32, day as u32)
+}
+
+/// Julian Day at 00:00 UTC of (Western) Easter Sunday in a given year of the Gregorian calendar
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let jd = easter_jd(2024); // 2460400.5 => 2024-03-31
+/// ```
+///
+pub fn easter_jd(year: i32) -> f64 {
+  let (month, day) = gregorian_easter_month_day(year);
+  gregorian_to_jdn(year as i64, month, day) as f64 - 0.5
+}
+
+///
+/// Date of (Western) Easter Sunday as a chrono::NaiveDate
+///
+pub fn easter_date(year: i32) -> Option<NaiveDate> {
+  let (month, day) = gregorian_easter_month_day(year);
+  NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Julian Day at 00:00 UTC of Orthodox Easter Sunday, computed with the Julian calendar computus.
+/// The Julian Day identifies the same day whichever calendar it is later rendered in
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let jd = orthodox_easter_jd(2024); // 2460435.5 => 2024-05-05 (Gregorian)
+/// ```
+///
+pub fn orthodox_easter_jd(year: i32) -> f64 {
+  let (month, day) = julian_easter_month_day(year);
+  julian_calendar_to_jdn(year as i64, month, day) as f64 - 0.5
+}
+
+///
+/// Date of Orthodox Easter Sunday as a chrono::NaiveDate in the Gregorian calendar
+///
+pub fn orthodox_easter_date(year: i32) -> Option<NaiveDate> {
+  let (month, day) = julian_easter_month_day(year);
+  jdn_to_naive_date(julian_calendar_to_jdn(year as i64, month, day))
+}
+
+/// Golden number (1 to 19), the position of a year in the 19-year Metonic cycle
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(golden_number(2024), 11);
+/// ```
+///
+pub fn golden_number(year: i32) -> u8 {
+  (year.rem_euclid(19) + 1) as u8
+}
+
+/// Gregorian epact (0 to 29), the age of the ecclesiastical moon on 1 January, as used to find the Paschal full moon
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(epact(2024), 19);
+/// ```
+///
+pub fn epact(year: i32) -> u8 {
+  // h is the offset of the Paschal full moon in the Anonymous Gregorian algorithm and falls 23 days after the epact
+  let a = year.rem_euclid(19);
+  let b = year.div_euclid(100);
+  let f = (b + 8).div_euclid(25);
+  let g = (b - f + 1).div_euclid(3);
+  let h = (19 * a + b - b.div_euclid(4) - g + 15).rem_euclid(30);
+  (23 - h).rem_euclid(30) as u8
+}
+
+/// Gregorian dominical letter(s) naming the Sundays of a year, where 1 January is A, 2 January is B and so on.
+/// Leap years have two letters, the first for January and February and the second for the rest of the year
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(dominical_letter(2024), "GF");
+/// assert_eq!(dominical_letter(2025), "E");
+/// ```
+///
+pub fn dominical_letter(year: i32) -> String {
+  let first_weekday = jdn_weekday_index(gregorian_to_jdn(year as i64, 1, 1)) as i32;
+  let first_index = (7 - first_weekday).rem_euclid(7);
+  let letter = |index: i32| (b'A' + index.rem_euclid(7) as u8) as char;
+  if is_gregorian_leap_year(year as i64) {
+    format!("{}{}", letter(first_index), letter(first_index - 1))
+  } else {
+    letter(first_index).to_string()
+  }
+}
+
+/// Roman indiction (1 to 15), the position of a year in the 15-year fiscal cycle used to date medieval charters
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(indiction(2024), 2);
+/// ```
+///
+pub fn indiction(year: i32) -> u8 {
+  ((year + 2).rem_euclid(15) + 1) as u8
+}
+
+///
+/// Position of a year (1 to 28) in the Julian solar cycle after which weekdays recur on the same dates
+///
+pub fn solar_cycle(year: i32) -> u8 {
+  ((year + 8).rem_euclid(28) + 1) as u8
+}
+
+///
+/// Ecclesiastical calendar quantities for a Gregorian year
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Computus {
+  pub year: i32,
+  pub golden_number: u8,
+  pub epact: u8,
+  pub dominical_letter: String,
+  pub indiction: u8,
+  pub solar_cycle: u8,
+}
+
+///
+/// All computus quantities for a Gregorian year
+///
+pub fn computus(year: i32) -> Computus {
+  Computus {
+    year,
+    golden_number: golden_number(year),
+    epact: epact(year),
+    dominical_letter: dominical_letter(year),
+    indiction: indiction(year),
+    solar_cycle: solar_cycle(year),
+  }
+}
+
+/// All computus quantities for the Gregorian year containing a Julian Day, or None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let quantities = computus_at(2460258.488768587).unwrap();
+/// assert_eq!(quantities.year, 2023);
+/// ```
+///
+pub fn computus_at(jd: f64) -> Option<Computus> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let (year, _, _) = jdn_to_gregorian(jd_to_jdn(jd));
+  Some(computus(year as i32))
+}
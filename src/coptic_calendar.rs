@@ -0,0 +1,113 @@
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::is_supported_jd;
+
+/// Julian Day Number of 1 Thout 1 AM, the start of the Era of Martyrs (29 August 284 in the Julian calendar)
+const COPTIC_EPOCH_JDN: i64 = 1825030;
+
+///
+/// Leap year rule shared by the Coptic and Ethiopic calendars: every 4th year, the year before the Julian leap year
+///
+pub(crate) fn is_alexandrian_leap_year(year: i32) -> bool {
+  year.rem_euclid(4) == 3
+}
+
+///
+/// Number of days in one of the twelve 30-day months or the 13th month of 5 or 6 epagomenal days, or None if the month is out of range
+///
+pub(crate) fn alexandrian_month_length(year: i32, month: u32) -> Option<u32> {
+  match month {
+    1..=12 => Some(30),
+    13 => Some(if is_alexandrian_leap_year(year) { 6 } else { 5 }),
+    _ => None,
+  }
+}
+
+///
+/// Julian Day Number of a date in a calendar with twelve 30-day months and 5 or 6 epagomenal days, counted from an epoch
+/// Returns None if the month or day is out of range
+///
+pub(crate) fn alexandrian_to_jdn(epoch_jdn: i64, year: i32, month: u32, day: u32) -> Option<i64> {
+  if day < 1 || day > alexandrian_month_length(year, month)? {
+    return None;
+  }
+  let year = year as i64;
+  Some(epoch_jdn - 1 + 365 * (year - 1) + year.div_euclid(4) + 30 * (month as i64 - 1) + day as i64)
+}
+
+///
+/// Date as (year, month, day) in a calendar with twelve 30-day months and 5 or 6 epagomenal days
+///
+pub(crate) fn jdn_to_alexandrian(epoch_jdn: i64, jdn: i64) -> Option<(i32, u32, u32)> {
+  let year = (4 * (jdn - epoch_jdn) + 1463).div_euclid(1461);
+  let year_start = epoch_jdn - 1 + 365 * (year - 1) + year.div_euclid(4) + 1;
+  let day_of_year = (jdn - year_start) as u32;
+  Some((i32::try_from(year).ok()?, day_of_year / 30 + 1, day_of_year % 30 + 1))
+}
+
+/// Convert a Julian Day to a Coptic date (year, month, day) in the Era of Martyrs (Anno Martyrum),
+/// where months 1 to 12 (Thout to Mesori) have 30 days and month 13 (Pi Kogi Enavot) has 5 or 6 epagomenal days.
+/// Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Coptic Christmas, 29 Koiak 1739 AM, fell on 7 January 2023
+/// assert_eq!(julian_day_to_coptic(2459951.5), Some((1739, 4, 29)));
+/// ```
+///
+pub fn julian_day_to_coptic(jd: f64) -> Option<(i32, u32, u32)> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  jdn_to_alexandrian(COPTIC_EPOCH_JDN, jd_to_jdn(jd))
+}
+
+/// Convert a Coptic date to the Julian Day at 00:00 UTC, or None if the month or day is out of range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(coptic_to_julian_day(1739, 4, 29), Some(2459951.5));
+/// ```
+///
+pub fn coptic_to_julian_day(year: i32, month: u32, day: u32) -> Option<f64> {
+  alexandrian_to_jdn(COPTIC_EPOCH_JDN, year, month, day).map(|jdn| jdn as f64 - 0.5)
+}
+
+///
+/// Whether a Coptic year has 6 epagomenal days
+///
+pub fn is_coptic_leap_year(year: i32) -> bool {
+  is_alexandrian_leap_year(year)
+}
+
+///
+/// Coptic calendar in the Era of Martyrs, see julian_day_to_coptic()
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CopticCalendar;
+
+impl Calendar for CopticCalendar {
+  fn months_in_year(&self, _year: i32) -> u32 {
+    13
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    alexandrian_month_length(year, month)
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    is_alexandrian_leap_year(year)
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    coptic_to_julian_day(year, month, day)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    julian_day_to_coptic(jd)
+  }
+}
@@ -0,0 +1,165 @@
+use chrono::{DateTime, Datelike, Months};
+use crate::{is_supported_jd, julian_day_to_unix_millis, TimeUnit};
+
+/// Signed number of milliseconds from jd_a to jd_b, negative if jd_b is earlier.
+/// Both Julian Days are converted to whole milliseconds first, so the difference is exact to the millisecond
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 2024-09-04 18:00:00 to 18:00:01.5
+/// assert_eq!(millis_between(2460558.25, 2460558.25 + 1.5 / 86400.0), 1500);
+/// ```
+///
+pub fn millis_between(jd_a: f64, jd_b: f64) -> i64 {
+  julian_day_to_unix_millis(jd_b).saturating_sub(julian_day_to_unix_millis(jd_a))
+}
+
+///
+/// Signed number of whole seconds from jd_a to jd_b, truncated toward zero
+///
+pub fn seconds_between(jd_a: f64, jd_b: f64) -> i64 {
+  millis_between(jd_a, jd_b) / 1000
+}
+
+/// Signed number of days, including the fraction, from jd_a to jd_b, computed from the millisecond difference
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(days_between(2460558.25, 2460556.75), -1.5);
+/// ```
+///
+pub fn days_between(jd_a: f64, jd_b: f64) -> f64 {
+  millis_between(jd_a, jd_b) as f64 / 86_400_000.0
+}
+
+/// Julian Day at fraction t of the way from jd_a to jd_b, where 0.0 gives jd_a and 1.0 gives jd_b.
+/// Values of t outside 0 to 1 extrapolate
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(lerp_jd(2460558.0, 2460560.0, 0.25), 2460558.5);
+/// ```
+///
+pub fn lerp_jd(jd_a: f64, jd_b: f64, t: f64) -> f64 {
+  if t == 1.0 {
+    // avoid rounding away from the end point
+    jd_b
+  } else {
+    jd_a + (jd_b - jd_a) * t
+  }
+}
+
+/// Fraction of the way a Julian Day lies from jd_a to jd_b, the inverse of lerp_jd().
+/// Returns None if jd_a and jd_b are equal or any value is NaN, infinite or unsupported
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(fraction_between(2460558.5, 2460558.0, 2460560.0), Some(0.25));
+/// ```
+///
+pub fn fraction_between(jd: f64, jd_a: f64, jd_b: f64) -> Option<f64> {
+  if jd_a == jd_b || ![jd, jd_a, jd_b].into_iter().all(is_supported_jd) {
+    return None;
+  }
+  Some((jd - jd_a) / (jd_b - jd_a))
+}
+
+/// Describe a Julian Day relative to a reference Julian Day, e.g. "3 days ago" or "in 2 hours", to the nearest second.
+/// See format_relative_with_granularity()
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(format_relative(2460555.25, 2460558.5), "3 days ago");
+/// assert_eq!(format_relative(2460558.5 + 2.5 / 24.0, 2460558.5), "in 2 hours");
+/// ```
+///
+pub fn format_relative(jd: f64, reference_jd: f64) -> String {
+  format_relative_with_granularity(jd, reference_jd, TimeUnit::Second)
+}
+
+/// Describe a Julian Day relative to a reference Julian Day in the largest whole unit from days down to the granularity,
+/// truncating the remainder, so 1 day 23 hours ago is "1 day ago". Differences smaller than the granularity are "now"
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let reference_jd = 2460558.5;
+/// assert_eq!(format_relative_with_granularity(reference_jd - 0.5 / 1440.0, reference_jd, TimeUnit::Second), "30 seconds ago");
+/// assert_eq!(format_relative_with_granularity(reference_jd - 0.5 / 1440.0, reference_jd, TimeUnit::Minute), "now");
+/// ```
+///
+pub fn format_relative_with_granularity(jd: f64, reference_jd: f64, granularity: TimeUnit) -> String {
+  let millis = millis_between(reference_jd, jd);
+  let abs_millis = millis.unsigned_abs();
+  let units = [
+    (TimeUnit::Day, "day"),
+    (TimeUnit::Hour, "hour"),
+    (TimeUnit::Minute, "minute"),
+    (TimeUnit::Second, "second"),
+    (TimeUnit::Millisecond, "millisecond"),
+  ];
+  for (unit, name) in units {
+    let unit_millis = (86_400_000 / unit.per_day()) as u64;
+    let count = abs_millis / unit_millis;
+    if count > 0 {
+      let plural = if count == 1 { "" } else { "s" };
+      return if millis < 0 { format!("{} {}{} ago", count, name, plural) } else { format!("in {} {}{}", count, name, plural) };
+    }
+    if unit == granularity {
+      break;
+    }
+  }
+  "now".to_string()
+}
+
+///
+/// Whole years, months and days followed by (hours, minutes, seconds), as returned by calendar_diff()
+///
+pub type CalendarDiff = (u32, u32, u32, (u32, u32, u32));
+
+/// Elapsed time from start_jd to end_jd in UTC as whole calendar years, months and days plus (hours, minutes, seconds),
+/// as used for ages and contract terms. Years and months are counted from the start as in chrono's month arithmetic,
+/// so a month from the 31st ends on the last day of a shorter month: Jan 31 to Feb 29 is 1 month and to Mar 1 is 1 month 1 day,
+/// and Feb 29 to Feb 28 of the next year is 1 year. Milliseconds are truncated.
+/// Returns None if end_jd is before start_jd or either is outside the supported range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let start = datetime_to_julian_day("2020-02-29 09:00").unwrap();
+/// let end = datetime_to_julian_day("2024-09-04 18:30:15").unwrap();
+/// assert_eq!(calendar_diff(start, end), Some((4, 6, 6, (9, 30, 15))));
+/// ```
+///
+pub fn calendar_diff(start_jd: f64, end_jd: f64) -> Option<CalendarDiff> {
+  if !is_supported_jd(start_jd) || !is_supported_jd(end_jd) {
+    return None;
+  }
+  let to_datetime = |jd: f64| DateTime::from_timestamp_millis(julian_day_to_unix_millis(jd)).map(|dt| dt.naive_utc());
+  let start = to_datetime(start_jd)?;
+  let end = to_datetime(end_jd)?;
+  if end < start {
+    return None;
+  }
+  let mut months = (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+  let mut anchor = start.checked_add_months(Months::new(months as u32))?;
+  if anchor > end {
+    months -= 1;
+    anchor = start.checked_add_months(Months::new(months as u32))?;
+  }
+  let secs = (end - anchor).num_seconds() as u32;
+  let hms = (secs / 3600 % 24, secs / 60 % 60, secs % 60);
+  Some((months as u32 / 12, months as u32 % 12, secs / 86400, hms))
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, NaiveDateTime};
+use crate::unix_millis_to_julian_day;
+
+///
+/// Milliseconds since the unix epoch for a GNU date style @ timestamp in seconds such as @1662310800 or @-86400.5,
+/// a bare 10-digit timestamp in seconds or a bare 13-digit timestamp in milliseconds.
+/// Fractions of a second beyond milliseconds are truncated
+///
+fn epoch_string_to_millis(dt: &str) -> Option<i64> {
+  let trimmed = dt.trim();
+  let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+  match trimmed.strip_prefix('@') {
+    Some(secs_str) => {
+      let (sign, unsigned) = match secs_str.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, secs_str.strip_prefix('+').unwrap_or(secs_str)),
+      };
+      let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, "0"));
+      if !is_digits(whole) || !is_digits(fraction) {
+        return None;
+      }
+      let frac_millis: i64 = format!("{:0<3}", &fraction[..fraction.len().min(3)]).parse().ok()?;
+      let millis = whole.parse::<i64>().ok()?.checked_mul(1000)?.checked_add(frac_millis)?;
+      Some(sign * millis)
+    }
+    None if is_digits(trimmed) && trimmed.len() == 10 => trimmed.parse::<i64>().ok().map(|secs| secs * 1000),
+    None if is_digits(trimmed) && trimmed.len() == 13 => trimmed.parse().ok(),
+    None => None,
+  }
+}
+
+/// Convert a unix timestamp string to a Julian Day: @ followed by seconds as in GNU date, e.g. @1662310800,
+/// or bare 10-digit seconds or 13-digit milliseconds as found in logs. Returns None for other strings
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(epoch_string_to_julian_day("@1662314400"), Some(2459827.25));
+/// assert_eq!(epoch_string_to_julian_day("1662314400000"), Some(2459827.25));
+/// ```
+///
+pub fn epoch_string_to_julian_day(dt: &str) -> Option<f64> {
+  epoch_string_to_millis(dt).map(unix_millis_to_julian_day)
+}
+
+///
+/// NaiveDateTime in UTC for a unix timestamp string, see epoch_string_to_julian_day().
+/// Returns Some(None) if the string is a timestamp out of chrono's range
+///
+pub(crate) fn epoch_string_to_datetime(dt: &str) -> Option<Option<NaiveDateTime>> {
+  epoch_string_to_millis(dt).map(|millis| DateTime::from_timestamp_millis(millis).map(|dt| dt.naive_utc()))
+}
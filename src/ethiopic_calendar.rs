@@ -0,0 +1,101 @@
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::coptic_calendar::{alexandrian_month_length, alexandrian_to_jdn, is_alexandrian_leap_year, jdn_to_alexandrian};
+use crate::is_supported_jd;
+
+/// Julian Day Number of 1 Mäskäräm 1 in the Era of Mercy (29 August 8 in the Julian calendar)
+const ETHIOPIC_EPOCH_JDN: i64 = 1724221;
+
+/// Years between the Era of the World (Amete Alem) and the Era of Mercy (Amete Mihret)
+const AMETE_ALEM_OFFSET: i32 = 5500;
+
+///
+/// Eras of the Ethiopian calendar
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EthiopicEra {
+  /// Era of Mercy (Amete Mihret), the civil era in use today
+  AmeteMihret,
+  /// Era of the World (Amete Alem), 5500 years earlier
+  AmeteAlem,
+}
+
+impl EthiopicEra {
+  fn year_offset(&self) -> i32 {
+    match self {
+      EthiopicEra::AmeteMihret => 0,
+      EthiopicEra::AmeteAlem => AMETE_ALEM_OFFSET,
+    }
+  }
+}
+
+/// Convert a Julian Day to an Ethiopian date (year, month, day) in the given era.
+/// The structure matches the Coptic calendar, with twelve 30-day months and a 13th month (Pagumē) of 5 or 6 days.
+/// Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Ethiopian new year 2017 fell on 11 September 2024
+/// assert_eq!(julian_day_to_ethiopic(2460564.5, EthiopicEra::AmeteMihret), Some((2017, 1, 1)));
+/// assert_eq!(julian_day_to_ethiopic(2460564.5, EthiopicEra::AmeteAlem), Some((7517, 1, 1)));
+/// ```
+///
+pub fn julian_day_to_ethiopic(jd: f64, era: EthiopicEra) -> Option<(i32, u32, u32)> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let (year, month, day) = jdn_to_alexandrian(ETHIOPIC_EPOCH_JDN, jd_to_jdn(jd))?;
+  Some((year.checked_add(era.year_offset())?, month, day))
+}
+
+/// Convert an Ethiopian date in the given era to the Julian Day at 00:00 UTC, or None if the month or day is out of range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(ethiopic_to_julian_day(2017, 1, 1, EthiopicEra::AmeteMihret), Some(2460564.5));
+/// ```
+///
+pub fn ethiopic_to_julian_day(year: i32, month: u32, day: u32, era: EthiopicEra) -> Option<f64> {
+  alexandrian_to_jdn(ETHIOPIC_EPOCH_JDN, year.checked_sub(era.year_offset())?, month, day).map(|jdn| jdn as f64 - 0.5)
+}
+
+///
+/// Ethiopian calendar in a given era, see julian_day_to_ethiopic(). The default era is Amete Mihret
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EthiopicCalendar {
+  pub era: EthiopicEra,
+}
+
+impl Default for EthiopicCalendar {
+  fn default() -> Self {
+    EthiopicCalendar { era: EthiopicEra::AmeteMihret }
+  }
+}
+
+impl Calendar for EthiopicCalendar {
+  fn months_in_year(&self, _year: i32) -> u32 {
+    13
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    alexandrian_month_length(year.checked_sub(self.era.year_offset())?, month)
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    // the era offset is a multiple of 4, so wrapping keeps the leap year cycle
+    is_alexandrian_leap_year(year.wrapping_sub(self.era.year_offset()))
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    ethiopic_to_julian_day(year, month, day, self.era)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    julian_day_to_ethiopic(jd, self.era)
+  }
+}
@@ -0,0 +1,64 @@
+use chrono::NaiveDateTime;
+use crate::{is_supported_jd, julian_day_to_datetime};
+
+/// Julian Day of serial 0 in Excel's 1900 date system for serials from 61 (1900-03-01) onwards, i.e. 1899-12-30 00:00
+pub const EXCEL_SERIAL_EPOCH_JD: f64 = 2415018.5;
+
+/// Serial of the nonexistent 1900-02-29, kept by Excel for compatibility with Lotus 1-2-3
+const EXCEL_FALSE_LEAP_DAY: f64 = 60.0;
+
+/// Convert a serial in Excel's default 1900 date system, with days as whole numbers and the time as a fraction, to a Julian Day.
+/// Serials below 60 are one day later than the true count as Excel treats 1900 as a leap year,
+/// and serial 60, the nonexistent 1900-02-29, and negative serials return None
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 2022-09-04 18:00 UTC
+/// assert_eq!(excel_serial_to_julian_day(44808.75), Some(2459827.25));
+/// // 1900-01-01
+/// assert_eq!(excel_serial_to_julian_day(1.0), Some(2415020.5));
+/// assert_eq!(excel_serial_to_julian_day(60.0), None);
+/// ```
+///
+pub fn excel_serial_to_julian_day(serial: f64) -> Option<f64> {
+  if !serial.is_finite() || serial < 0.0 || serial.floor() == EXCEL_FALSE_LEAP_DAY {
+    return None;
+  }
+  let leap_day_correction = if serial < EXCEL_FALSE_LEAP_DAY { 1.0 } else { 0.0 };
+  Some(EXCEL_SERIAL_EPOCH_JD + serial + leap_day_correction)
+}
+
+/// Convert a Julian Day to a serial in Excel's 1900 date system, or None before serial 0 (1899-12-31)
+/// or for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_excel_serial(2459827.25), Some(44808.75));
+/// assert_eq!(julian_day_to_excel_serial(2415079.5), Some(61.0));
+/// ```
+///
+pub fn julian_day_to_excel_serial(jd: f64) -> Option<f64> {
+  let serial = jd - EXCEL_SERIAL_EPOCH_JD;
+  if !is_supported_jd(jd) || serial < 1.0 {
+    return None;
+  }
+  Some(if serial < EXCEL_FALSE_LEAP_DAY + 1.0 { serial - 1.0 } else { serial })
+}
+
+///
+/// NaiveDateTime in UTC for a bare Excel serial from 1 to 80000, optionally with a fraction for the time, e.g. 44808.75.
+/// Returns None for other strings and Some(None) for serial 60
+///
+pub(crate) fn excel_serial_string_to_datetime(dt: &str) -> Option<Option<NaiveDateTime>> {
+  let trimmed = dt.trim();
+  let (whole, fraction) = trimmed.split_once('.').unwrap_or((trimmed, "0"));
+  let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+  if !is_digits(whole) || !is_digits(fraction) || !(1..=80000).contains(&whole.parse::<u32>().ok()?) {
+    return None;
+  }
+  Some(excel_serial_to_julian_day(trimmed.parse().ok()?).and_then(|jd| julian_day_to_datetime(jd).ok()))
+}
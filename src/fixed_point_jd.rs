@@ -0,0 +1,50 @@
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// Microseconds from JD 0 (noon UTC on 24 November 4714 BCE) to 1970-01-01 00:00:00 UTC
+const UNIX_EPOCH_MICROS: i64 = 210_866_760_000_000_000;
+
+///
+/// Instant as a whole number of microseconds since JD 0, for exact equality, ordering and hashing.
+/// Microseconds rather than microdays (86.4 ms) are counted so that unix microsecond timestamps convert without loss.
+/// The i64 range covers about ±292,000 years from JD 0
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedPointJd(pub i64);
+
+impl FixedPointJd {
+  /// Nearest microsecond to a Julian Day, or None if it is not finite or out of range
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(FixedPointJd::from_jd(2460558.5), Some(FixedPointJd(212_592_254_400_000_000)));
+  /// ```
+  pub fn from_jd(jd: f64) -> Option<Self> {
+    let micros = (jd * MICROS_PER_DAY as f64).round();
+    (micros.is_finite() && micros.abs() < i64::MAX as f64).then_some(FixedPointJd(micros as i64))
+  }
+
+  /// Julian Day as a 64-bit float
+  pub fn to_jd(&self) -> f64 {
+    self.0.div_euclid(MICROS_PER_DAY) as f64 + self.0.rem_euclid(MICROS_PER_DAY) as f64 / MICROS_PER_DAY as f64
+  }
+
+  /// Convert a unix timestamp in microseconds, or None on overflow
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let micros = 1_725_451_200_123_456;
+  /// assert_eq!(FixedPointJd::from_unix_micros(micros).and_then(|jd| jd.to_unix_micros()), Some(micros));
+  /// ```
+  pub fn from_unix_micros(micros: i64) -> Option<Self> {
+    micros.checked_add(UNIX_EPOCH_MICROS).map(FixedPointJd)
+  }
+
+  /// Unix timestamp in microseconds, or None on overflow
+  pub fn to_unix_micros(&self) -> Option<i64> {
+    self.0.checked_sub(UNIX_EPOCH_MICROS)
+  }
+}
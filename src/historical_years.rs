@@ -0,0 +1,162 @@
+use crate::civil::*;
+use crate::is_supported_jd;
+
+///
+/// Eras of historical year numbering, which has no year 0: 1 BC is followed by AD 1
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoricalEra {
+  /// BC or BCE, years before the Common Era
+  BeforeChrist,
+  /// AD or CE, years of the Common Era
+  AnnoDomini,
+}
+
+///
+/// Labels for years before the Common Era in era-aware formatting
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraNotation {
+  /// BC, e.g. 44 BC
+  BcAd,
+  /// BCE, e.g. 44 BCE
+  BceCe,
+}
+
+impl EraNotation {
+  /// Label for years before the Common Era
+  pub fn before_label(&self) -> &'static str {
+    match self {
+      EraNotation::BcAd => "BC",
+      EraNotation::BceCe => "BCE",
+    }
+  }
+}
+
+/// Labels recognised in date strings, longest first so that BCE is not read as BC
+const ERA_LABELS: [(&str, HistoricalEra); 4] = [
+  ("BCE", HistoricalEra::BeforeChrist),
+  ("BC", HistoricalEra::BeforeChrist),
+  ("AD", HistoricalEra::AnnoDomini),
+  ("CE", HistoricalEra::AnnoDomini),
+];
+
+/// Convert an astronomical year (with year 0 = 1 BC and -1 = 2 BC) to a historical year and era
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(astronomical_to_historical_year(-43), (44, HistoricalEra::BeforeChrist));
+/// assert_eq!(astronomical_to_historical_year(0), (1, HistoricalEra::BeforeChrist));
+/// ```
+///
+pub fn astronomical_to_historical_year(year: i64) -> (i64, HistoricalEra) {
+  if year > 0 { (year, HistoricalEra::AnnoDomini) } else { (1 - year, HistoricalEra::BeforeChrist) }
+}
+
+/// Convert a historical year and era to an astronomical year, or None for year 0 or negative years
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(historical_to_astronomical_year(44, HistoricalEra::BeforeChrist), Some(-43));
+/// ```
+///
+pub fn historical_to_astronomical_year(year: i64, era: HistoricalEra) -> Option<i64> {
+  match (year > 0, era) {
+    (false, _) => None,
+    (true, HistoricalEra::AnnoDomini) => Some(year),
+    (true, HistoricalEra::BeforeChrist) => Some(1 - year),
+  }
+}
+
+/// Format a Julian Day as YYYY-mm-dd HH:MM:SS in the proleptic Gregorian calendar with historical year numbering,
+/// appending BC to years before the Common Era instead of using a negative astronomical year.
+/// Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_historical_string(1705427.5), Some("0044-03-15 00:00:00 BC".to_string()));
+/// assert_eq!(julian_day_to_historical_string(2460557.5), Some("2024-09-04 00:00:00".to_string()));
+/// ```
+///
+pub fn julian_day_to_historical_string(jd: f64) -> Option<String> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let (jdn, secs) = jd_to_jdn_and_seconds(jd);
+  let (year, month, day) = jdn_to_gregorian(jdn);
+  Some(match astronomical_to_historical_year(year) {
+    (bc_year, HistoricalEra::BeforeChrist) => format!("{} BC", format_civil(bc_year, month, day, secs)),
+    _ => format_civil(year, month, day, secs),
+  })
+}
+
+/// Format a Julian Day as YYYY-mm-dd HH:MM:SS in the proleptic Gregorian calendar with the era label directly after
+/// the year for dates before the Common Era, e.g. "4714 BCE-11-24 12:00:00", instead of a negative astronomical year.
+/// Common Era dates are unlabelled and the output can be read back by the fuzzy ISO parser.
+/// Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(format_julian_day_with_era(0.0, EraNotation::BceCe), Some("4714 BCE-11-24 12:00:00".to_string()));
+/// assert_eq!(format_julian_day_with_era(1705427.5, EraNotation::BcAd), Some("0044 BC-03-15 00:00:00".to_string()));
+/// ```
+///
+pub fn format_julian_day_with_era(jd: f64, notation: EraNotation) -> Option<String> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let (jdn, secs) = jd_to_jdn_and_seconds(jd);
+  let (year, month, day) = jdn_to_gregorian(jdn);
+  Some(match astronomical_to_historical_year(year) {
+    (bc_year, HistoricalEra::BeforeChrist) => {
+      let civil_string = format_civil(bc_year, month, day, secs);
+      let (year_str, rest) = civil_string.split_once('-').unwrap_or_default();
+      format!("{} {}-{}", year_str, notation.before_label(), rest)
+    },
+    _ => format_civil(year, month, day, secs),
+  })
+}
+
+///
+/// Match a whitespace-separated token against the era labels, ignoring case and dots (B.C.),
+/// returning the era and any date remainder directly following the label, e.g. "-11-24" in "BCE-11-24"
+///
+fn match_era_label(token: &str) -> Option<(HistoricalEra, &str)> {
+  let undotted = token.replace('.', "").to_uppercase();
+  if let Some((_, era)) = ERA_LABELS.iter().find(|(label, _)| undotted == *label) {
+    return Some((*era, ""));
+  }
+  let upper = token.to_uppercase();
+  ERA_LABELS.iter().find_map(|(label, era)| {
+    let remainder = token.get(label.len()..)?;
+    (upper.starts_with(label) && remainder.starts_with('-')).then_some((*era, remainder))
+  })
+}
+
+///
+/// Rewrite a date with a historical era label, e.g. "44 BC", "0044-03-15 12:00 BC", "4713 BCE-11-24 12:00" or "AD 1066-10-14",
+/// as a date string with an astronomical year for the fuzzy ISO parser, or None if the string has no era label
+///
+pub(crate) fn historical_to_astronomical_string(dt: &str) -> Option<String> {
+  let tokens: Vec<&str> = dt.split_whitespace().collect();
+  let (index, era, remainder) = tokens.iter().enumerate().find_map(|(index, token)| {
+    match_era_label(token).map(|(era, remainder)| (index, era, remainder))
+  })?;
+  let mut rest = tokens[..index].join(" ") + remainder;
+  if index + 1 < tokens.len() {
+    rest = format!("{} {}", rest, tokens[index + 1..].join(" "));
+  }
+  let rest = rest.trim();
+  let year_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+  let year = historical_to_astronomical_year(rest[..year_end].parse().ok()?, era)?;
+  let sign = if year < 0 { "-" } else { "" };
+  Some(format!("{}{:04}{}", sign, year.abs(), &rest[year_end..]))
+}
@@ -0,0 +1,73 @@
+use chrono::Duration;
+
+/// Milliseconds in a unit of a human-friendly duration, accepting the usual short and long forms
+fn human_unit_millis(unit: &str) -> Option<i64> {
+  let millis = match unit.to_lowercase().as_str() {
+    "ms" | "msec" | "msecs" | "millis" | "millisecond" | "milliseconds" => 1,
+    "s" | "sec" | "secs" | "second" | "seconds" => 1000,
+    "m" | "min" | "mins" | "minute" | "minutes" => 60_000,
+    "h" | "hr" | "hrs" | "hour" | "hours" => 3_600_000,
+    "d" | "day" | "days" => 86_400_000,
+    "w" | "wk" | "wks" | "week" | "weeks" => 604_800_000,
+    _ => return None,
+  };
+  Some(millis)
+}
+
+/// Parse a human-friendly duration such as "3d 4h 30m", "1.5 hours" or "2w1d" to a whole number of milliseconds.
+/// Months and years are not accepted as their length varies
+fn human_duration_to_millis(duration_str: &str) -> Option<i64> {
+  let mut chars = duration_str.trim().chars().peekable();
+  let mut total: i64 = 0;
+  let mut has_term = false;
+  while chars.peek().is_some() {
+    let mut number = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+      number.push(c);
+    }
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    let mut unit = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_alphabetic()) {
+      unit.push(c);
+    }
+    while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+    let value = number.parse::<f64>().ok()?;
+    let millis = (value * human_unit_millis(&unit)? as f64).round();
+    if !millis.is_finite() || millis > i64::MAX as f64 {
+      return None;
+    }
+    total = total.checked_add(millis as i64)?;
+    has_term = true;
+  }
+  if has_term { Some(total) } else { None }
+}
+
+/// Convert a human-friendly duration such as "3d 4h 30m" to fractional days, for use with Julian Day arithmetic.
+/// Units may be abbreviated or spelt out, from ms to weeks, and terms may be separated by spaces or commas.
+/// Returns None for unknown units, including months and years whose length varies
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(human_duration_to_days("1d 12h"), Some(1.5));
+/// assert_eq!(human_duration_to_days("2 weeks, 3.5 days"), Some(17.5));
+/// ```
+///
+pub fn human_duration_to_days(duration_str: &str) -> Option<f64> {
+  human_duration_to_millis(duration_str).map(|millis| millis as f64 / 86_400_000.0)
+}
+
+/// Convert a human-friendly duration such as "3d 4h 30m" to a chrono Duration, exact to the millisecond
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+/// use chrono::Duration;
+///
+/// assert_eq!(human_duration_to_duration("4h 30m 250ms"), Some(Duration::milliseconds(16_200_250)));
+/// ```
+///
+pub fn human_duration_to_duration(duration_str: &str) -> Option<Duration> {
+  human_duration_to_millis(duration_str).and_then(Duration::try_milliseconds)
+}
@@ -0,0 +1,256 @@
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::julian_calendar::is_julian_leap_year;
+use crate::calendar::{ordinal_to_calendar_date, parse_calendar_parts};
+use crate::is_supported_jd;
+
+///
+/// Errors when converting a date in a hybrid Julian/Gregorian calendar
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HybridDateError {
+  /// The input could not be read as a date
+  InvalidFormat,
+  /// The month, day or time is out of range for the calendar in force on that date
+  InvalidDate,
+  /// The date falls in the days skipped when the Gregorian calendar was adopted
+  SkippedByReform,
+}
+
+///
+/// Hybrid civil calendar that follows the Julian calendar before a configurable reform date and the Gregorian calendar
+/// from that date, as different countries adopted the Gregorian reform at different times
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GregorianReform {
+  first_gregorian_jdn: i64,
+}
+
+impl GregorianReform {
+  /// Papal reform adopted in Rome, Spain, Portugal and Poland: Thursday 4 October 1582 was followed by Friday 15 October 1582
+  pub const ROME: GregorianReform = GregorianReform { first_gregorian_jdn: 2299161 };
+
+  /// Reform adopted in Great Britain and its colonies: Wednesday 2 September 1752 was followed by Thursday 14 September 1752
+  pub const BRITAIN: GregorianReform = GregorianReform { first_gregorian_jdn: 2361222 };
+
+  /// Reform adopted in Russia: 31 January 1918 was followed by 14 February 1918
+  pub const RUSSIA: GregorianReform = GregorianReform { first_gregorian_jdn: 2421639 };
+
+  /// Construct a hybrid calendar from the first day of the Gregorian calendar
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // France adopted the Gregorian calendar on 20 December 1582
+  /// let france = GregorianReform::new(1582, 12, 20).unwrap();
+  /// assert_eq!(france.gap_days(), 10);
+  /// ```
+  pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+    if (1..=12).contains(&month) && day >= 1 && day <= month_length(month, is_gregorian_leap_year(year as i64)) {
+      Some(GregorianReform { first_gregorian_jdn: gregorian_to_jdn(year as i64, month, day) })
+    } else {
+      None
+    }
+  }
+
+  /// Julian Day at 00:00 UTC of the first day of the Gregorian calendar
+  pub fn reform_jd(&self) -> f64 {
+    self.first_gregorian_jdn as f64 - 0.5
+  }
+
+  /// Number of calendar dates skipped at the reform, e.g. 10 in 1582 and 11 in 1752
+  pub fn gap_days(&self) -> i64 {
+    let (year, month, day) = jdn_to_gregorian(self.first_gregorian_jdn);
+    julian_calendar_to_jdn(year, month, day) - self.first_gregorian_jdn
+  }
+
+  /// Whether a Julian Day falls on or after the reform, i.e. in the Gregorian part of the hybrid calendar
+  pub fn is_gregorian(&self, jd: f64) -> bool {
+    jd_to_jdn(jd) >= self.first_gregorian_jdn
+  }
+
+  /// Civil date as (year, month, day) with astronomical year numbering for the UTC calendar day containing a Julian Day,
+  /// or None for NaN, infinite or unsupported Julian Days
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let jd = 2361221.5; // the first day of the Gregorian calendar in Britain
+  /// assert_eq!(GregorianReform::BRITAIN.julian_day_to_date(jd), Some((1752, 9, 14)));
+  /// assert_eq!(GregorianReform::BRITAIN.julian_day_to_date(jd - 1.0), Some((1752, 9, 2)));
+  /// ```
+  pub fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let (year, month, day) = self.jdn_to_date(jd_to_jdn(jd));
+    Some((i32::try_from(year).ok()?, month, day))
+  }
+
+  /// Julian Day at 00:00 UTC for a civil date in the hybrid calendar with astronomical year numbering.
+  /// Dates skipped by the reform return an explicit error rather than being shifted
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(GregorianReform::ROME.date_to_julian_day(1582, 10, 4), Ok(2299159.5));
+  /// assert_eq!(GregorianReform::ROME.date_to_julian_day(1582, 10, 10), Err(HybridDateError::SkippedByReform));
+  /// ```
+  pub fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Result<f64, HybridDateError> {
+    self.date_to_jdn(year as i64, month, day).map(|jdn| jdn as f64 - 0.5)
+  }
+
+  /// Format a Julian Day as YYYY-mm-dd HH:MM:SS in the hybrid calendar, or None for NaN, infinite or unsupported Julian Days
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(GregorianReform::BRITAIN.format(2361220.75), Some("1752-09-02 06:00:00".to_string()));
+  /// ```
+  pub fn format(&self, jd: f64) -> Option<String> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let (jdn, secs) = jd_to_jdn_and_seconds(jd);
+    let (year, month, day) = self.jdn_to_date(jdn);
+    Some(format_civil(year, month, day, secs))
+  }
+
+  /// Parse an ISO-8601-like date-time string with the same fuzzy rules as iso_fuzzy_string_to_datetime(),
+  /// interpreting the date in the hybrid calendar and returning a Julian Day
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // 1700-02-29 only exists in the Julian calendar still used in Britain
+  /// assert_eq!(GregorianReform::BRITAIN.parse("1700-02-29 12:00"), Ok(2342042.0));
+  /// assert_eq!(GregorianReform::ROME.parse("1700-02-29 12:00"), Err(HybridDateError::InvalidDate));
+  /// ```
+  pub fn parse(&self, dt_str: &str) -> Result<f64, HybridDateError> {
+    let (year, month, day, hour, minute, second) = parse_calendar_parts(dt_str, |year, day_of_year| {
+      let (year, month, day) = ordinal_to_calendar_date(self, i32::try_from(year).ok()?, day_of_year)?;
+      Some((year as i64, month, day))
+    }).ok_or(HybridDateError::InvalidFormat)?;
+    if hour > 23 || minute > 59 || second > 59 {
+      return Err(HybridDateError::InvalidDate);
+    }
+    let jdn = self.date_to_jdn(year, month, day)?;
+    Ok(jdn as f64 - 0.5 + (hour * 3600 + minute * 60 + second) as f64 / 86400.0)
+  }
+
+  /// Format a Julian Day as a dual-dated string in the British convention, giving the Old Style (Julian) and
+  /// New Style (Gregorian) day for dates before the reform, e.g. "11/22 February 1731/32".
+  /// Between 1 January and 24 March the year is also dual-dated, because the Old Style year began on 25 March (Lady Day).
+  /// Dates on or after the reform are rendered in the Gregorian calendar only, e.g. "14 September 1752".
+  /// Returns None for NaN, infinite or unsupported Julian Days
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // George Washington's birthday
+  /// let jd = GregorianReform::BRITAIN.parse("1732-02-11").unwrap();
+  /// assert_eq!(GregorianReform::BRITAIN.format_dual(jd), Some("11/22 February 1731/32".to_string()));
+  /// ```
+  pub fn format_dual(&self, jd: f64) -> Option<String> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let jdn = jd_to_jdn(jd);
+    let (ns_year, ns_month, ns_day) = jdn_to_gregorian(jdn);
+    let ns_month_name = ENGLISH_MONTH_NAMES[ns_month as usize - 1];
+    if jdn >= self.first_gregorian_jdn {
+      return Some(format!("{} {} {}", ns_day, ns_month_name, ns_year));
+    }
+    let (os_year, os_month, os_day) = jdn_to_julian_calendar(jdn);
+    let os_month_name = ENGLISH_MONTH_NAMES[os_month as usize - 1];
+    let year_str = if os_month < 3 || (os_month == 3 && os_day < 25) {
+      dual_year(os_year - 1, os_year)
+    } else {
+      os_year.to_string()
+    };
+    Some(if os_year != ns_year {
+      format!("{} {} {}/{} {} {}", os_day, os_month_name, year_str, ns_day, ns_month_name, ns_year)
+    } else if os_month != ns_month {
+      format!("{} {}/{} {} {}", os_day, os_month_name, ns_day, ns_month_name, year_str)
+    } else {
+      format!("{}/{} {} {}", os_day, ns_day, os_month_name, year_str)
+    })
+  }
+
+  fn jdn_to_date(&self, jdn: i64) -> (i64, u32, u32) {
+    if jdn >= self.first_gregorian_jdn {
+      jdn_to_gregorian(jdn)
+    } else {
+      jdn_to_julian_calendar(jdn)
+    }
+  }
+
+  fn date_to_jdn(&self, year: i64, month: u32, day: u32) -> Result<i64, HybridDateError> {
+    if !(1..=12).contains(&month) || day < 1 {
+      return Err(HybridDateError::InvalidDate);
+    }
+    let (reform_year, reform_month, reform_day) = jdn_to_gregorian(self.first_gregorian_jdn);
+    if (year, month, day) >= (reform_year, reform_month, reform_day) {
+      if day > month_length(month, is_gregorian_leap_year(year)) {
+        return Err(HybridDateError::InvalidDate);
+      }
+      return Ok(gregorian_to_jdn(year, month, day));
+    }
+    if day > month_length(month, is_julian_leap_year(year as i32)) {
+      return Err(HybridDateError::InvalidDate);
+    }
+    let jdn = julian_calendar_to_jdn(year, month, day);
+    if jdn >= self.first_gregorian_jdn {
+      Err(HybridDateError::SkippedByReform)
+    } else {
+      Ok(jdn)
+    }
+  }
+}
+
+///
+/// Render a pair of consecutive years as 1731/32, keeping all digits of the second year when the century changes, e.g. 1699/1700
+///
+fn dual_year(first: i64, second: i64) -> String {
+  if first.div_euclid(100) == second.div_euclid(100) {
+    format!("{}/{:02}", first, second.rem_euclid(100))
+  } else {
+    format!("{}/{}", first, second)
+  }
+}
+
+impl Default for GregorianReform {
+  /// The original papal reform of 1582
+  fn default() -> Self {
+    GregorianReform::ROME
+  }
+}
+
+impl Calendar for GregorianReform {
+  fn months_in_year(&self, _year: i32) -> u32 {
+    12
+  }
+
+  /// Number of dates in a month, excluding any days skipped by the reform
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    (1..=12).contains(&month).then(|| (1..=31).filter(|day| self.date_to_jdn(year as i64, month, *day).is_ok()).count() as u32)
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    Calendar::days_in_month(self, year, 2) == Some(29)
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    GregorianReform::date_to_julian_day(self, year, month, day).ok()
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    GregorianReform::julian_day_to_date(self, jd)
+  }
+}
@@ -0,0 +1,221 @@
+use chrono::{DateTime, Duration, NaiveDateTime};
+use crate::{is_supported_jd, iso_fuzzy_string_to_datetime, julian_day_to_unix_millis, JulianDay};
+use crate::iso_duration::IsoDuration;
+use crate::rounding::unix_units_to_julian_day;
+
+///
+/// Half-open interval of Julian Days from start (inclusive) to end (exclusive)
+///
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct JdInterval {
+  pub start: f64,
+  pub end: f64,
+}
+
+impl JdInterval {
+  /// Returns None if either bound is NaN, infinite or unsupported or the end is before the start
+  pub fn new(start: f64, end: f64) -> Option<Self> {
+    if is_supported_jd(start) && is_supported_jd(end) && start <= end {
+      Some(JdInterval { start, end })
+    } else {
+      None
+    }
+  }
+
+  /// Whether the interval contains no instants, i.e. start equals end
+  pub fn is_empty(&self) -> bool {
+    self.start >= self.end
+  }
+
+  /// Whether a Julian Day is on or after the start and before the end
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let interval = JdInterval::new(2460558.5, 2460559.5).unwrap();
+  /// assert!(interval.contains(2460558.5));
+  /// assert!(!interval.contains(2460559.5));
+  /// ```
+  pub fn contains(&self, jd: f64) -> bool {
+    jd >= self.start && jd < self.end
+  }
+
+  /// Length of the interval in days
+  pub fn duration_days(&self) -> f64 {
+    self.end - self.start
+  }
+
+  /// Whether the two intervals share at least one instant. Intervals that only touch do not overlap
+  pub fn overlaps(&self, other: &JdInterval) -> bool {
+    self.start < other.end && other.start < self.end
+  }
+
+  /// The instants in both intervals, or None if they do not overlap
+  pub fn intersect(&self, other: &JdInterval) -> Option<JdInterval> {
+    if self.overlaps(other) {
+      Some(JdInterval { start: self.start.max(other.start), end: self.end.min(other.end) })
+    } else {
+      None
+    }
+  }
+
+  /// The instants in either interval, or None if they neither overlap nor touch, as the result would have a gap
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let a = JdInterval::new(2460558.5, 2460559.5).unwrap();
+  /// let b = JdInterval::new(2460559.5, 2460560.5).unwrap();
+  /// assert_eq!(a.union(&b), JdInterval::new(2460558.5, 2460560.5));
+  /// assert_eq!(a.intersect(&b), None);
+  /// ```
+  pub fn union(&self, other: &JdInterval) -> Option<JdInterval> {
+    if self.start <= other.end && other.start <= self.end {
+      Some(JdInterval { start: self.start.min(other.start), end: self.end.max(other.end) })
+    } else {
+      None
+    }
+  }
+
+  /// Julian Days from the start at intervals of one day, stopping before the end.
+  /// Each value is computed from the start rather than accumulated, so there is no drift
+  pub fn days(&self) -> impl Iterator<Item = f64> {
+    let JdInterval { start, end } = *self;
+    (0u64..).map(move |n| start + n as f64).take_while(move |jd| *jd < end)
+  }
+}
+
+impl IntoIterator for JdInterval {
+  type Item = f64;
+  type IntoIter = Box<dyn Iterator<Item = f64>>;
+
+  /// Iterate over the interval one day at a time, see days()
+  fn into_iter(self) -> Self::IntoIter {
+    Box::new(self.days())
+  }
+}
+
+///
+/// Iterator over Julian Days from a start up to but not including an end, at a fixed step.
+/// The start and step are held as whole milliseconds and each value is computed as start + n * step,
+/// so long ranges do not drift as repeated float addition would
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JdRangeIter {
+  start_ms: i64,
+  end_ms: i64,
+  step_ms: i64,
+  index: i64,
+}
+
+impl JdRangeIter {
+  /// Iterate one day at a time from start_jd to end_jd. Both are rounded to the millisecond
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let jds: Vec<f64> = JdRangeIter::new(2460558.5, 2460559.5).step_days(0.25).collect();
+  /// assert_eq!(jds, vec![2460558.5, 2460558.75, 2460559.0, 2460559.25]);
+  /// ```
+  pub fn new(start_jd: f64, end_jd: f64) -> Self {
+    JdRangeIter {
+      start_ms: julian_day_to_unix_millis(start_jd),
+      end_ms: julian_day_to_unix_millis(end_jd),
+      step_ms: 86_400_000,
+      index: 0,
+    }
+  }
+
+  /// Set the step in days, rounded to the millisecond. A negative step counts down to an earlier end,
+  /// while a zero step yields nothing
+  pub fn step_days(mut self, days: f64) -> Self {
+    self.step_ms = (days * 86_400_000.0).round() as i64;
+    self
+  }
+
+  /// Set the step as a chrono Duration, truncated to the millisecond
+  pub fn step_duration(mut self, duration: Duration) -> Self {
+    self.step_ms = duration.num_milliseconds();
+    self
+  }
+
+  fn next_millis(&mut self) -> Option<i64> {
+    let ms = self.step_ms.checked_mul(self.index).and_then(|offset| self.start_ms.checked_add(offset))?;
+    let in_range = match self.step_ms.signum() {
+      1 => ms < self.end_ms,
+      -1 => ms > self.end_ms,
+      _ => false,
+    };
+    if in_range {
+      self.index += 1;
+      Some(ms)
+    } else {
+      None
+    }
+  }
+
+  /// Yield each step as a NaiveDateTime in UTC rather than a Julian Day
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  /// use chrono::Duration;
+  ///
+  /// let times: Vec<String> = JdRangeIter::new(2460558.5, 2460558.625)
+  ///   .step_duration(Duration::hours(1))
+  ///   .datetimes()
+  ///   .map(|dt| dt.format("%H:%M").to_string())
+  ///   .collect();
+  /// assert_eq!(times, vec!["00:00", "01:00", "02:00"]);
+  /// ```
+  pub fn datetimes(mut self) -> impl Iterator<Item = NaiveDateTime> {
+    std::iter::from_fn(move || self.next_millis()).map_while(|ms| DateTime::from_timestamp_millis(ms).map(|dt| dt.naive_utc()))
+  }
+}
+
+impl Iterator for JdRangeIter {
+  type Item = f64;
+
+  fn next(&mut self) -> Option<f64> {
+    self.next_millis().map(|ms| unix_units_to_julian_day(ms, 86_400_000))
+  }
+}
+
+/// Date-time at one end of an ISO 8601 interval, with an optional trailing Z for UTC
+fn parse_interval_endpoint(dt_str: &str) -> Option<NaiveDateTime> {
+  let trimmed = dt_str.trim();
+  iso_fuzzy_string_to_datetime(trimmed.strip_suffix('Z').unwrap_or(trimmed)).ok()
+}
+
+/// Parse an ISO 8601 time interval in UTC as start/end, start/duration or duration/end, e.g. 2024-09-01T09:00Z/PT90M.
+/// Durations may include years and months, which are applied with calendar arithmetic from the date given.
+/// Returns None if the string is not a valid interval or the end is before the start
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let interval = iso_interval_to_jd_interval("2024-09-04T06:00:00Z/P1DT12H").unwrap();
+/// assert_eq!(interval, JdInterval { start: 2460557.75, end: 2460559.25 });
+/// assert_eq!(iso_interval_to_jd_interval("P1D/2024-09-05"), JdInterval::new(2460557.5, 2460558.5));
+/// ```
+///
+pub fn iso_interval_to_jd_interval(interval_str: &str) -> Option<JdInterval> {
+  let (first, second) = interval_str.trim().split_once('/')?;
+  let is_duration = |part: &str| part.trim().trim_start_matches(['-', '+']).starts_with('P');
+  match (is_duration(first), is_duration(second)) {
+    (false, false) => JdInterval::new(parse_interval_endpoint(first)?.to_jd(), parse_interval_endpoint(second)?.to_jd()),
+    (false, true) => {
+      let start = parse_interval_endpoint(first)?;
+      JdInterval::new(start.to_jd(), IsoDuration::parse(second)?.apply_to(start, false)?)
+    }
+    (true, false) => {
+      let end = parse_interval_endpoint(second)?;
+      JdInterval::new(IsoDuration::parse(first)?.apply_to(end, true)?, end.to_jd())
+    }
+    (true, true) => None,
+  }
+}
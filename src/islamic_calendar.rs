@@ -0,0 +1,186 @@
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::is_supported_jd;
+
+/// Number of days in a 30-year cycle of the tabular Islamic calendar (19 common years of 354 days and 11 leap years of 355 days)
+const ISLAMIC_CYCLE_DAYS: i64 = 10631;
+
+///
+/// Intercalation schemes of the tabular Islamic calendar, each adding a leap day to 11 years of every 30-year cycle
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IslamicLeapPattern {
+  /// Type I: leap years 2, 5, 7, 10, 13, 15, 18, 21, 24, 26 and 29
+  TypeI,
+  /// Type II: leap years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29, the most common civil scheme (Kuwaiti algorithm)
+  TypeII,
+  /// Type III (Fatimid): leap years 2, 5, 8, 10, 13, 16, 19, 21, 24, 27 and 29
+  TypeIII,
+  /// Type IV (Habash al-Hasib): leap years 2, 5, 8, 11, 13, 16, 19, 21, 24, 27 and 30
+  TypeIV,
+}
+
+impl IslamicLeapPattern {
+  /// positions of the leap years within the 30-year cycle
+  pub fn leap_years(&self) -> [u8; 11] {
+    match self {
+      IslamicLeapPattern::TypeI => [2, 5, 7, 10, 13, 15, 18, 21, 24, 26, 29],
+      IslamicLeapPattern::TypeII => [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29],
+      IslamicLeapPattern::TypeIII => [2, 5, 8, 10, 13, 16, 19, 21, 24, 27, 29],
+      IslamicLeapPattern::TypeIV => [2, 5, 8, 11, 13, 16, 19, 21, 24, 27, 30],
+    }
+  }
+}
+
+///
+/// Epoch of the tabular Islamic calendar, i.e. the Julian calendar date of 1 Muharram 1 AH
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IslamicEpoch {
+  /// Friday 16 July 622 (Julian), used for civil dates
+  Civil,
+  /// Thursday 15 July 622 (Julian), used in astronomical tables
+  Astronomical,
+}
+
+impl IslamicEpoch {
+  /// Julian Day Number of 1 Muharram 1 AH
+  pub fn jdn(&self) -> i64 {
+    match self {
+      IslamicEpoch::Civil => 1948440,
+      IslamicEpoch::Astronomical => 1948439,
+    }
+  }
+}
+
+///
+/// Arithmetic (tabular) Islamic calendar with a selectable intercalation scheme and epoch.
+/// The default is the Type II scheme with the civil epoch
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TabularIslamic {
+  pub leap_pattern: IslamicLeapPattern,
+  pub epoch: IslamicEpoch,
+}
+
+impl Default for TabularIslamic {
+  fn default() -> Self {
+    TabularIslamic { leap_pattern: IslamicLeapPattern::TypeII, epoch: IslamicEpoch::Civil }
+  }
+}
+
+impl TabularIslamic {
+  pub fn new(leap_pattern: IslamicLeapPattern, epoch: IslamicEpoch) -> Self {
+    TabularIslamic { leap_pattern, epoch }
+  }
+
+  /// Whether a year AH has 355 days, with Dhu al-Hijjah extended to 30 days
+  pub fn is_leap_year(&self, year: i32) -> bool {
+    let position = (year - 1).rem_euclid(30) as u8 + 1;
+    self.leap_pattern.leap_years().contains(&position)
+  }
+
+  /// Number of days in a month: odd months have 30 days, even months 29, and month 12 has 30 in leap years
+  pub fn days_in_month(&self, year: i32, month: u32) -> u32 {
+    if month % 2 == 1 || (month == 12 && self.is_leap_year(year)) { 30 } else { 29 }
+  }
+
+  /// Julian Day at 00:00 UTC for a date in the tabular Islamic calendar, or None if the month or day is out of range
+  /// The Islamic day traditionally begins at the preceding sunset
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // 1 Muharram 1446 AH
+  /// let jd = TabularIslamic::default().date_to_julian_day(1446, 1, 1);
+  /// assert_eq!(jd, Some(2460499.5)); // 2024-07-08
+  /// ```
+  pub fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    if !(1..=12).contains(&month) || day < 1 || day > self.days_in_month(year, month) {
+      return None;
+    }
+    let jdn = self.year_start_jdn(year) + 29 * (month as i64 - 1) + month as i64 / 2 + day as i64 - 1;
+    Some(jdn as f64 - 0.5)
+  }
+
+  /// Tabular Islamic date as (year, month, day) for the UTC calendar day containing a Julian Day,
+  /// or None for NaN, infinite or unsupported Julian Days
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(TabularIslamic::default().julian_day_to_date(2460499.5), Some((1446, 1, 1)));
+  /// ```
+  pub fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let jdn = jd_to_jdn(jd);
+    let mut year = i32::try_from((jdn - self.epoch.jdn()).div_euclid(ISLAMIC_CYCLE_DAYS) * 30 + 1).ok()?;
+    while self.year_start_jdn(year + 1) <= jdn {
+      year += 1;
+    }
+    let mut day_of_year = (jdn - self.year_start_jdn(year)) as u32;
+    let mut month = 1;
+    while day_of_year >= self.days_in_month(year, month) {
+      day_of_year -= self.days_in_month(year, month);
+      month += 1;
+    }
+    Some((year, month, day_of_year + 1))
+  }
+
+  ///
+  /// Julian Day Number of 1 Muharram of a year AH
+  ///
+  fn year_start_jdn(&self, year: i32) -> i64 {
+    let elapsed = year as i64 - 1;
+    let position = elapsed.rem_euclid(30) as u8;
+    let leap_days = elapsed.div_euclid(30) * 11 + self.leap_pattern.leap_years().iter().filter(|leap| **leap <= position).count() as i64;
+    self.epoch.jdn() + elapsed * 354 + leap_days
+  }
+}
+
+impl Calendar for TabularIslamic {
+  fn months_in_year(&self, _year: i32) -> u32 {
+    12
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    (1..=12).contains(&month).then(|| TabularIslamic::days_in_month(self, year, month))
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    TabularIslamic::is_leap_year(self, year)
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    TabularIslamic::date_to_julian_day(self, year, month, day)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    TabularIslamic::julian_day_to_date(self, jd)
+  }
+}
+
+/// Convert a Julian Day to a tabular Islamic date (year, month, day) with the default Type II civil scheme,
+/// or None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let (year, month, day) = julian_day_to_islamic(2460258.488768587).unwrap();
+/// ```
+///
+pub fn julian_day_to_islamic(jd: f64) -> Option<(i32, u32, u32)> {
+  TabularIslamic::default().julian_day_to_date(jd)
+}
+
+///
+/// Convert a tabular Islamic date to the Julian Day at 00:00 UTC with the default Type II civil scheme
+///
+pub fn islamic_to_julian_day(year: i32, month: u32, day: u32) -> Option<f64> {
+  TabularIslamic::default().date_to_julian_day(year, month, day)
+}
@@ -0,0 +1,190 @@
+use chrono::{Months, NaiveDateTime};
+
+///
+/// Components of an ISO 8601 duration such as P1Y2M10DT2H30M. Years and months are kept apart from
+/// days and seconds because their length depends on the date they are applied to
+///
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct IsoDuration {
+  pub negative: bool,
+  pub years: u32,
+  pub months: u32,
+  /// Days including weeks, which may have a fraction
+  pub days: f64,
+  /// Hours, minutes and seconds in seconds
+  pub seconds: f64,
+}
+
+/// Values of the components of one part of a duration, in the order of the designators.
+/// Each component may appear once and only in order, and a decimal comma is accepted as in ISO 8601
+fn parse_components(part: &str, designators: &[char]) -> Option<Vec<Option<f64>>> {
+  let mut values = vec![None; designators.len()];
+  let mut next_index = 0;
+  let mut number = String::new();
+  for c in part.chars() {
+    if c.is_ascii_digit() || c == '.' || c == ',' {
+      number.push(if c == ',' { '.' } else { c });
+      continue;
+    }
+    let index = next_index + designators[next_index..].iter().position(|d| *d == c)?;
+    if number.is_empty() {
+      return None;
+    }
+    values[index] = Some(number.parse::<f64>().ok()?);
+    number.clear();
+    next_index = index + 1;
+  }
+  if number.is_empty() {
+    Some(values)
+  } else {
+    None
+  }
+}
+
+impl IsoDuration {
+  /// Parse an ISO 8601 duration such as P3W, PT36H or -P1DT6H. Years and months must be whole numbers
+  pub(crate) fn parse(duration_str: &str) -> Option<Self> {
+    let trimmed = duration_str.trim();
+    let (negative, unsigned) = match trimmed.strip_prefix('-') {
+      Some(rest) => (true, rest),
+      None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let body = unsigned.strip_prefix('P')?;
+    let (date_part, time_part) = match body.split_once('T') {
+      Some((date_part, time_part)) => (date_part, time_part),
+      None => (body, ""),
+    };
+    if (date_part.is_empty() && time_part.is_empty()) || body.ends_with('T') {
+      return None;
+    }
+    let date_values = parse_components(date_part, &['Y', 'M', 'W', 'D'])?;
+    let time_values = parse_components(time_part, &['H', 'M', 'S'])?;
+    let whole = |value: Option<f64>| -> Option<u32> {
+      let value = value.unwrap_or(0.0);
+      if value.fract() == 0.0 && value <= u32::MAX as f64 { Some(value as u32) } else { None }
+    };
+    let [hours, minutes, seconds] = [0, 1, 2].map(|i| time_values[i].unwrap_or(0.0));
+    Some(IsoDuration {
+      negative,
+      years: whole(date_values[0])?,
+      months: whole(date_values[1])?,
+      days: date_values[2].unwrap_or(0.0) * 7.0 + date_values[3].unwrap_or(0.0),
+      seconds: hours * 3600.0 + minutes * 60.0 + seconds,
+    })
+  }
+
+  /// Signed number of days excluding any year and month components
+  pub(crate) fn fixed_days(&self) -> f64 {
+    let days = self.days + self.seconds / 86_400.0;
+    if self.negative { -days } else { days }
+  }
+
+  /// Julian Day after applying the duration to a date-time, or before it if reverse is true.
+  /// Years and months are applied first with chrono's month arithmetic, so that Jan 31 + P1M is the last day of February
+  pub(crate) fn apply_to(&self, dt: NaiveDateTime, reverse: bool) -> Option<f64> {
+    use crate::JulianDay;
+    let months = Months::new(self.years.checked_mul(12)?.checked_add(self.months)?);
+    let backwards = self.negative != reverse;
+    let shifted = if backwards { dt.checked_sub_months(months)? } else { dt.checked_add_months(months)? };
+    let days = if reverse { -self.fixed_days() } else { self.fixed_days() };
+    Some(shifted.to_jd() + days)
+  }
+}
+
+///
+/// How to convert the year and month components of an ISO 8601 duration, which have no fixed length, to days
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonthYearPolicy {
+  /// Durations with years or months are not converted
+  #[default]
+  Reject,
+  /// Years and months have the mean lengths of the Gregorian calendar, 365.2425 and 30.436875 days
+  Nominal,
+}
+
+/// Mean length of a Gregorian year in days
+pub const NOMINAL_YEAR_DAYS: f64 = 365.2425;
+
+/// Mean length of a Gregorian month in days
+pub const NOMINAL_MONTH_DAYS: f64 = NOMINAL_YEAR_DAYS / 12.0;
+
+/// Convert an ISO 8601 duration such as P1DT6H, PT90M or -P2W to signed fractional days.
+/// Returns None if the string is not a valid duration or it has years or months, see iso_duration_to_days_with_policy()
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(iso_duration_to_days("P1DT6H"), Some(1.25));
+/// assert_eq!(iso_duration_to_days("P1M"), None);
+/// ```
+///
+pub fn iso_duration_to_days(duration_str: &str) -> Option<f64> {
+  iso_duration_to_days_with_policy(duration_str, MonthYearPolicy::Reject)
+}
+
+/// Convert an ISO 8601 duration to signed fractional days, with years and months handled by the policy
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(iso_duration_to_days_with_policy("P1Y", MonthYearPolicy::Nominal), Some(365.2425));
+/// ```
+///
+pub fn iso_duration_to_days_with_policy(duration_str: &str, policy: MonthYearPolicy) -> Option<f64> {
+  let duration = IsoDuration::parse(duration_str)?;
+  if duration.years == 0 && duration.months == 0 {
+    return Some(duration.fixed_days());
+  }
+  match policy {
+    MonthYearPolicy::Reject => None,
+    MonthYearPolicy::Nominal => {
+      let nominal_days = duration.years as f64 * NOMINAL_YEAR_DAYS + duration.months as f64 * NOMINAL_MONTH_DAYS;
+      Some(duration.fixed_days() + if duration.negative { -nominal_days } else { nominal_days })
+    }
+  }
+}
+
+/// Format signed fractional days as an ISO 8601 duration in days, hours, minutes and seconds,
+/// rounded to the millisecond, e.g. 1.25 is P1DT6H. Years, months and weeks are never used so the result is exact
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(days_to_iso_duration(1.25), "P1DT6H");
+/// assert_eq!(days_to_iso_duration(-0.5 / 86400.0), "-PT0.5S");
+/// assert_eq!(days_to_iso_duration(0.0), "PT0S");
+/// ```
+///
+pub fn days_to_iso_duration(days: f64) -> String {
+  let millis = (days * 86_400_000.0).round() as i64;
+  let sign = if millis < 0 { "-" } else { "" };
+  let abs_millis = millis.unsigned_abs();
+  let whole_days = abs_millis / 86_400_000;
+  let hours = abs_millis / 3_600_000 % 24;
+  let minutes = abs_millis / 60_000 % 60;
+  let seconds = abs_millis / 1000 % 60;
+  let frac_millis = abs_millis % 1000;
+  let mut time_part = String::new();
+  if hours > 0 {
+    time_part.push_str(&format!("{}H", hours));
+  }
+  if minutes > 0 {
+    time_part.push_str(&format!("{}M", minutes));
+  }
+  if frac_millis > 0 {
+    let fraction = format!("{:03}", frac_millis);
+    time_part.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+  } else if seconds > 0 || (whole_days == 0 && time_part.is_empty()) {
+    time_part.push_str(&format!("{}S", seconds));
+  }
+  let date_part = if whole_days > 0 { format!("{}D", whole_days) } else { String::new() };
+  if time_part.is_empty() {
+    format!("{}P{}", sign, date_part)
+  } else {
+    format!("{}P{}T{}", sign, date_part, time_part)
+  }
+}
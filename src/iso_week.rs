@@ -0,0 +1,170 @@
+use crate::civil::*;
+use crate::is_supported_jd;
+use crate::WeekStart;
+
+///
+/// Julian Day Number of the Monday starting week 1 of an ISO week-numbering year, i.e. the week containing 4 January
+///
+fn iso_week_one_monday_jdn(year: i64) -> i64 {
+  let jan_4 = gregorian_to_jdn(year, 1, 4);
+  jan_4 - jan_4.rem_euclid(7)
+}
+
+///
+/// Number of weeks (52 or 53) in an ISO week-numbering year
+///
+pub fn iso_weeks_in_year(year: i32) -> u32 {
+  ((iso_week_one_monday_jdn(year as i64 + 1) - iso_week_one_monday_jdn(year as i64)) / 7) as u32
+}
+
+/// Convert a Julian Day to an ISO 8601 week date (year, week, weekday) for the UTC calendar day containing it,
+/// where weekdays run from Monday = 1 to Sunday = 7 and the week-numbering year may differ from the calendar year
+/// in the first and last days of January and December. Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Sunday 4 September 2022
+/// assert_eq!(julian_day_to_iso_week_date(2459826.5), Some((2022, 35, 7)));
+/// ```
+///
+pub fn julian_day_to_iso_week_date(jd: f64) -> Option<(i32, u32, u32)> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let jdn = jd_to_jdn(jd);
+  let weekday = jdn.rem_euclid(7) + 1;
+  let (year, _, _) = jdn_to_gregorian(jdn - weekday + 4);
+  let week = (jdn - iso_week_one_monday_jdn(year)) / 7 + 1;
+  Some((year as i32, week as u32, weekday as u32))
+}
+
+/// ISO 8601 week-numbering year and week number (1 to 53) of a Julian Day in the local time given by an offset in seconds from UTC.
+/// Week 1 is the week from Monday containing the first Thursday of the year, so the first days of January may fall in
+/// week 52 or 53 of the previous year and the last days of December in week 1 of the next year.
+/// Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Sunday 2023-01-01 belongs to the last week of 2022
+/// assert_eq!(julian_day_to_iso_week(2459945.5, 0), Some((2022, 52)));
+/// ```
+///
+pub fn julian_day_to_iso_week(jd: f64, offset_secs: i32) -> Option<(i32, u32)> {
+  let (year, week, _) = julian_day_to_iso_week_date(jd + offset_secs as f64 / 86400.0)?;
+  Some((year, week))
+}
+
+///
+/// Week-numbering systems for week numbers within a year
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeekRule {
+  /// ISO 8601: weeks start on Monday and week 1 contains the first Thursday, with a separate week-numbering year
+  Iso,
+  /// US: weeks start on Sunday and week 1 contains 1 January, so the first and last weeks may be partial
+  Us,
+  /// Middle Eastern: weeks start on Saturday and week 1 contains 1 January
+  MiddleEastern,
+}
+
+impl WeekRule {
+  /// First day of the week under this rule
+  pub fn week_start(&self) -> WeekStart {
+    match self {
+      WeekRule::Iso => WeekStart::Monday,
+      WeekRule::Us => WeekStart::Sunday,
+      WeekRule::MiddleEastern => WeekStart::Saturday,
+    }
+  }
+
+  /// Weekday index (Sun = 0 ... Sat = 6) on which weeks start
+  pub fn first_weekday_index(&self) -> u8 {
+    self.week_start().weekday_index()
+  }
+}
+
+/// Week-numbering year and week number of a Julian Day under a week rule, in the local time given by an offset in seconds from UTC.
+/// With the US and Middle Eastern rules the year is the calendar year and week numbers run from 1 up to 54.
+/// Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Sunday 2023-01-01 is in ISO week 52 of 2022 but starts US week 1
+/// assert_eq!(julian_day_to_week_number(2459945.5, 0, WeekRule::Iso), Some((2022, 52)));
+/// assert_eq!(julian_day_to_week_number(2459945.5, 0, WeekRule::Us), Some((2023, 1)));
+/// ```
+///
+pub fn julian_day_to_week_number(jd: f64, offset_secs: i32, rule: WeekRule) -> Option<(i32, u32)> {
+  if rule == WeekRule::Iso {
+    return julian_day_to_iso_week(jd, offset_secs);
+  }
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let jdn = jd_to_jdn(jd + offset_secs as f64 / 86400.0);
+  let (year, _, _) = jdn_to_gregorian(jdn);
+  let jan_1 = gregorian_to_jdn(year, 1, 1);
+  let lead_days = (jdn_weekday_index(jan_1) as i64 - rule.first_weekday_index() as i64).rem_euclid(7);
+  Some((year as i32, ((jdn - jan_1 + lead_days) / 7 + 1) as u32))
+}
+
+/// Convert an ISO 8601 week date to the Julian Day at 00:00 UTC, or None if the week or weekday is out of range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(iso_week_date_to_julian_day(2022, 35, 7), Some(2459826.5));
+/// ```
+///
+pub fn iso_week_date_to_julian_day(year: i32, week: u32, weekday: u32) -> Option<f64> {
+  if week < 1 || week > iso_weeks_in_year(year) || !(1..=7).contains(&weekday) {
+    return None;
+  }
+  Some((iso_week_one_monday_jdn(year as i64) + 7 * (week as i64 - 1) + weekday as i64 - 1) as f64 - 0.5)
+}
+
+/// Format a Julian Day as an ISO 8601 week date string, e.g. "2022-W35-7", or None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(format_iso_week_date(2459826.5), Some("2022-W35-7".to_string()));
+/// ```
+///
+pub fn format_iso_week_date(jd: f64) -> Option<String> {
+  let (year, week, weekday) = julian_day_to_iso_week_date(jd)?;
+  let sign = if year < 0 { "-" } else { "" };
+  Some(format!("{}{:04}-W{:02}-{}", sign, year.abs(), week, weekday))
+}
+
+/// Parse an ISO 8601 week date in the extended (2022-W35-7) or basic (2022W357) format to the Julian Day at 00:00 UTC.
+/// The weekday may be omitted for the Monday of the week, e.g. 2022-W35
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(parse_iso_week_date("2022-W35-7"), Some(2459826.5));
+/// assert_eq!(parse_iso_week_date("2022W35"), Some(2459820.5));
+/// ```
+///
+pub fn parse_iso_week_date(date_str: &str) -> Option<f64> {
+  let trimmed = date_str.trim();
+  let (year_str, week_part) = trimmed.split_once(['W', 'w'])?;
+  let year: i32 = year_str.strip_suffix('-').unwrap_or(year_str).parse().ok()?;
+  let week_digits: String = week_part.chars().filter(|c| *c != '-').collect();
+  if !week_digits.chars().all(|c| c.is_ascii_digit()) || !(2..=3).contains(&week_digits.len()) {
+    return None;
+  }
+  let week = week_digits[..2].parse().ok()?;
+  let weekday = if week_digits.len() == 3 { week_digits[2..].parse().ok()? } else { 1 };
+  iso_week_date_to_julian_day(year, week, weekday)
+}
@@ -0,0 +1,153 @@
+use crate::civil::*;
+use crate::civil_time::civil_to_julian_day;
+use crate::is_supported_jd;
+
+///
+/// Japanese era (nengō) starting on a proleptic Gregorian date, whose first year (gannen) is the Gregorian year of that date
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JapaneseEra {
+  pub kanji: &'static str,
+  pub romaji: &'static str,
+  pub start_year: i32,
+  pub start_month: u32,
+  pub start_day: u32,
+}
+
+impl JapaneseEra {
+  pub const fn new(kanji: &'static str, romaji: &'static str, start_year: i32, start_month: u32, start_day: u32) -> Self {
+    JapaneseEra { kanji, romaji, start_year, start_month, start_day }
+  }
+
+  /// Julian Day Number of the first day of the era
+  fn start_jdn(&self) -> i64 {
+    gregorian_to_jdn(self.start_year as i64, self.start_month, self.start_day)
+  }
+}
+
+/// Modern Japanese eras from Meiji to Reiwa. Japan adopted the Gregorian calendar on Meiji 6-01-01 (1873),
+/// so earlier Meiji dates are Gregorian equivalents rather than the lunisolar dates of contemporary documents
+pub const JAPANESE_ERAS: [JapaneseEra; 5] = [
+  JapaneseEra::new("明治", "Meiji", 1868, 10, 23),
+  JapaneseEra::new("大正", "Taishō", 1912, 7, 30),
+  JapaneseEra::new("昭和", "Shōwa", 1926, 12, 25),
+  JapaneseEra::new("平成", "Heisei", 1989, 1, 8),
+  JapaneseEra::new("令和", "Reiwa", 2019, 5, 1),
+];
+
+///
+/// Table of Japanese eras used for formatting and parsing, extensible with future or historical eras.
+/// The default table holds JAPANESE_ERAS
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JapaneseEraTable {
+  eras: Vec<JapaneseEra>,
+}
+
+impl Default for JapaneseEraTable {
+  fn default() -> Self {
+    JapaneseEraTable { eras: JAPANESE_ERAS.to_vec() }
+  }
+}
+
+impl JapaneseEraTable {
+  /// Add an era to the table, keeping the eras in order of their start dates
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let table = JapaneseEraTable::default().with_era(JapaneseEra::new("天保", "Tenpō", 1831, 1, 23));
+  /// assert_eq!(table.eras().len(), 6);
+  /// ```
+  pub fn with_era(mut self, era: JapaneseEra) -> Self {
+    self.eras.push(era);
+    self.eras.sort_by_key(|era| era.start_jdn());
+    self
+  }
+
+  /// Eras in the table in chronological order
+  pub fn eras(&self) -> &[JapaneseEra] {
+    &self.eras
+  }
+
+  /// Era and year within that era for a Julian Day, or None before the first era in the table
+  /// or for NaN, infinite or unsupported Julian Days
+  pub fn era_year(&self, jd: f64) -> Option<(JapaneseEra, i32)> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let jdn = jd_to_jdn(jd);
+    let era = *self.eras.iter().rev().find(|era| era.start_jdn() <= jdn)?;
+    let (year, _, _) = jdn_to_gregorian(jdn);
+    Some((era, year as i32 - era.start_year + 1))
+  }
+
+  /// Format the UTC calendar day of a Julian Day as an era date, e.g. "令和6年9月4日", with the first year written as 元年.
+  /// Returns None where era_year() does
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let table = JapaneseEraTable::default();
+  /// assert_eq!(table.format(2460557.5), Some("令和6年9月4日".to_string()));
+  /// assert_eq!(table.format(2458604.5), Some("令和元年5月1日".to_string()));
+  /// ```
+  pub fn format(&self, jd: f64) -> Option<String> {
+    let (era, era_year) = self.era_year(jd)?;
+    let (_, month, day) = jdn_to_gregorian(jd_to_jdn(jd));
+    let year = if era_year == 1 { "元".to_string() } else { era_year.to_string() };
+    Some(format!("{}{}年{}月{}日", era.kanji, year, month, day))
+  }
+
+  /// Parse an era date such as "令和6年9月4日" or "平成元年1月8日" to the Julian Day at 00:00 UTC.
+  /// Full-width digits are accepted. Returns None if the era is unknown, the date is invalid or it is outside the era,
+  /// i.e. before its start or on or after the start of the next era in the table
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(JapaneseEraTable::default().parse("令和６年９月４日"), Some(2460557.5));
+  /// // Heisei began on 8 January 1989
+  /// assert_eq!(JapaneseEraTable::default().parse("昭和64年1月8日"), None);
+  /// ```
+  pub fn parse(&self, date_str: &str) -> Option<f64> {
+    let date_str: String = date_str.trim().chars().map(|c| match c {
+      '０'..='９' => char::from_digit(c as u32 - '０' as u32, 10).unwrap(),
+      _ => c,
+    }).collect();
+    let index = self.eras.iter().position(|era| date_str.starts_with(era.kanji))?;
+    let era = &self.eras[index];
+    let (year_str, rest) = date_str[era.kanji.len()..].split_once('年')?;
+    let (month_str, rest) = rest.split_once('月')?;
+    let day_str = rest.strip_suffix('日')?;
+    let era_year: i32 = if year_str.trim() == "元" { 1 } else { year_str.trim().parse().ok()? };
+    let next_era = self.eras.get(index + 1);
+    let year = era.start_year.checked_add(era_year.checked_sub(1)?)?;
+    if era_year < 1 || next_era.is_some_and(|next_era| year > next_era.start_year) {
+      return None;
+    }
+    let jd = civil_to_julian_day(year as i64, month_str.trim().parse().ok()?, day_str.trim().parse().ok()?, 0, 0, 0, 0)?;
+    let jdn = jd_to_jdn(jd);
+    if jdn < era.start_jdn() || next_era.is_some_and(|next_era| jdn >= next_era.start_jdn()) {
+      return None;
+    }
+    Some(jd)
+  }
+}
+
+///
+/// Format a Julian Day as a Japanese era date with the default era table, e.g. "令和6年9月4日"
+///
+pub fn julian_day_to_japanese_era_string(jd: f64) -> Option<String> {
+  JapaneseEraTable::default().format(jd)
+}
+
+///
+/// Parse a Japanese era date such as "令和6年9月4日" to a Julian Day with the default era table
+///
+pub fn japanese_era_string_to_julian_day(date_str: &str) -> Option<f64> {
+  JapaneseEraTable::default().parse(date_str)
+}
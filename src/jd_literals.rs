@@ -0,0 +1,46 @@
+use crate::{JulianDayError, MODIFIED_JULIAN_DAY_OFFSET};
+
+/// Difference between a Julian Day and a Truncated Julian Day as defined by NASA, which counts days from 1968-05-24 00:00 UTC
+pub const TRUNCATED_JULIAN_DAY_OFFSET: f64 = 2440000.5;
+
+/// Difference between a Julian Day and a Reduced Julian Day, which counts days from 1858-11-16 12:00 UTC
+pub const REDUCED_JULIAN_DAY_OFFSET: f64 = 2400000.0;
+
+///
+/// Prefixes of day-number literals, longest first, with the offset to add to reach a Julian Day
+///
+const JD_LITERAL_PREFIXES: [(&str, f64); 4] = [
+  ("MJD", MODIFIED_JULIAN_DAY_OFFSET),
+  ("TJD", TRUNCATED_JULIAN_DAY_OFFSET),
+  ("RJD", REDUCED_JULIAN_DAY_OFFSET),
+  ("JD", 0.0),
+];
+
+/// Parse a day-number literal to a Julian Day: a plain decimal number as a Julian Day, e.g. "2459827.25",
+/// or a number with a JD, MJD, TJD or RJD prefix, e.g. "JD 2459827.25", "MJD 59826.75" or "TJD 19826".
+/// Prefixes are case-insensitive and may be followed by a space, colon or equals sign
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(jd_literal_to_julian_day("JD 2459827.25"), Ok(2459827.25));
+/// assert_eq!(jd_literal_to_julian_day("MJD 59826.75"), Ok(2459827.25));
+/// assert_eq!(jd_literal_to_julian_day("TJD 19826"), Ok(2459826.5));
+/// ```
+///
+pub fn jd_literal_to_julian_day(literal: &str) -> Result<f64, JulianDayError> {
+  let trimmed = literal.trim();
+  let (offset, number) = JD_LITERAL_PREFIXES.iter()
+    .find_map(|(prefix, offset)| {
+      let head = trimmed.get(..prefix.len())?;
+      head.eq_ignore_ascii_case(prefix).then(|| (*offset, trimmed[prefix.len()..].trim_start_matches([' ', ':', '='])))
+    })
+    .unwrap_or((0.0, trimmed));
+  let unsigned = number.strip_prefix(['-', '+']).unwrap_or(number);
+  let is_decimal = !unsigned.is_empty() && unsigned.chars().all(|c| c.is_ascii_digit() || c == '.') && unsigned.matches('.').count() <= 1 && unsigned != ".";
+  if !is_decimal {
+    return Err(JulianDayError::InvalidFormat);
+  }
+  number.parse::<f64>().map(|value| value + offset).map_err(|_| JulianDayError::InvalidFormat)
+}
@@ -0,0 +1,108 @@
+use std::ops::{Add, Sub};
+use crate::civil::*;
+
+///
+/// Integer Julian Day Number of a calendar day. Unlike astronomical Julian Days, which begin at noon UTC,
+/// this crate numbers civil days from midnight UTC, so JDN 2460558 runs from JD 2460557.5 to 2460558.5
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Jdn(pub i64);
+
+impl Jdn {
+  /// Day number containing a Julian Day
+  pub fn from_jd(jd: f64) -> Self {
+    Jdn(jd_to_jdn(jd))
+  }
+
+  /// Julian Day at midnight UTC at the start of the day, i.e. N - 0.5, not the astronomical noon Julian Day N
+  /// used by SOFA or Meeus
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(Jdn(2460558).to_jd(), 2460557.5);
+  /// ```
+  pub fn to_jd(&self) -> f64 {
+    self.0 as f64 - 0.5
+  }
+
+  /// Day number of a proleptic Gregorian date with astronomical year numbering, or None if the month or day is out of range
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(Jdn::from_gregorian(2024, 9, 4), Some(Jdn(2460558)));
+  /// ```
+  pub fn from_gregorian(year: i64, month: u32, day: u32) -> Option<Self> {
+    ((1..=12).contains(&month) && day >= 1 && day <= month_length(month, is_gregorian_leap_year(year)))
+      .then(|| Jdn(gregorian_to_jdn(year, month, day)))
+  }
+
+  /// Proleptic Gregorian date as (year, month, day)
+  pub fn to_gregorian(&self) -> (i64, u32, u32) {
+    jdn_to_gregorian(self.0)
+  }
+
+  /// Weekday index (Sun = 0, Mon = 1 ... Sat = 6) in UTC
+  pub fn weekday_index(&self) -> u8 {
+    jdn_weekday_index(self.0)
+  }
+}
+
+/// Add a number of days
+impl Add<i64> for Jdn {
+  type Output = Jdn;
+
+  fn add(self, days: i64) -> Jdn {
+    Jdn(self.0 + days)
+  }
+}
+
+/// Subtract a number of days
+impl Sub<i64> for Jdn {
+  type Output = Jdn;
+
+  fn sub(self, days: i64) -> Jdn {
+    Jdn(self.0 - days)
+  }
+}
+
+/// Number of days between two day numbers
+impl Sub<Jdn> for Jdn {
+  type Output = i64;
+
+  fn sub(self, other: Jdn) -> i64 {
+    self.0 - other.0
+  }
+}
+
+/// Split a Julian Day into the number of its calendar day and the fraction of that day elapsed since midnight UTC, from 0 up to but excluding 1.
+/// Noon UTC, where Julian Days are whole numbers, falls at a fraction of 0.5
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(split_jd(2460558.0), (Jdn(2460558), 0.5));
+/// assert_eq!(split_jd(2460558.5), (Jdn(2460559), 0.0));
+/// ```
+///
+pub fn split_jd(jd: f64) -> (Jdn, f64) {
+  let jdn = jd_to_jdn(jd);
+  (Jdn(jdn), jd - (jdn as f64 - 0.5))
+}
+
+/// Merge a day number and the fraction of the day since midnight UTC into a Julian Day, reversing split_jd()
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(merge_jd(Jdn(2460558), 0.75), 2460558.25);
+/// ```
+///
+pub fn merge_jd(jdn: Jdn, fraction: f64) -> f64 {
+  jdn.to_jd() + fraction
+}
@@ -0,0 +1,97 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::civil::*;
+use crate::is_supported_jd;
+
+///
+/// Leap year rule of the Julian calendar with astronomical year numbering (every 4th year including year 0 = 1 BC)
+///
+pub fn is_julian_leap_year(year: i32) -> bool {
+  year.rem_euclid(4) == 0
+}
+
+/// Convert a Julian Day to a proleptic Julian calendar date as (year, month, day) with astronomical year numbering.
+/// The date is that of the UTC calendar day containing the Julian Day, i.e. starting at midnight.
+/// Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Isaac Newton was born on 1643-01-04 (Gregorian), recorded in England as 25 December 1642 (Old Style)
+/// assert_eq!(julian_day_to_julian_calendar(2321156.5), Some((1642, 12, 25)));
+/// ```
+///
+pub fn julian_day_to_julian_calendar(jd: f64) -> Option<(i32, u32, u32)> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let (year, month, day) = jdn_to_julian_calendar(jd_to_jdn(jd));
+  Some((i32::try_from(year).ok()?, month, day))
+}
+
+/// Convert a proleptic Julian calendar date with astronomical year numbering to the Julian Day at 00:00 UTC
+/// Returns None if the month or day is out of range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_calendar_to_julian_day(1642, 12, 25), Some(2321156.5));
+/// ```
+///
+pub fn julian_calendar_to_julian_day(year: i32, month: u32, day: u32) -> Option<f64> {
+  if (1..=12).contains(&month) && day >= 1 && day <= month_length(month, is_julian_leap_year(year)) {
+    Some(julian_calendar_to_jdn(year as i64, month, day) as f64 - 0.5)
+  } else {
+    None
+  }
+}
+
+///
+/// This trait may be implemented by any Date or DateTime object
+/// Implementations for chrono::NaiveDate and chrono::NaiveDateTime are provided below
+///
+pub trait JulianCalendar {
+
+  ///
+  /// The proleptic Julian calendar date as (year, month, day) with astronomical year numbering
+  ///
+  fn to_julian_calendar(&self) -> (i32, u32, u32);
+
+  ///
+  /// Construct a Date or DateTime object (at midnight) from a proleptic Julian calendar date
+  ///
+  fn from_julian_calendar(year: i32, month: u32, day: u32) -> Option<Self> where Self: Sized;
+
+}
+
+impl JulianCalendar for NaiveDate {
+  /// Old Style date of a Gregorian chrono::NaiveDate
+  ///
+  /// ### Example:
+  /// ```
+  /// use chrono::NaiveDate;
+  /// use julian_day_converter::*;
+  ///
+  /// let october_revolution = NaiveDate::from_ymd_opt(1917, 11, 7).unwrap();
+  /// assert_eq!(october_revolution.to_julian_calendar(), (1917, 10, 25));
+  /// ```
+  fn to_julian_calendar(&self) -> (i32, u32, u32) {
+    let (year, month, day) = jdn_to_julian_calendar(naive_date_to_jdn(self));
+    (year as i32, month, day)
+  }
+
+  fn from_julian_calendar(year: i32, month: u32, day: u32) -> Option<Self> {
+    julian_calendar_to_julian_day(year, month, day).and_then(|jd| jdn_to_naive_date(jd_to_jdn(jd)))
+  }
+}
+
+impl JulianCalendar for NaiveDateTime {
+  fn to_julian_calendar(&self) -> (i32, u32, u32) {
+    self.date().to_julian_calendar()
+  }
+
+  fn from_julian_calendar(year: i32, month: u32, day: u32) -> Option<Self> {
+    NaiveDate::from_julian_calendar(year, month, day).map(|date| date.and_time(NaiveTime::MIN))
+  }
+}
@@ -0,0 +1,252 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+use chrono::{Duration, NaiveDateTime};
+use crate::*;
+
+/// Difference between a Julian Day and a Modified Julian Day, which counts days from 1858-11-17 00:00 UTC
+pub const MODIFIED_JULIAN_DAY_OFFSET: f64 = 2400000.5;
+
+///
+/// Julian Day wrapped in its own type, so it cannot be mixed up with Modified Julian Days, unix days or other plain f64 values.
+/// Values are always within the supported range, see supported_jd_range(). Arithmetic with f64 days and chrono::Duration
+/// saturates at the ends of the range, with NaN days saturating to the earliest Julian Day, while checked_add_days()
+/// and checked_sub_days() return None instead
+///
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct JulianDayValue(f64);
+
+impl JulianDayValue {
+  /// Construct from a Julian Day, or None if it is NaN, infinite or outside the supported range
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(JulianDayValue::new(2460558.5).map(|jd| jd.value()), Some(2460558.5));
+  /// assert_eq!(JulianDayValue::new(f64::NAN), None);
+  /// assert_eq!(JulianDayValue::new(1e12), None);
+  /// ```
+  pub fn new(jd: f64) -> Option<Self> {
+    is_supported_jd(jd).then_some(JulianDayValue(jd))
+  }
+
+  /// Add a number of days, or None if the result is NaN or outside the supported range
+  pub fn checked_add_days(&self, days: f64) -> Option<Self> {
+    JulianDayValue::new(self.0 + days)
+  }
+
+  /// Subtract a number of days, or None if the result is NaN or outside the supported range
+  pub fn checked_sub_days(&self, days: f64) -> Option<Self> {
+    JulianDayValue::new(self.0 - days)
+  }
+
+  /// Julian Day as a 64-bit float
+  pub fn value(&self) -> f64 {
+    self.0
+  }
+
+  /// Construct from a Modified Julian Day, or None if it is NaN, infinite or outside the supported range
+  pub fn from_mjd(mjd: f64) -> Option<Self> {
+    JulianDayValue::new(mjd + MODIFIED_JULIAN_DAY_OFFSET)
+  }
+
+  /// Modified Julian Day (JD - 2400000.5)
+  pub fn mjd(&self) -> f64 {
+    self.0 - MODIFIED_JULIAN_DAY_OFFSET
+  }
+
+  /// Construct from a unix timestamp in seconds, see unixtime_to_julian_day(), or None if outside the supported range
+  pub fn from_unixtime(ts: i64) -> Option<Self> {
+    JulianDayValue::new(unixtime_to_julian_day(ts))
+  }
+
+  /// Unix timestamp in seconds, see julian_day_to_unixtime()
+  pub fn to_unixtime(&self) -> i64 {
+    julian_day_to_unixtime(self.0)
+  }
+
+  /// Timezone-neutral chrono::NaiveDateTime, see julian_day_to_datetime()
+  pub fn to_datetime(&self) -> Result<NaiveDateTime, DateRangeCoversionError> {
+    julian_day_to_datetime(self.0)
+  }
+
+  /// Julian Day of an ISO-8601-like date-time string, see datetime_to_julian_day()
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(JulianDayValue::from_datetime_str("2024-09-04 12:00").map(|jd| jd.value()), Some(2460558.0));
+  /// ```
+  pub fn from_datetime_str(dt_str: &str) -> Option<Self> {
+    datetime_to_julian_day(dt_str).ok().and_then(JulianDayValue::new)
+  }
+}
+
+impl WeekdayIndex for JulianDayValue {
+  fn weekday_index(&self, offset_secs: i32) -> u8 {
+    julian_day_to_weekday_index(self.0, offset_secs)
+  }
+}
+
+impl From<JulianDayValue> for f64 {
+  fn from(jd: JulianDayValue) -> f64 {
+    jd.0
+  }
+}
+
+impl fmt::Display for JulianDayValue {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+///
+/// Notation a Julian Day was read from by JulianDayValue::parse_with_notation()
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JdNotation {
+  /// Day-number literal such as 2460558.5, JD 2460558.5 or MJD 60558
+  JdLiteral,
+  /// Date-time string accepted by the fuzzy ISO parser such as 2024-09-04 12:00
+  IsoDateTime,
+  /// Unix timestamp with an @ prefix such as @1725451200
+  UnixTime,
+}
+
+impl JulianDayValue {
+  /// Parse a Julian Day from any of the supported notations and report which notation matched.
+  /// A day-number literal is tried first, then an ISO-like date-time, except that @ unix timestamps,
+  /// which the fuzzy parser also accepts, are reported as unix time. Bare numbers are read as Julian Days
+  /// unless they are outside the supported range, as 10- and 13-digit unix timestamps are
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let (jd, notation) = JulianDayValue::parse_with_notation("2024-09-04 12:00").unwrap();
+  /// assert_eq!((jd.value(), notation), (2460558.0, JdNotation::IsoDateTime));
+  /// assert_eq!(JulianDayValue::parse_with_notation("MJD 60557.5").map(|(_, notation)| notation), Some(JdNotation::JdLiteral));
+  /// assert_eq!(JulianDayValue::parse_with_notation("@1725451200").map(|(_, notation)| notation), Some(JdNotation::UnixTime));
+  /// ```
+  pub fn parse_with_notation(s: &str) -> Option<(Self, JdNotation)> {
+    let (jd, notation) = if let Some(jd) = jd_literal_to_julian_day(s).ok().filter(|jd| is_supported_jd(*jd)) {
+      (jd, JdNotation::JdLiteral)
+    } else if let Some(jd) = epoch_string_to_julian_day(s) {
+      (jd, JdNotation::UnixTime)
+    } else {
+      (fuzzy_string_to_julian_day(s).ok()?, JdNotation::IsoDateTime)
+    };
+    JulianDayValue::new(jd).map(|value| (value, notation))
+  }
+}
+
+impl FromStr for JulianDayValue {
+  type Err = JulianDayError;
+
+  /// parse a Julian Day from a day-number literal such as 2460558.5 or MJD 60558, an ISO-like date-time
+  /// or an @ unix timestamp, see parse_with_notation(), failing with InvalidFormat if none matches
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    JulianDayValue::parse_with_notation(s).map(|(jd, _)| jd).ok_or(JulianDayError::InvalidFormat)
+  }
+}
+
+/// Add a number of days, saturating at the ends of the supported range
+impl Add<f64> for JulianDayValue {
+  type Output = JulianDayValue;
+
+  fn add(self, days: f64) -> JulianDayValue {
+    JulianDayValue(clamp_jd(self.0 + days))
+  }
+}
+
+/// Subtract a number of days, saturating at the ends of the supported range
+impl Sub<f64> for JulianDayValue {
+  type Output = JulianDayValue;
+
+  fn sub(self, days: f64) -> JulianDayValue {
+    JulianDayValue(clamp_jd(self.0 - days))
+  }
+}
+
+impl AddAssign<f64> for JulianDayValue {
+  fn add_assign(&mut self, days: f64) {
+    *self = *self + days;
+  }
+}
+
+impl SubAssign<f64> for JulianDayValue {
+  fn sub_assign(&mut self, days: f64) {
+    *self = *self - days;
+  }
+}
+
+impl Add<Duration> for JulianDayValue {
+  type Output = JulianDayValue;
+
+  fn add(self, duration: Duration) -> JulianDayValue {
+    self + duration_to_days(duration)
+  }
+}
+
+impl Sub<Duration> for JulianDayValue {
+  type Output = JulianDayValue;
+
+  fn sub(self, duration: Duration) -> JulianDayValue {
+    self - duration_to_days(duration)
+  }
+}
+
+impl AddAssign<Duration> for JulianDayValue {
+  fn add_assign(&mut self, duration: Duration) {
+    *self = *self + duration;
+  }
+}
+
+impl SubAssign<Duration> for JulianDayValue {
+  fn sub_assign(&mut self, duration: Duration) {
+    *self = *self - duration;
+  }
+}
+
+/// Interval between two Julian Days as a chrono::Duration, rounded to the nearest millisecond
+/// as 64-bit Julian Days near the present only resolve about 40 microseconds.
+///
+/// ### Example:
+/// ```
+/// use chrono::Duration;
+/// use julian_day_converter::*;
+///
+/// let start = JulianDayValue::new(2460558.25).unwrap();
+/// let end = start + Duration::minutes(90);
+/// assert_eq!(end - start, Duration::minutes(90));
+/// ```
+impl Sub<JulianDayValue> for JulianDayValue {
+  type Output = Duration;
+
+  fn sub(self, other: JulianDayValue) -> Duration {
+    days_to_duration(self.0 - other.0)
+  }
+}
+
+///
+/// Length of a number of days as a chrono::Duration, rounded to the nearest millisecond and saturating at the limits of Duration
+///
+fn days_to_duration(days: f64) -> Duration {
+  let millis = (days * 86_400_000.0).round();
+  if millis >= Duration::MAX.num_milliseconds() as f64 {
+    Duration::MAX
+  } else if millis <= Duration::MIN.num_milliseconds() as f64 {
+    Duration::MIN
+  } else {
+    Duration::milliseconds(millis as i64)
+  }
+}
+
+///
+/// Length of a chrono::Duration in days, keeping sub-millisecond precision
+///
+fn duration_to_days(duration: Duration) -> f64 {
+  duration.num_seconds() as f64 / 86400.0 + duration.subsec_nanos() as f64 / 86_400_000_000_000.0
+}
@@ -1,9 +1,131 @@
-use chrono::{NaiveDateTime, ParseError};
+use chrono::{DateTime, Duration, NaiveDateTime, ParseError, Utc, Weekday};
+use historical_years::historical_to_astronomical_string;
+use ordinal_date::ordinal_to_calendar_string;
+use year_numbering::{buddhist_era_to_common_era_string, minguo_to_common_era_string};
+use rounding::julian_day_to_unix_units_wide;
+use utc_offset::{split_tz_abbreviation, split_utc_offset, UTC_NAMES};
+use twelve_hour_clock::twelve_hour_to_24_hour_string;
+use names::month_name_to_numeric_string;
+use parse_options::{complete_partial_date, slash_date_to_iso_string};
+use relative_dates::resolve_relative_keyword;
+use epoch_strings::epoch_string_to_datetime;
+use excel_serial::excel_serial_string_to_datetime;
+
+mod calendar;
+mod astro;
+mod moon;
+mod seasons;
+mod light_time;
+mod civil;
+mod civil_time;
+mod computus;
+mod julian_calendar;
+mod hybrid_calendar;
+mod islamic_calendar;
+mod umm_al_qura;
+mod persian_calendar;
+mod coptic_calendar;
+mod ethiopic_calendar;
+mod mayan_calendar;
+mod bahai_calendar;
+mod chinese_calendar;
+mod year_numbering;
+mod japanese_era;
+mod iso_week;
+mod ordinal_date;
+mod historical_years;
+mod names;
+mod weekdays;
+mod business_days;
+mod recurrence;
+mod periods;
+mod julian_day_value;
+mod two_part_jd;
+mod jdn;
+mod fixed_point_jd;
+mod rounding;
+mod checked;
+mod supported_range;
+mod comparison;
+mod elapsed;
+mod interval;
+mod iso_duration;
+mod human_duration;
+mod utc_offset;
+mod twelve_hour_clock;
+mod parse_options;
+mod relative_dates;
+mod epoch_strings;
+mod rfc_formats;
+mod jd_literals;
+mod multi_format;
+mod parse_errors;
+mod parse_candidates;
+mod parse_report;
+mod excel_serial;
+#[cfg(feature = "solar")]
+mod solar;
+#[cfg(feature = "tz")]
+mod timezone;
+
+pub use calendar::*;
+pub use astro::*;
+pub use moon::*;
+pub use seasons::*;
+pub use light_time::*;
+pub use civil_time::*;
+pub use computus::*;
+pub use julian_calendar::*;
+pub use hybrid_calendar::*;
+pub use islamic_calendar::*;
+pub use umm_al_qura::*;
+pub use persian_calendar::*;
+pub use coptic_calendar::*;
+pub use ethiopic_calendar::*;
+pub use mayan_calendar::*;
+pub use bahai_calendar::*;
+pub use chinese_calendar::*;
+pub use year_numbering::*;
+pub use japanese_era::*;
+pub use iso_week::*;
+pub use ordinal_date::*;
+pub use historical_years::*;
+pub use names::*;
+pub use weekdays::*;
+pub use business_days::*;
+pub use recurrence::*;
+pub use periods::*;
+pub use julian_day_value::*;
+pub use two_part_jd::*;
+pub use jdn::*;
+pub use fixed_point_jd::*;
+pub use rounding::*;
+pub use checked::*;
+pub use supported_range::*;
+pub use comparison::*;
+pub use elapsed::*;
+pub use interval::*;
+pub use iso_duration::*;
+pub use human_duration::*;
+pub use utc_offset::*;
+pub use parse_options::*;
+pub use epoch_strings::*;
+pub use rfc_formats::*;
+pub use jd_literals::*;
+pub use multi_format::*;
+pub use parse_errors::*;
+pub use parse_candidates::*;
+pub use parse_report::*;
+pub use excel_serial::*;
+#[cfg(feature = "solar")]
+pub use solar::*;
+#[cfg(feature = "tz")]
+pub use timezone::*;
 
 /// Public constant that may be useful to library users
 pub const JULIAN_DAY_UNIX_EPOCH_DAYS: f64 = 2440587.5; // 1970-01-01 00:00:00 UTC
 
-const JULIAN_DAY_UNIX_EPOCH_WEEKDAY: u8 = 4; // 1970-01-01 00:00:00 was a Wednesday UTC
+const JULIAN_DAY_UNIX_EPOCH_WEEKDAY: u8 = 4; // 1970-01-01 00:00:00 UTC was a Thursday
 
 ///
 /// Custom Error Type for date range conversion errors
@@ -22,10 +144,13 @@ pub struct DateRangeCoversionError;
 /// ```
 ///
 pub fn unixtime_to_julian_day(ts: i64) -> f64 {
-  (ts as f64 / 86_400f64) + JULIAN_DAY_UNIX_EPOCH_DAYS
+  JULIAN_DAY_UNIX_EPOCH_DAYS + ts.div_euclid(86_400) as f64 + ts.rem_euclid(86_400) as f64 / 86_400f64
 }
 
 /// convert julian day as 64-bit float to unix timestamp seconds as a signed 64 bit integer
+/// The result is the start of the second containing the instant (floored rather than truncated toward zero),
+/// so 1969-12-31 23:59:59.5 is -1 rather than 0. The instant is first rounded to the nearest millisecond,
+/// so float noise just below a whole second does not drop to the previous second
 /// 
 /// ### Example:
 /// ```
@@ -33,10 +158,80 @@ pub fn unixtime_to_julian_day(ts: i64) -> f64 {
 /// 
 /// let julian_day: f64 = 2460258.488768587;
 /// let unix_time: i64 = julian_day_to_unixtime(julian_day);
+/// assert_eq!(julian_day_to_unixtime(JULIAN_DAY_UNIX_EPOCH_DAYS - 0.5 / 86400.0), -1);
 /// ```
 ///
 pub fn julian_day_to_unixtime(jd: f64) -> i64 {
-  ((jd - JULIAN_DAY_UNIX_EPOCH_DAYS) * 86400f64) as i64
+  let millis = julian_day_to_unix_units_wide(jd, 86_400_000, RoundingMode::HalfEven);
+  millis.div_euclid(1000).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+///
+/// Convert a unix timestamp in milliseconds to julian days as a 64-bit float.
+/// Whole days are counted with integer arithmetic and only the time of day is divided,
+/// so the result is the nearest f64 to the exact Julian Day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(unix_millis_to_julian_day(1_725_472_800_000), 2460558.25);
+/// ```
+///
+pub fn unix_millis_to_julian_day(ms: i64) -> f64 {
+  let days = ms.div_euclid(86_400_000);
+  let millis_of_day = ms.rem_euclid(86_400_000);
+  JULIAN_DAY_UNIX_EPOCH_DAYS + days as f64 + millis_of_day as f64 / 86_400_000f64
+}
+
+/// Convert julian days as a 64-bit float to a unix timestamp in milliseconds, rounded to the nearest millisecond with ties to even.
+/// Use julian_day_to_unix_millis_rounded() for other rounding modes.
+/// The Julian Day is split into its calendar day and the fraction since midnight, and the day is converted with integer arithmetic.
+/// Round trips with unix_millis_to_julian_day() are exact to the millisecond up to JD 4194304 (about 6770 CE)
+/// and within a second across the whole i64 range, where an f64 can no longer resolve milliseconds.
+/// Results outside the i64 range saturate
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_unix_millis(2460558.25), 1_725_472_800_000);
+/// ```
+///
+pub fn julian_day_to_unix_millis(jd: f64) -> i64 {
+  julian_day_to_unix_millis_rounded(jd, RoundingMode::HalfEven)
+}
+
+///
+/// Convert a unix timestamp in microseconds to julian days as a 64-bit float.
+/// Whole days are converted separately from the time of day, so only the final sum is rounded
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(unix_micros_to_julian_day(1_725_451_200_000_000), 2460558.0);
+/// ```
+///
+pub fn unix_micros_to_julian_day(micros: i64) -> f64 {
+  let days = micros.div_euclid(86_400_000_000);
+  let micros_of_day = micros.rem_euclid(86_400_000_000);
+  JULIAN_DAY_UNIX_EPOCH_DAYS + days as f64 + micros_of_day as f64 / 86_400_000_000f64
+}
+
+/// Convert julian days as a 64-bit float to a unix timestamp in microseconds, rounded to the nearest microsecond with ties to even.
+/// A 64-bit Julian Day near the present epoch only resolves about 20 microseconds.
+/// The calendar day is converted with integer arithmetic and results outside the i64 range saturate
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_unix_micros(2460558.25), 1_725_472_800_000_000);
+/// ```
+///
+pub fn julian_day_to_unix_micros(jd: f64) -> i64 {
+  julian_day_to_unix_units(jd, 86_400_000_000, RoundingMode::HalfEven)
 }
 
 /// convert julian day as 64-bit float to a timezone-neutral chrono::NaiveDateTime object
@@ -53,8 +248,11 @@ pub fn julian_day_to_unixtime(jd: f64) -> i64 {
 /// ```
 ///
 pub fn julian_day_to_datetime(jd: f64) -> Result<NaiveDateTime, DateRangeCoversionError> {
-  if let Some(dt) = NaiveDateTime::from_timestamp_opt(julian_day_to_unixtime(jd), 0) {
-    Ok(dt)
+  if !is_finite_jd(jd) {
+    return Err(DateRangeCoversionError);
+  }
+  if let Some(dt) = DateTime::from_timestamp(julian_day_to_unixtime(jd), 0) {
+    Ok(dt.naive_utc())
   } else {
     Err(DateRangeCoversionError)
   }
@@ -75,7 +273,7 @@ pub fn julian_day_to_datetime(jd: f64) -> Result<NaiveDateTime, DateRangeCoversi
 ///
 pub fn datetime_to_julian_day(dt_str: &str) -> Result<f64, ParseError> {
   match iso_fuzzy_string_to_datetime(dt_str) {
-      Ok(dt) => Ok(unixtime_to_julian_day(dt.timestamp())),
+      Ok(dt) => Ok(unix_millis_to_julian_day(dt.and_utc().timestamp_millis())),
       Err(error) => Err(error)
   }
 }
@@ -96,6 +294,16 @@ pub trait JulianDay {
   */
   fn from_jd(jd: f64) -> Option<Self> where Self: Sized;
 
+  /*
+  * Convert from a Julian Day as f64 to DateTime Object, with the reason for failure
+  */
+  fn try_from_jd(jd: f64) -> Result<Self, JulianDayError> where Self: Sized {
+    if !is_finite_jd(jd) {
+      return Err(JulianDayError::NonFinite);
+    }
+    Self::from_jd(jd).ok_or(JulianDayError::OutOfRange)
+  }
+
 }
 
 ///
@@ -112,8 +320,44 @@ pub trait WeekdayIndex {
   /// East of UTC => Positive hour offset * 3600, e.g. +3600 => UTC+1
   ///
   fn weekday_index(&self, offset_secs: i32) -> u8;
+
+  ///
+  /// Current weekday as a chrono::Weekday with the same timezone offset rules as weekday_index()
+  /// Call as WeekdayIndex::weekday(&dt, offset_secs) if chrono::Datelike is also in scope
+  ///
+  fn weekday(&self, offset_secs: i32) -> Weekday {
+    weekday_index_to_weekday(self.weekday_index(offset_secs))
+  }
+
+  ///
+  /// Weekday index counted from the given first day of the week, e.g. Monday = 0 ... Sunday = 6 with WeekStart::Monday
+  ///
+  fn weekday_index_from(&self, offset_secs: i32, week_start: WeekStart) -> u8 {
+    weekday_index_from_week_start(self.weekday_index(offset_secs), week_start)
+  }
 }
 
+///
+/// First day of the week for locale-aligned weekday indices
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekStart {
+  #[default]
+  Sunday,
+  Monday,
+  Saturday,
+}
+
+impl WeekStart {
+  /// Weekday index (Sun = 0 ... Sat = 6) of the first day of the week
+  pub fn weekday_index(&self) -> u8 {
+    match self {
+      WeekStart::Sunday => 0,
+      WeekStart::Monday => 1,
+      WeekStart::Saturday => 6,
+    }
+  }
+}
 
 /*
 * This trait may be implemented by any Date or DateTime object
@@ -126,6 +370,8 @@ pub trait FromFuzzyISOString {
   /// Valid formats
   /// Full date-time: e.g. 2023-11-15T17:53:26
   /// with optional millisecends (ignored): e.g. 2023-11-15T17:53:26.383Z
+  /// with a 12-hour clock time: e.g. 2023-11-15 5:53 PM
+  /// with a UTC offset, which is applied: e.g. 2023-11-15T17:53:26+05:30 or 2023-11-15 17:53 -0800
   /// with space rather than T: 2023-11-15 17:53:26
   /// without seconds: 2023-11-15T17:53 (rounded to the start of the minute)
   /// without minutes: 2023-11-15T17 (rounded to the top of the hour)
@@ -151,7 +397,7 @@ impl JulianDay for NaiveDateTime {
   /// }
   /// ```
   fn to_jd(&self) -> f64 {
-    unixtime_to_julian_day(self.timestamp())
+    unixtime_to_julian_day(self.and_utc().timestamp())
   }
 
   /// construct a DateTime object from a Julian day value (64-bit float)
@@ -167,22 +413,14 @@ impl JulianDay for NaiveDateTime {
   /// }
   /// ```
   fn from_jd(jd: f64) -> Option<Self> {
-    if let Ok(dt) = julian_day_to_datetime(jd) {
-      Some(dt)
-    } else {
-      None
-    }
+    julian_day_to_datetime(jd).ok()
   }
 }
 
 impl FromFuzzyISOString for NaiveDateTime {
   /// construct a DateTime object from an exact or approximate ISO-8601-compatible string
   fn from_fuzzy_iso_string(dt_str: &str) -> Option<Self> {
-    if let Ok(dt) = iso_fuzzy_string_to_datetime(dt_str) {
-      Some(dt)
-    } else {
-      None
-    }
+    iso_fuzzy_string_to_datetime(dt_str).ok()
   }
 }
 
@@ -193,22 +431,121 @@ impl WeekdayIndex for NaiveDateTime {
   }
 }
 
+/// Calculate the weekday index from a given Julian Day with timezone offsets in seconds.
+/// Days are floored and wrapped with Euclidean modulo, so dates before 1970 and BCE Julian Days are also supported
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
 ///
-/// Calculate the weekday index from a given Julian Day with timezone offsets in seconds
+/// // Julian Day 0 began at noon on Monday 24 November 4714 BC (proleptic Gregorian)
+/// assert_eq!(julian_day_to_weekday_index(0.0, 0), 1);
+/// ```
 ///
 pub fn julian_day_to_weekday_index(jd: f64, offset_secs: i32) -> u8 {
-	let ref_jd = jd + (offset_secs as f64 / 86400f64);
-	let days_since_1970 = ref_jd - JULIAN_DAY_UNIX_EPOCH_DAYS as f64;
-  let ds = (days_since_1970 as u64) % 7;
-  let days_since_index = if ds < 7 { ds as u8 } else { 0u8 };
-	(days_since_index + JULIAN_DAY_UNIX_EPOCH_WEEKDAY) % 7
+  let ref_jd = jd + (offset_secs as f64 / 86400f64);
+  // wrap in f64 before casting so that huge Julian Days cannot overflow, while NaN casts to 0
+  let weekday_offset = (ref_jd - JULIAN_DAY_UNIX_EPOCH_DAYS).floor().rem_euclid(7.0) as u8;
+  (weekday_offset + JULIAN_DAY_UNIX_EPOCH_WEEKDAY) % 7
+}
+
+/// Calculate the weekday index from a given Julian Day with timezone offsets in seconds, counted from the given first day of the week
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Sunday 2022-09-04 is the last day of a week starting on Monday and the 2nd of a week starting on Saturday
+/// assert_eq!(julian_day_to_weekday_index_from(2459827.25, 0, WeekStart::Monday), 6);
+/// assert_eq!(julian_day_to_weekday_index_from(2459827.25, 0, WeekStart::Saturday), 1);
+/// ```
+///
+pub fn julian_day_to_weekday_index_from(jd: f64, offset_secs: i32, week_start: WeekStart) -> u8 {
+  weekday_index_from_week_start(julian_day_to_weekday_index(jd, offset_secs), week_start)
+}
+
+///
+/// Shift a weekday index (Sun = 0 ... Sat = 6) so that the first day of the week is 0
+///
+fn weekday_index_from_week_start(index: u8, week_start: WeekStart) -> u8 {
+  (index + 7 - week_start.weekday_index()) % 7
+}
+
+/// Calculate the weekday index (Sun = 0, Mon = 1 ... Sat = 6) directly from a unix timestamp in seconds
+/// with timezone offsets in seconds, using integer arithmetic only
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 1969-12-31 23:00 UTC was a Wednesday, but already Thursday at UTC+2
+/// assert_eq!(unixtime_to_weekday_index(-3600, 0), 3);
+/// assert_eq!(unixtime_to_weekday_index(-3600, 7200), 4);
+/// ```
+///
+pub fn unixtime_to_weekday_index(ts: i64, offset_secs: i32) -> u8 {
+  let days_since_1970 = (ts as i128 + offset_secs as i128).div_euclid(86_400);
+  (days_since_1970 + JULIAN_DAY_UNIX_EPOCH_WEEKDAY as i128).rem_euclid(7) as u8
+}
+
+///
+/// Calculate the weekday index (Sun = 0, Mon = 1 ... Sat = 6) from a unix timestamp in milliseconds with timezone offsets in seconds
+///
+pub fn unix_millis_to_weekday_index(ms: i64, offset_secs: i32) -> u8 {
+  unixtime_to_weekday_index(ms.div_euclid(1000), offset_secs)
+}
+
+/// Calculate the chrono::Weekday from a given Julian Day with timezone offsets in seconds
+///
+/// ### Example:
+/// ```
+/// use chrono::Weekday;
+/// use julian_day_converter::*;
+///
+/// // 2022-09-04 18:00 UTC was a Sunday, but already Monday at UTC+10
+/// assert_eq!(julian_day_to_weekday(2459827.25, 0), Weekday::Sun);
+/// assert_eq!(julian_day_to_weekday(2459827.25, 36000), Weekday::Mon);
+/// ```
+///
+pub fn julian_day_to_weekday(jd: f64, offset_secs: i32) -> Weekday {
+  weekday_index_to_weekday(julian_day_to_weekday_index(jd, offset_secs))
+}
+
+///
+/// Convert a weekday index (Sun = 0, Mon = 1 ... Sat = 6) to a chrono::Weekday
+///
+pub fn weekday_index_to_weekday(index: u8) -> Weekday {
+  match index % 7 {
+    0 => Weekday::Sun,
+    1 => Weekday::Mon,
+    2 => Weekday::Tue,
+    3 => Weekday::Wed,
+    4 => Weekday::Thu,
+    5 => Weekday::Fri,
+    _ => Weekday::Sat,
+  }
 }
 
 ///
 /// Utility function to convert any ISO-8601-like date string to a Chrono NaiveDateTime object
 /// This function accepts YYYY-mm-dd HH:MM:SS separated by a space or letter T and with or without hours, minutes or seconds.
-/// Missing time parts will be replaced by 00, hence 2022-06-23 will be 2022-06-23 00:00:00 UTC and 22-06-23 18:20 will be 2022-06-23 18:30:00
+/// Missing time parts will be replaced by 00, hence 2022-06-23 will be 2022-06-23 00:00:00 UTC and 22-06-23 18:20 will be 2022-06-23 18:20:00,
+/// as two-digit years fall in the window from 1970 to 2069 unless set otherwise with iso_fuzzy_string_to_datetime_with()
 /// Missing month and day parts will be replaced by `01`.
+/// Republic of China (Minguo) dates such as 民國113年9月4日 or ROC 113/09/04 are also accepted.
+/// Ordinal dates with a three-digit day of year, e.g. 2022-247 or 2022-247T14:30, are read as the equivalent calendar date.
+/// Numeric dates with slashes are accepted when the order of day and month is clear, e.g. 25/12/2022, 12/25/2022 or 2022/12/25,
+/// see iso_fuzzy_string_to_datetime_with() to set the order for dates such as 04/11/1877.
+/// English month names and abbreviations are accepted with an optional weekday and ordinal day, e.g. 4 Sep 2022,
+/// September 4th, 2022 or 04-SEP-2022.
+/// Unix timestamps are accepted as @ followed by seconds, e.g. @1662310800, or as bare 10-digit seconds or 13-digit milliseconds.
+/// Relative dates such as now, today, yesterday, tomorrow, +3 days, -2 weeks, in 6 hours or 2 days ago are resolved
+/// against the current time, or the reference time set with iso_fuzzy_string_to_datetime_with().
+/// Times on the 12-hour clock are accepted with an am or pm suffix, e.g. 2022-09-04 6:30 PM or 2022-09-04 6.30pm.
+/// A trailing Z, UTC, GMT or numeric offset such as +05:30 or -0800 after the time is applied, so the result is in UTC.
+/// Without one the time is assumed to be UTC already. Other timezone abbreviations such as EST are rejected
+/// unless a table is set with FuzzyParseOptions::with_tz_abbreviations().
+/// Years may carry a BC/BCE or AD/CE label, e.g. 44 BC or 0044-03-15 BC, which is equivalent to the astronomical year -0043.
 /// 
 /// ## Example:
 /// ```
@@ -221,20 +558,130 @@ pub fn julian_day_to_weekday_index(jd: f64, offset_secs: i32) -> u8 {
 /// ```
 ///
 pub fn iso_fuzzy_string_to_datetime(dt: &str) -> Result<NaiveDateTime, ParseError> {
+  iso_fuzzy_string_to_datetime_with(dt, &FuzzyParseOptions::default())
+}
+
+/// Convert a fuzzy date-time string to a NaiveDateTime in UTC as iso_fuzzy_string_to_datetime() with parser options,
+/// e.g. the order of day and month in slash-separated dates or the reference time for relative dates
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let options = FuzzyParseOptions::default().with_date_order(DateOrder::DMY);
+/// let dt = iso_fuzzy_string_to_datetime_with("04/11/1877 12:00", &options).unwrap();
+/// assert_eq!(dt.to_string(), "1877-11-04 12:00:00");
+/// // without a date order the day and month could be swapped
+/// assert!(iso_fuzzy_string_to_datetime("04/11/1877").is_err());
+/// ```
+///
+pub fn iso_fuzzy_string_to_datetime_with(dt: &str, options: &FuzzyParseOptions) -> Result<NaiveDateTime, ParseError> {
+  if options.numeric_hint == Some(NumericHint::ExcelSerial) {
+    if let Some(excel_dt) = excel_serial_string_to_datetime(dt) {
+      return excel_dt.ok_or_else(out_of_range_error);
+    }
+  }
+  if let Some(epoch_dt) = epoch_string_to_datetime(dt) {
+    return epoch_dt.ok_or_else(out_of_range_error);
+  }
+  let reference = options.reference.unwrap_or_else(|| Utc::now().naive_utc());
+  if let Some(relative) = resolve_relative_keyword(dt, reference) {
+    return Ok(relative);
+  }
+  let (local_dt, offset_secs) = split_offset_with_options(dt, options).ok_or_else(out_of_range_error)?;
+  let local = NaiveDateTime::parse_from_str(normalize_fuzzy_iso_string_with(local_dt, options).as_str(), "%Y-%m-%d %H:%M:%S")?;
+  let local = complete_partial_date(local_dt, local, options.partial_date).ok_or_else(out_of_range_error)?;
+  match offset_secs {
+    Some(secs) => local.checked_sub_signed(Duration::seconds(secs as i64)).ok_or_else(out_of_range_error),
+    None => Ok(local),
+  }
+}
+
+///
+/// Split a trailing Z, UTC, numeric offset or timezone abbreviation from a date-time string. Without a table in the options
+/// only GMT and UT are read as abbreviations. Returns None if the offset is invalid or the abbreviation unknown or ambiguous
+///
+pub(crate) fn split_offset_with_options<'a>(dt: &'a str, options: &FuzzyParseOptions) -> Option<(&'a str, Option<i32>)> {
+  let (local_dt, offset_secs) = split_utc_offset(dt)?;
+  match offset_secs {
+    Some(_) => Some((local_dt, offset_secs)),
+    None => split_tz_abbreviation(local_dt, &options.tz_abbreviations.unwrap_or(UTC_NAMES)).ok(),
+  }
+}
+
+///
+/// chrono's out-of-range parse error for values that parse but cannot be represented after adjustment
+///
+fn out_of_range_error() -> ParseError {
+  NaiveDateTime::parse_from_str("2000-13-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap_err()
+}
+
+///
+/// Complete a fuzzy ISO-8601-like string to the full YYYY-mm-dd HH:MM:SS form without validating the values.
+/// A leading minus sign is kept with the year for astronomical year numbering, e.g. -0043-03-15,
+/// and historical years with an era label, e.g. 0044-03-15 BC, are converted to astronomical years.
+/// Minguo dates prefixed with 民國 or ROC, e.g. 民國113年9月4日, and Thai Buddhist Era dates labelled พ.ศ. or BE,
+/// e.g. 2567-09-04 BE, are converted to the Common Era
+/// and ordinal dates such as 2022-247 to calendar dates.
+/// English month names, e.g. 4 Sep 2022, and 12-hour clock times, e.g. 6:30 pm, are converted to numeric form.
+/// Two-digit years are kept as years 0 to 99, as the calendar and era parsers using this read years in their own numbering
+///
+pub(crate) fn normalize_fuzzy_iso_string(dt: &str) -> String {
+  normalize_fuzzy_iso_string_with(dt, &FuzzyParseOptions::default().with_two_digit_year_pivot(None))
+}
+
+///
+/// Complete a fuzzy ISO-8601-like string as normalize_fuzzy_iso_string() with parser options
+///
+pub(crate) fn normalize_fuzzy_iso_string_with(dt: &str, options: &FuzzyParseOptions) -> String {
+  if let Some(numeric_dt) = month_name_to_numeric_string(dt, &LocaleNames::ENGLISH) {
+    return normalize_fuzzy_iso_string_with(&numeric_dt, options);
+  }
+  if let Some(astronomical_dt) = historical_to_astronomical_string(dt) {
+    return normalize_fuzzy_iso_string_with(&astronomical_dt, options);
+  }
+  if let Some(ce_dt) = minguo_to_common_era_string(dt) {
+    return normalize_fuzzy_iso_string_with(&ce_dt, options);
+  }
+  if let Some(iso_dt) = slash_date_to_iso_string(dt, options.date_order) {
+    return normalize_fuzzy_iso_string_with(&iso_dt, options);
+  }
+  if let Some(ce_dt) = buddhist_era_to_common_era_string(dt) {
+    return normalize_fuzzy_iso_string_with(&ce_dt, options);
+  }
+  if let Some(calendar_dt) = ordinal_to_calendar_string(dt) {
+    return normalize_fuzzy_iso_string_with(&calendar_dt, options);
+  }
+  if let Some(time_24_dt) = twelve_hour_to_24_hour_string(dt) {
+    return normalize_fuzzy_iso_string_with(&time_24_dt, options);
+  }
   let dt_base = if dt.contains('.') { dt.split(".").next().unwrap() } else { dt };
-  let clean_dt = dt_base.replace("T", " ").trim().to_string();
+  let trimmed_dt = dt_base.replace("T", " ").trim().to_string();
+  let (year_sign, clean_dt) = match trimmed_dt.strip_prefix('-') {
+    Some(unsigned_dt) => ("-", unsigned_dt.to_string()),
+    None => ("", trimmed_dt.clone()),
+  };
   let mut dt_parts = clean_dt.split(" ");
   let mut date_part = if clean_dt.clone().contains(" ") { dt_parts.next().unwrap().to_string() } else { clean_dt.clone() };
-  let mut date_parts: Vec<&str> = if date_part.len() > 1 { date_part.split("-").into_iter().collect() } else { vec!("2000", "01", "01") };
+  let mut date_parts: Vec<&str> = if date_part.len() > 1 { date_part.split("-").collect() } else { vec!("2000", "01", "01") };
   if date_parts.len() < 2 { 
     date_parts.push("01");
    }
   if date_parts.len() < 3 { 
     date_parts.push("01");
   }
-  date_part = format!("{}-{}-{}", date_parts[0], date_parts[1], date_parts[2]);
+  let expanded_year = match options.two_digit_year_pivot {
+    Some(pivot) if year_sign.is_empty() && date_parts[0].len() == 2 && date_part.contains('-') => {
+      date_parts[0].parse().map(|year| expand_two_digit_year(year, pivot).to_string()).ok()
+    }
+    _ => None,
+  };
+  if let Some(year) = expanded_year.as_deref() {
+    date_parts[0] = year;
+  }
+  date_part = format!("{}{}-{}-{}", year_sign, date_parts[0], date_parts[1], date_parts[2]);
   let time_part = if clean_dt.clone().contains(" ") { dt_parts.next().unwrap().to_string() } else { "".to_string() };
-  let mut time_parts = if time_part.len() > 1 { time_part.split(":").into_iter().collect() } else { vec!("00", "00", "00") };
+  let mut time_parts = if time_part.len() > 1 { time_part.split(":").collect() } else { vec!("00", "00", "00") };
   let num_time_parts = time_parts.len();
   if num_time_parts < 3 { 
     time_parts.push("00");
@@ -242,7 +689,5 @@ pub fn iso_fuzzy_string_to_datetime(dt: &str) -> Result<NaiveDateTime, ParseErro
   if num_time_parts < 2 {
     time_parts.push("00");
   }
-  let formatted_str = format!("{} {}:{}:{}", date_part, time_parts[0], time_parts[1], time_parts[2]);
-  NaiveDateTime::parse_from_str(formatted_str.as_str(), "%Y-%m-%d %H:%M:%S")
+  format!("{} {}:{}:{}", date_part, time_parts[0], time_parts[1], time_parts[2])
 }
-
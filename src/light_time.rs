@@ -0,0 +1,141 @@
+use crate::astro::*;
+
+/// Light travel time for one astronomical unit in days
+pub const LIGHT_TIME_AU_DAYS: f64 = 499.004784 / 86400.0;
+
+/// Mean obliquity of the ecliptic at J2000.0 in degrees
+const J2000_OBLIQUITY_DEG: f64 = 23.4392911;
+
+/// Difference between Terrestrial Time and International Atomic Time in seconds
+const TT_MINUS_TAI_SECS: f64 = 32.184;
+
+/// Julian Days (UTC) from which each cumulative TAI - UTC offset in seconds applies
+const LEAP_SECONDS: [(f64, f64); 28] = [
+  (2441317.5, 10.0), // 1972-01-01
+  (2441499.5, 11.0), // 1972-07-01
+  (2441683.5, 12.0), // 1973-01-01
+  (2442048.5, 13.0), // 1974-01-01
+  (2442413.5, 14.0), // 1975-01-01
+  (2442778.5, 15.0), // 1976-01-01
+  (2443144.5, 16.0), // 1977-01-01
+  (2443509.5, 17.0), // 1978-01-01
+  (2443874.5, 18.0), // 1979-01-01
+  (2444239.5, 19.0), // 1980-01-01
+  (2444786.5, 20.0), // 1981-07-01
+  (2445151.5, 21.0), // 1982-07-01
+  (2445516.5, 22.0), // 1983-07-01
+  (2446247.5, 23.0), // 1985-07-01
+  (2447161.5, 24.0), // 1988-01-01
+  (2447892.5, 25.0), // 1990-01-01
+  (2448257.5, 26.0), // 1991-01-01
+  (2448804.5, 27.0), // 1992-07-01
+  (2449169.5, 28.0), // 1993-07-01
+  (2449534.5, 29.0), // 1994-07-01
+  (2450083.5, 30.0), // 1996-01-01
+  (2450630.5, 31.0), // 1997-07-01
+  (2451179.5, 32.0), // 1999-01-01
+  (2453736.5, 33.0), // 2006-01-01
+  (2454832.5, 34.0), // 2009-01-01
+  (2456109.5, 35.0), // 2012-07-01
+  (2457204.5, 36.0), // 2015-07-01
+  (2457754.5, 37.0), // 2017-01-01
+];
+
+///
+/// Cumulative leap seconds (TAI - UTC) at a Julian Day in UTC. Dates before 1972 use the initial 10 second offset
+///
+pub(crate) fn tai_minus_utc(jd_utc: f64) -> f64 {
+  LEAP_SECONDS.iter().rev().find(|(start, _)| jd_utc >= *start).map(|(_, secs)| *secs).unwrap_or(10.0)
+}
+
+///
+/// Unit vector towards a target with right ascension and declination in degrees (J2000.0 equatorial)
+///
+fn target_unit_vector(ra_deg: f64, dec_deg: f64) -> [f64; 3] {
+  let (ra, dec) = (ra_deg.to_radians(), dec_deg.to_radians());
+  [dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin()]
+}
+
+///
+/// Rotate a vector in J2000.0 ecliptic coordinates into J2000.0 equatorial coordinates
+///
+fn ecliptic_to_equatorial(v: [f64; 3]) -> [f64; 3] {
+  let eps = J2000_OBLIQUITY_DEG.to_radians();
+  [v[0], v[1] * eps.cos() - v[2] * eps.sin(), v[1] * eps.sin() + v[2] * eps.cos()]
+}
+
+///
+/// Heliocentric position of the Earth in AU (J2000.0 equatorial), from the low-precision solar model
+///
+fn earth_heliocentric_position(jd: f64) -> [f64; 3] {
+  let t = julian_centuries(jd);
+  // the Earth is opposite the Sun; subtract general precession to refer the longitude to J2000.0
+  let lng = (sun_true_longitude(t) + 180.0 - 1.397 * t).to_radians();
+  let r = sun_distance_au(t);
+  ecliptic_to_equatorial([r * lng.cos(), r * lng.sin(), 0.0])
+}
+
+///
+/// Position of the Sun relative to the solar system barycentre in AU (J2000.0 equatorial),
+/// approximated from the mean circular orbits of Jupiter and Saturn
+///
+fn sun_barycentric_position(jd: f64) -> [f64; 3] {
+  let t = julian_centuries(jd);
+  // (mean longitude at J2000.0, degrees per century, semi-major axis in AU, planet mass / (Sun + planet mass))
+  let planets = [
+    (34.39644, 3034.74612775, 5.20288700, 1.0 / 1048.3486),
+    (49.95424, 1222.49362201, 9.53667594, 1.0 / 3498.898),
+  ];
+  let mut offset = [0.0; 3];
+  for (l0, rate, a, mass_ratio) in planets {
+    let lng = (l0 + rate * t).to_radians();
+    offset[0] -= mass_ratio * a * lng.cos();
+    offset[1] -= mass_ratio * a * lng.sin();
+  }
+  ecliptic_to_equatorial(offset)
+}
+
+///
+/// Light travel time in days for the projection of a position vector in AU onto the direction of a target
+///
+fn romer_delay_days(position: [f64; 3], ra_deg: f64, dec_deg: f64) -> f64 {
+  let n = target_unit_vector(ra_deg, dec_deg);
+  (position[0] * n[0] + position[1] * n[1] + position[2] * n[2]) * LIGHT_TIME_AU_DAYS
+}
+
+/// Heliocentric Julian Day, correcting a geocentric Julian Day for the light travel time (Rømer delay)
+/// between the Earth and the Sun in the direction of a target with J2000.0 right ascension and declination in degrees.
+/// The result remains in the time scale of the input, usually UTC, and is accurate to within about a second.
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Algol (RA 47.04º, Dec +40.96º)
+/// let heliocentric_jd = hjd(2460258.488768587, 47.04, 40.96);
+/// ```
+///
+pub fn hjd(jd: f64, ra_deg: f64, dec_deg: f64) -> f64 {
+  jd + romer_delay_days(earth_heliocentric_position(jd), ra_deg, dec_deg)
+}
+
+/// Barycentric Julian Day in Barycentric Dynamical Time (BJD_TDB) for a Julian Day in UTC and a target with
+/// J2000.0 right ascension and declination in degrees. This applies leap seconds and the TT offset, the Rømer delay
+/// relative to the solar system barycentre and the periodic TDB - TT term, and is accurate to within about a second.
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let barycentric_jd = bjd_tdb(2460258.488768587, 47.04, 40.96);
+/// ```
+///
+pub fn bjd_tdb(jd_utc: f64, ra_deg: f64, dec_deg: f64) -> f64 {
+  let jd_tt = jd_utc + (tai_minus_utc(jd_utc) + TT_MINUS_TAI_SECS) / 86400.0;
+  let g = (357.53 + 0.98560028 * (jd_tt - JULIAN_DAY_J2000)).to_radians();
+  let tdb_minus_tt_secs = 0.001657 * g.sin() + 0.000014 * (2.0 * g).sin();
+  let earth = earth_heliocentric_position(jd_tt);
+  let sun = sun_barycentric_position(jd_tt);
+  let position = [earth[0] + sun[0], earth[1] + sun[1], earth[2] + sun[2]];
+  jd_tt + tdb_minus_tt_secs / 86400.0 + romer_delay_days(position, ra_deg, dec_deg)
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::civil::*;
+use crate::{is_supported_jd, JulianDayError};
+
+/// Goodman-Martinez-Thompson (GMT) correlation constant: the Julian Day Number of the Long Count creation date 0.0.0.0.0
+pub const GMT_CORRELATION: i64 = 584283;
+
+/// Tzolk'in day names from Imix (1) to Ajaw (20)
+pub const TZOLKIN_NAMES: [&str; 20] = [
+  "Imix", "Ik'", "Ak'bal", "K'an", "Chikchan", "Kimi", "Manik'", "Lamat", "Muluk", "Ok",
+  "Chuwen", "Eb", "Ben", "Ix", "Men", "K'ib", "Kaban", "Etz'nab", "Kawak", "Ajaw",
+];
+
+/// Haab' month names from Pop (1) to the five-day Wayeb (19)
+pub const HAAB_MONTH_NAMES: [&str; 19] = [
+  "Pop", "Wo'", "Sip", "Sotz'", "Sek", "Xul", "Yaxk'in", "Mol", "Ch'en", "Yax",
+  "Sak'", "Keh", "Mak", "K'ank'in", "Muwan", "Pax", "K'ayab", "Kumk'u", "Wayeb",
+];
+
+///
+/// Mayan Long Count date as baktun.katun.tun.uinal.kin, counting days from the creation date 0.0.0.0.0 (also written 13.0.0.0.0)
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LongCount {
+  pub baktun: i32,
+  pub katun: u8,
+  pub tun: u8,
+  pub uinal: u8,
+  pub kin: u8,
+}
+
+impl LongCount {
+  /// Construct a Long Count date, or None if the katun, tun, uinal or kin is out of range
+  pub fn new(baktun: i32, katun: u8, tun: u8, uinal: u8, kin: u8) -> Option<Self> {
+    if katun < 20 && tun < 20 && uinal < 18 && kin < 20 {
+      Some(LongCount { baktun, katun, tun, uinal, kin })
+    } else {
+      None
+    }
+  }
+
+  /// Number of days since the creation date
+  pub fn days(&self) -> i64 {
+    self.baktun as i64 * 144000 + self.katun as i64 * 7200 + self.tun as i64 * 360 + self.uinal as i64 * 20 + self.kin as i64
+  }
+
+  /// Long Count date for a number of days since the creation date
+  pub fn from_days(days: i64) -> Self {
+    let baktun = days.div_euclid(144000) as i32;
+    let rem = days.rem_euclid(144000);
+    LongCount {
+      baktun,
+      katun: (rem / 7200) as u8,
+      tun: (rem % 7200 / 360) as u8,
+      uinal: (rem % 360 / 20) as u8,
+      kin: (rem % 20) as u8,
+    }
+  }
+}
+
+impl fmt::Display for LongCount {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}.{}.{}.{}.{}", self.baktun, self.katun, self.tun, self.uinal, self.kin)
+  }
+}
+
+impl FromStr for LongCount {
+  type Err = JulianDayError;
+
+  /// parse a dotted Long Count such as 13.0.0.0.0, failing with InvalidFormat if it has the wrong shape or a unit is out of range
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let parts: Vec<&str> = s.trim().split('.').collect();
+    if parts.len() != 5 {
+      return Err(JulianDayError::InvalidFormat);
+    }
+    let baktun = parts[0].parse::<i32>().map_err(|_| JulianDayError::InvalidFormat)?;
+    let units: Vec<u8> = parts[1..].iter().map(|part| part.parse::<u8>()).collect::<Result<_, _>>().map_err(|_| JulianDayError::InvalidFormat)?;
+    LongCount::new(baktun, units[0], units[1], units[2], units[3]).ok_or(JulianDayError::InvalidFormat)
+  }
+}
+
+///
+/// Position in the 260-day Tzolk'in cycle, e.g. 4 Ajaw
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tzolkin {
+  number: u8,
+  name_index: u8,
+}
+
+impl Tzolkin {
+  /// Construct a Tzolk'in position, or None if the number is not from 1 to 13 or the name index is not from 1 to 20
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(Tzolkin::new(4, 20).unwrap().to_string(), "4 Ajaw");
+  /// assert_eq!(Tzolkin::new(1, 0), None);
+  /// ```
+  pub fn new(number: u8, name_index: u8) -> Option<Self> {
+    ((1..=13).contains(&number) && (1..=20).contains(&name_index)).then_some(Tzolkin { number, name_index })
+  }
+
+  /// Day number from 1 to 13
+  pub fn number(&self) -> u8 {
+    self.number
+  }
+
+  /// Day name index from 1 (Imix) to 20 (Ajaw)
+  pub fn name_index(&self) -> u8 {
+    self.name_index
+  }
+
+  pub fn name(&self) -> &'static str {
+    TZOLKIN_NAMES[self.name_index as usize - 1]
+  }
+}
+
+impl fmt::Display for Tzolkin {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} {}", self.number, self.name())
+  }
+}
+
+///
+/// Position in the 365-day Haab' cycle, e.g. 3 K'ank'in
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Haab {
+  day: u8,
+  month: u8,
+}
+
+impl Haab {
+  /// Construct a Haab' position, or None if the month is not from 1 to 19 or the day exceeds 19 (4 in Wayeb)
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(Haab::new(3, 14).unwrap().to_string(), "3 K'ank'in");
+  /// assert_eq!(Haab::new(5, 19), None);
+  /// ```
+  pub fn new(day: u8, month: u8) -> Option<Self> {
+    let max_day = if month == 19 { 4 } else { 19 };
+    ((1..=19).contains(&month) && day <= max_day).then_some(Haab { day, month })
+  }
+
+  /// Day of the month from 0 to 19 (0 to 4 in Wayeb)
+  pub fn day(&self) -> u8 {
+    self.day
+  }
+
+  /// Month index from 1 (Pop) to 19 (Wayeb)
+  pub fn month(&self) -> u8 {
+    self.month
+  }
+
+  pub fn month_name(&self) -> &'static str {
+    HAAB_MONTH_NAMES[self.month as usize - 1]
+  }
+}
+
+impl fmt::Display for Haab {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} {}", self.day, self.month_name())
+  }
+}
+
+/// Convert a Julian Day to a Long Count date with a correlation constant, usually GMT_CORRELATION (584283).
+/// Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let long_count = julian_day_to_long_count(2456282.5, GMT_CORRELATION).unwrap(); // 2012-12-21
+/// assert_eq!(long_count.to_string(), "13.0.0.0.0");
+/// ```
+///
+pub fn julian_day_to_long_count(jd: f64, correlation: i64) -> Option<LongCount> {
+  is_supported_jd(jd).then(|| LongCount::from_days(jd_to_jdn(jd) - correlation))
+}
+
+/// Convert a Long Count date to the Julian Day at 00:00 UTC with a correlation constant
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Pakal's accession, 24 July 615 in the Julian calendar with the GMT correlation
+/// let long_count: LongCount = "9.9.2.4.8".parse().unwrap();
+/// let jd = long_count_to_julian_day(&long_count, GMT_CORRELATION);
+/// assert_eq!(jd, 1945890.5);
+/// assert_eq!(julian_day_to_julian_calendar(jd), Some((615, 7, 24)));
+/// ```
+///
+pub fn long_count_to_julian_day(long_count: &LongCount, correlation: i64) -> f64 {
+  (long_count.days() + correlation) as f64 - 0.5
+}
+
+///
+/// Tzolk'in date for a Julian Day, where the creation date was 4 Ajaw, or None for NaN, infinite or unsupported Julian Days
+///
+pub fn julian_day_to_tzolkin(jd: f64, correlation: i64) -> Option<Tzolkin> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let days = jd_to_jdn(jd) - correlation;
+  Some(Tzolkin {
+    number: ((days + 3).rem_euclid(13) + 1) as u8,
+    name_index: ((days + 19).rem_euclid(20) + 1) as u8,
+  })
+}
+
+///
+/// Haab' date for a Julian Day, where the creation date was 8 Kumk'u, or None for NaN, infinite or unsupported Julian Days
+///
+pub fn julian_day_to_haab(jd: f64, correlation: i64) -> Option<Haab> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let count = (jd_to_jdn(jd) - correlation + 348).rem_euclid(365);
+  Some(Haab { day: (count % 20) as u8, month: (count / 20 + 1) as u8 })
+}
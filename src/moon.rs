@@ -0,0 +1,162 @@
+use crate::astro::*;
+use crate::is_supported_jd;
+
+/// Mean length of the synodic month (new moon to new moon) in days
+pub const SYNODIC_MONTH_DAYS: f64 = 29.530588861;
+
+/// Most lunation indices tried after the mean estimate before the phase search gives up
+const MAX_PHASE_SEARCH_STEPS: i64 = 4096;
+
+/// Offset between Meeus' lunation index (k = 0 at the new moon of 2000-01-06) and the Brown Lunation Number
+const BROWN_LUNATION_OFFSET: i64 = 953;
+
+///
+/// Geocentric apparent longitude of the Moon in degrees using the principal periodic terms of Meeus, chapter 47
+///
+fn moon_longitude(t: f64) -> f64 {
+  let l = 218.3164477 + 481267.88123421 * t;
+  let d = (297.8501921 + 445267.1114034 * t).to_radians();
+  let m = (357.5291092 + 35999.0502909 * t).to_radians();
+  let mp = (134.9633964 + 477198.8675055 * t).to_radians();
+  let f = (93.2720950 + 483202.0175233 * t).to_radians();
+  let terms = 6.288774 * mp.sin()
+    + 1.274027 * (2.0 * d - mp).sin()
+    + 0.658314 * (2.0 * d).sin()
+    + 0.213618 * (2.0 * mp).sin()
+    - 0.185116 * m.sin()
+    - 0.114332 * (2.0 * f).sin()
+    + 0.058793 * (2.0 * d - 2.0 * mp).sin()
+    + 0.057066 * (2.0 * d - m - mp).sin()
+    + 0.053322 * (2.0 * d + mp).sin()
+    + 0.045758 * (2.0 * d - m).sin()
+    - 0.040923 * (m - mp).sin()
+    - 0.034720 * d.sin()
+    - 0.030383 * (m + mp).sin();
+  normalize_degrees(l + terms)
+}
+
+/// Fraction of the current lunation elapsed, where 0.0 = new moon, 0.25 = first quarter, 0.5 = full moon and 0.75 = last quarter,
+/// derived from the elongation of the Moon from the Sun
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let phase = moon_phase_fraction(2460258.488768587);
+/// ```
+///
+pub fn moon_phase_fraction(jd: f64) -> f64 {
+  let t = julian_centuries(jd);
+  normalize_degrees(moon_longitude(t) - sun_apparent_longitude(t)) / 360.0
+}
+
+///
+/// Illuminated fraction of the Moon's disc from 0.0 (new) to 1.0 (full)
+///
+pub fn moon_illumination(jd: f64) -> f64 {
+  (1.0 - (moon_phase_fraction(jd) * std::f64::consts::TAU).cos()) / 2.0
+}
+
+///
+/// Approximate age of the Moon in days since the last new moon, based on the mean synodic month
+///
+pub fn moon_age_days(jd: f64) -> f64 {
+  moon_phase_fraction(jd) * SYNODIC_MONTH_DAYS
+}
+
+///
+/// Julian Day (TT) of the new moon (phase = 0.0) or full moon (phase = 0.5) for lunation index k
+/// counted from the new moon of 2000-01-06 (Meeus, chapter 49)
+///
+fn lunar_phase_jd(k: i64, phase: f64) -> f64 {
+  let k = k as f64 + phase;
+  let t = k / 1236.85;
+  let t2 = t * t;
+  let t3 = t2 * t;
+  let mean_jd = 2451550.09766 + SYNODIC_MONTH_DAYS * k + 0.00015437 * t2 - 0.000000150 * t3 + 0.00000000073 * t3 * t;
+  let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+  let m = (2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3).to_radians();
+  let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t3 * t).to_radians();
+  let f = (160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t3 * t).to_radians();
+  let omega = (124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3).to_radians();
+  let (c1, c2, c3, c4, c5, c6, c7) = if phase == 0.0 {
+    (-0.40720, 0.17241, 0.01608, 0.01039, 0.00739, -0.00514, 0.00208)
+  } else {
+    (-0.40614, 0.17302, 0.01614, 0.01043, 0.00734, -0.00515, 0.00209)
+  };
+  let correction = c1 * mp.sin()
+    + c2 * e * m.sin()
+    + c3 * (2.0 * mp).sin()
+    + c4 * (2.0 * f).sin()
+    + c5 * e * (mp - m).sin()
+    + c6 * e * (mp + m).sin()
+    + c7 * e * e * (2.0 * m).sin()
+    - 0.00111 * (mp - 2.0 * f).sin()
+    - 0.00057 * (mp + 2.0 * f).sin()
+    + 0.00056 * e * (2.0 * mp + m).sin()
+    - 0.00042 * (3.0 * mp).sin()
+    + 0.00042 * e * (m + 2.0 * f).sin()
+    + 0.00038 * e * (m - 2.0 * f).sin()
+    - 0.00024 * e * (2.0 * mp - m).sin()
+    - 0.00017 * omega.sin()
+    - 0.00007 * (mp + 2.0 * m).sin()
+    + 0.00004 * (2.0 * mp - 2.0 * f).sin()
+    + 0.00004 * (3.0 * m).sin()
+    + 0.00003 * (mp + m - 2.0 * f).sin()
+    + 0.00003 * (2.0 * mp + 2.0 * f).sin()
+    - 0.00003 * (mp + m + 2.0 * f).sin()
+    + 0.00003 * (mp - m + 2.0 * f).sin()
+    - 0.00002 * (mp - m - 2.0 * f).sin()
+    - 0.00002 * (3.0 * mp + m).sin()
+    + 0.00002 * (4.0 * mp).sin();
+  mean_jd + correction
+}
+
+///
+/// Search for the first lunation index whose phase falls strictly after the given Julian Day,
+/// or None if the Julian Day is not finite or outside the supported range
+///
+fn next_lunar_phase_index(jd: f64, phase: f64) -> Option<i64> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let start = ((jd - 2451550.09766) / SYNODIC_MONTH_DAYS).floor() as i64 - 1;
+  (start..start + MAX_PHASE_SEARCH_STEPS).find(|&k| lunar_phase_jd(k, phase) > jd)
+}
+
+/// Brown Lunation Number of the lunation in progress at the given Julian Day,
+/// where lunation 1 began with the new moon of 1923-01-17, or None if the Julian Day is not finite or outside the supported range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(lunation_number(2460258.488768587), Some(1247));
+/// ```
+///
+pub fn lunation_number(jd: f64) -> Option<i64> {
+  next_lunar_phase_index(jd, 0.0).map(|k| k - 1 + BROWN_LUNATION_OFFSET)
+}
+
+/// Julian Day of the next new moon strictly after the given Julian Day.
+/// The result is in Terrestrial Time (TT), about a minute ahead of UTC in the current era, and accurate to within a few minutes.
+/// Returns None if the Julian Day is not finite or outside the supported range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let new_moon_jd = next_new_moon_jd(2460258.488768587).unwrap();
+/// assert_eq!(next_new_moon_jd(f64::NAN), None);
+/// ```
+///
+pub fn next_new_moon_jd(jd: f64) -> Option<f64> {
+  next_lunar_phase_index(jd, 0.0).map(|k| lunar_phase_jd(k, 0.0))
+}
+
+///
+/// Julian Day (TT) of the next full moon strictly after the given Julian Day, see next_new_moon_jd()
+///
+pub fn next_full_moon_jd(jd: f64) -> Option<f64> {
+  next_lunar_phase_index(jd, 0.5).map(|k| lunar_phase_jd(k, 0.5))
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use crate::{fuzzy_string_to_julian_day, unix_millis_to_julian_day, JulianDayError};
+
+///
+/// Unix milliseconds for a string in a chrono format, which may have an offset such as %z, a date and time, or only a date
+///
+fn parse_format_to_millis(dt_str: &str, format: &str) -> Option<i64> {
+  if let Ok(dt) = DateTime::parse_from_str(dt_str, format) {
+    return Some(dt.timestamp_millis());
+  }
+  if let Ok(dt) = NaiveDateTime::parse_from_str(dt_str, format) {
+    return Some(dt.and_utc().timestamp_millis());
+  }
+  NaiveDate::parse_from_str(dt_str, format).ok().and_then(|date| date.and_hms_opt(0, 0, 0)).map(|dt| dt.and_utc().timestamp_millis())
+}
+
+/// Convert a date-time string to a Julian Day by trying each chrono format in order, then falling back to the fuzzy parser.
+/// Formats without an offset are read as UTC and date-only formats as midnight
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let formats = ["%d/%m/%Y %H:%M", "%Y%m%d%H%M%S"];
+/// assert_eq!(parse_with_formats("04/09/2022 18:00", &formats), Ok(2459827.25));
+/// assert_eq!(parse_with_formats("20220904180000", &formats), Ok(2459827.25));
+/// assert_eq!(parse_with_formats("2022-09-04T18:00Z", &formats), Ok(2459827.25));
+/// ```
+///
+pub fn parse_with_formats(dt_str: &str, formats: &[&str]) -> Result<f64, JulianDayError> {
+  let trimmed = dt_str.trim();
+  match formats.iter().find_map(|format| parse_format_to_millis(trimmed, format)) {
+    Some(millis) => Ok(unix_millis_to_julian_day(millis)),
+    None => fuzzy_string_to_julian_day(trimmed),
+  }
+}
@@ -0,0 +1,167 @@
+use crate::civil::*;
+use crate::civil_time::{MAX_EXTENDED_JD, MIN_EXTENDED_JD};
+use crate::julian_day_to_weekday_index;
+
+///
+/// Localised weekday and month names. Tables for other languages can be defined as constants of this type
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocaleNames {
+  /// Weekday names from Sunday to Saturday, matching the weekday index
+  pub weekdays: [&'static str; 7],
+  /// Month names from January to December
+  pub months: [&'static str; 12],
+}
+
+impl LocaleNames {
+  pub const ENGLISH: LocaleNames = LocaleNames {
+    weekdays: ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+    months: ENGLISH_MONTH_NAMES,
+  };
+
+  pub const FRENCH: LocaleNames = LocaleNames {
+    weekdays: ["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+    months: ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"],
+  };
+
+  pub const GERMAN: LocaleNames = LocaleNames {
+    weekdays: ["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"],
+    months: ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"],
+  };
+
+  pub const SPANISH: LocaleNames = LocaleNames {
+    weekdays: ["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"],
+    months: ["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"],
+  };
+}
+
+impl Default for LocaleNames {
+  fn default() -> Self {
+    LocaleNames::ENGLISH
+  }
+}
+
+/// Name of the weekday of a Julian Day in the local time given by an offset in seconds from UTC,
+/// or None if the Julian Day is not finite or outside the extended range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(weekday_name(2459827.25, 0, &LocaleNames::ENGLISH), Some("Sunday"));
+/// assert_eq!(weekday_name(2459827.25, 36000, &LocaleNames::FRENCH), Some("lundi"));
+/// assert_eq!(weekday_name(f64::NAN, 0, &LocaleNames::ENGLISH), None);
+/// ```
+///
+pub fn weekday_name(jd: f64, offset_secs: i32, locale: &LocaleNames) -> Option<&'static str> {
+  (MIN_EXTENDED_JD..=MAX_EXTENDED_JD).contains(&jd).then(|| locale.weekdays[julian_day_to_weekday_index(jd, offset_secs) as usize])
+}
+
+/// Name of the Gregorian month of a Julian Day in the local time given by an offset in seconds from UTC,
+/// or None if the Julian Day is not finite or outside the extended range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(month_name(2459827.25, 0, &LocaleNames::GERMAN), Some("September"));
+/// assert_eq!(month_name(1e300, 0, &LocaleNames::GERMAN), None);
+/// ```
+///
+pub fn month_name(jd: f64, offset_secs: i32, locale: &LocaleNames) -> Option<&'static str> {
+  if !(MIN_EXTENDED_JD..=MAX_EXTENDED_JD).contains(&jd) {
+    return None;
+  }
+  let (_, month, _) = jdn_to_gregorian(jd_to_jdn(jd + offset_secs as f64 / 86400.0));
+  Some(locale.months[month as usize - 1])
+}
+
+/// Month number from 1 to 12 for a month name in a locale, matching the full name or its first three letters
+/// regardless of case, with an optional trailing full stop. "Sept" is also accepted for English
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(parse_month_name("SEP", &LocaleNames::ENGLISH), Some(9));
+/// assert_eq!(parse_month_name("März", &LocaleNames::GERMAN), Some(3));
+/// ```
+///
+pub fn parse_month_name(name: &str, locale: &LocaleNames) -> Option<u32> {
+  let lower = name.trim().trim_end_matches('.').to_lowercase();
+  if lower.chars().count() < 3 {
+    return None;
+  }
+  locale.months.iter().position(|month| {
+    let month = month.to_lowercase();
+    lower == month || lower == month.chars().take(3).collect::<String>() || (lower == "sept" && month == "september")
+  }).map(|index| index as u32 + 1)
+}
+
+///
+/// Whether a token is a weekday name in a locale, in full or its first three letters
+///
+fn is_weekday_name(name: &str, locale: &LocaleNames) -> bool {
+  let lower = name.trim_end_matches('.').to_lowercase();
+  lower.chars().count() >= 3 && locale.weekdays.iter().any(|weekday| {
+    let weekday = weekday.to_lowercase();
+    lower == weekday || lower == weekday.chars().take(3).collect::<String>()
+  })
+}
+
+///
+/// Day of the month with an optional English ordinal suffix, e.g. 4 or 4th
+///
+fn parse_day_token(token: &str) -> Option<u32> {
+  let digits = ["st", "nd", "rd", "th"].iter().find_map(|suffix| token.to_lowercase().strip_suffix(suffix).map(str::to_string)).unwrap_or(token.to_string());
+  if digits.is_empty() || digits.len() > 2 || !digits.chars().all(|c| c.is_ascii_digit()) {
+    return None;
+  }
+  digits.parse().ok()
+}
+
+///
+/// Rewrite a date with a month name such as "4 Sep 2022", "September 4th, 2022 18:30" or "04-SEP-2022"
+/// as a numeric date for the fuzzy ISO parser, or None if the date does not contain a month name in the locale.
+/// A leading weekday name is skipped and anything after the day, month and year is kept as the time
+///
+pub(crate) fn month_name_to_numeric_string(dt: &str, locale: &LocaleNames) -> Option<String> {
+  let tokens: Vec<&str> = dt.split([' ', ',']).filter(|token| !token.is_empty()).collect();
+  let (mut year, mut month, mut day): (Option<&str>, Option<u32>, Option<u32>) = (None, None, None);
+  let mut consumed = 0;
+  for token in &tokens {
+    if year.is_some() && month.is_some() && day.is_some() {
+      break;
+    }
+    let parts: Vec<&str> = if token.contains(['-', '/']) { token.split(['-', '/']).collect() } else { vec![token] };
+    let mut parts_valid = true;
+    for part in &parts {
+      if consumed == 0 && month.is_none() && is_weekday_name(part, locale) {
+        continue;
+      }
+      if let Some(month_number) = parse_month_name(part, locale).filter(|_| month.is_none()) {
+        month = Some(month_number);
+      } else if let Some(day_number) = parse_day_token(part).filter(|_| day.is_none()) {
+        day = Some(day_number);
+      } else if year.is_none() && (part.len() >= 3 || (part.len() == 2 && day.is_some())) && part.chars().all(|c| c.is_ascii_digit()) {
+        year = Some(part);
+      } else {
+        parts_valid = false;
+        break;
+      }
+    }
+    if !parts_valid {
+      break;
+    }
+    consumed += 1;
+  }
+  let (year, month) = (year?, month?);
+  let time_part = tokens[consumed..].join(" ");
+  // two-digit years are left for the window in the fuzzy parser
+  let year = if year.len() == 2 { year.to_string() } else { format!("{:0>4}", year) };
+  let date_part = match day {
+    Some(day) => format!("{}-{:02}-{:02}", year, month, day),
+    None => format!("{}-{:02}", year, month),
+  };
+  Some(if time_part.is_empty() { date_part } else { format!("{} {}", date_part, time_part) })
+}
@@ -0,0 +1,115 @@
+use crate::civil::*;
+use crate::is_supported_jd;
+
+///
+/// Number of days in a proleptic Gregorian year
+///
+fn days_in_gregorian_year(year: i64) -> u32 {
+  if is_gregorian_leap_year(year) { 366 } else { 365 }
+}
+
+/// Convert a Julian Day to an ISO 8601 ordinal date (year, day of year from 1) for the UTC calendar day containing it,
+/// or None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_ordinal_date(2459826.5), Some((2022, 247))); // 2022-09-04
+/// ```
+///
+pub fn julian_day_to_ordinal_date(jd: f64) -> Option<(i32, u32)> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let jdn = jd_to_jdn(jd);
+  let (year, _, _) = jdn_to_gregorian(jdn);
+  Some((year as i32, (jdn - gregorian_to_jdn(year, 1, 1) + 1) as u32))
+}
+
+///
+/// Convert an ISO 8601 ordinal date to the Julian Day at 00:00 UTC, or None if the day of year is out of range
+///
+pub fn ordinal_date_to_julian_day(year: i32, day_of_year: u32) -> Option<f64> {
+  if day_of_year < 1 || day_of_year > days_in_gregorian_year(year as i64) {
+    return None;
+  }
+  Some((gregorian_to_jdn(year as i64, 1, 1) + day_of_year as i64 - 1) as f64 - 0.5)
+}
+
+/// Convert a Julian Day to a year and fractional day of year, where 1.0 is midnight UTC at the start of 1 January
+/// and 247.5 is noon on the 247th day. Returns None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_fractional_ordinal(2459827.0), Some((2022, 247.5)));
+/// ```
+///
+pub fn julian_day_to_fractional_ordinal(jd: f64) -> Option<(i32, f64)> {
+  let (year, _) = julian_day_to_ordinal_date(jd)?;
+  Some((year, jd - (gregorian_to_jdn(year as i64, 1, 1) as f64 - 0.5) + 1.0))
+}
+
+///
+/// Convert a year and fractional day of year (from 1.0 up to but excluding the day after the last) to a Julian Day
+///
+pub fn fractional_ordinal_to_julian_day(year: i32, day_of_year: f64) -> Option<f64> {
+  if !(1.0..(days_in_gregorian_year(year as i64) + 1) as f64).contains(&day_of_year) {
+    return None;
+  }
+  Some(gregorian_to_jdn(year as i64, 1, 1) as f64 - 0.5 + day_of_year - 1.0)
+}
+
+/// Format a Julian Day as an ISO 8601 ordinal date string, e.g. "2022-247", or None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(format_ordinal_date(2459826.5), Some("2022-247".to_string()));
+/// ```
+///
+pub fn format_ordinal_date(jd: f64) -> Option<String> {
+  let (year, day_of_year) = julian_day_to_ordinal_date(jd)?;
+  let sign = if year < 0 { "-" } else { "" };
+  Some(format!("{}{:04}-{:03}", sign, year.abs(), day_of_year))
+}
+
+///
+/// Split an ordinal date such as "2022-247" or "2022-247T14:30" into its year, day of year and any time part
+/// starting with a space or T, or None if the date part is not a year followed by a three-digit day of year
+///
+pub(crate) fn split_ordinal_date(dt: &str) -> Option<(i64, u32, &str)> {
+  let trimmed = dt.trim();
+  let (sign, unsigned) = match trimmed.strip_prefix('-') {
+    Some(rest) => (-1, rest),
+    None => (1, trimmed),
+  };
+  let (date_part, time_part) = match unsigned.find([' ', 'T']) {
+    Some(index) => unsigned.split_at(index),
+    None => (unsigned, ""),
+  };
+  let (year_str, day_str) = date_part.split_once('-')?;
+  if year_str.len() < 4 || day_str.len() != 3 || !year_str.chars().chain(day_str.chars()).all(|c| c.is_ascii_digit()) {
+    return None;
+  }
+  Some((year_str.parse::<i64>().ok()? * sign, day_str.parse().ok()?, time_part))
+}
+
+///
+/// Rewrite an ordinal date such as "2022-247" or "2022-247T14:30" as a Gregorian calendar date string for the fuzzy ISO parser,
+/// or None if the date part is not a year followed by a three-digit day of year
+///
+pub(crate) fn ordinal_to_calendar_string(dt: &str) -> Option<String> {
+  let (year, day_of_year, time_part) = split_ordinal_date(dt)?;
+  // out-of-range days are left to fail in the calendar date parser
+  let (year, month, day) = if day_of_year >= 1 && day_of_year <= days_in_gregorian_year(year) {
+    jdn_to_gregorian(gregorian_to_jdn(year, 1, 1) + day_of_year as i64 - 1)
+  } else {
+    (year, 0, 0)
+  };
+  let sign = if year < 0 { "-" } else { "" };
+  Some(format!("{}{:04}-{:02}-{:02}{}", sign, year.abs(), month, day, time_part))
+}
@@ -0,0 +1,52 @@
+use chrono::NaiveDateTime;
+use crate::parse_options::slash_date_to_iso_string;
+use crate::year_numbering::buddhist_era_to_common_era_string;
+use crate::{iso_fuzzy_string_to_datetime_with, unix_millis_to_julian_day, DateOrder, FuzzyParseOptions};
+
+///
+/// One reading of a date-time string, with the date order that produced it if the order matters
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseCandidate {
+  pub jd: f64,
+  pub datetime: NaiveDateTime,
+  pub date_order: Option<DateOrder>,
+}
+
+/// Every valid reading of a date-time string, so that an application can ask the user to choose rather than guessing.
+/// Slash-separated dates, including those with a Buddhist Era label, are read in each date order, with duplicate results dropped, while other strings
+/// have at most one candidate. Candidates are in the order DMY, MDY, YMD and the list is empty if nothing matched
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let candidates = parse_candidates("04/11/1877", &FuzzyParseOptions::default());
+/// let readings: Vec<(String, Option<DateOrder>)> = candidates.iter().map(|c| (c.datetime.to_string(), c.date_order)).collect();
+/// assert_eq!(readings, vec![
+///   ("1877-11-04 00:00:00".to_string(), Some(DateOrder::DMY)),
+///   ("1877-04-11 00:00:00".to_string(), Some(DateOrder::MDY)),
+/// ]);
+/// ```
+///
+pub fn parse_candidates(dt: &str, options: &FuzzyParseOptions) -> Vec<ParseCandidate> {
+  let to_candidate = |datetime: NaiveDateTime, date_order: Option<DateOrder>| ParseCandidate {
+    jd: unix_millis_to_julian_day(datetime.and_utc().timestamp_millis()),
+    datetime,
+    date_order,
+  };
+  // a Buddhist Era label may precede the slash-separated date, e.g. พ.ศ. 04/09/2567
+  let ce_dt = buddhist_era_to_common_era_string(dt);
+  if slash_date_to_iso_string(ce_dt.as_deref().unwrap_or(dt), None).is_none() {
+    return iso_fuzzy_string_to_datetime_with(dt, options).map(|datetime| to_candidate(datetime, None)).into_iter().collect();
+  }
+  let mut candidates: Vec<ParseCandidate> = Vec::new();
+  for order in [DateOrder::DMY, DateOrder::MDY, DateOrder::YMD] {
+    if let Ok(datetime) = iso_fuzzy_string_to_datetime_with(dt, &options.with_date_order(order)) {
+      if !candidates.iter().any(|candidate| candidate.datetime == datetime) {
+        candidates.push(to_candidate(datetime, Some(order)));
+      }
+    }
+  }
+  candidates
+}
@@ -0,0 +1,134 @@
+use chrono::NaiveDateTime;
+use crate::civil::*;
+use crate::parse_options::slash_date_to_iso_string;
+use crate::utc_offset::{split_tz_abbreviation, split_utc_offset, UTC_NAMES};
+use crate::{iso_fuzzy_string_to_datetime_with, normalize_fuzzy_iso_string_with, FuzzyParseOptions, TzAbbreviationError};
+
+///
+/// Why the fuzzy parser rejected a string: the byte offset and text of the first token that failed and what was expected there
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyParseError {
+  /// Byte offset of the token in the input string
+  pub position: usize,
+  /// Text of the token that could not be read, empty if the input ended early
+  pub token: String,
+  /// Description of what was expected, e.g. "a month from 1 to 12"
+  pub expected: &'static str,
+}
+
+impl FuzzyParseError {
+  fn new(position: usize, token: &str, expected: &'static str) -> Self {
+    FuzzyParseError { position, token: token.to_string(), expected }
+  }
+}
+
+///
+/// Runs of digits and runs of letters in a string with their byte offsets, so 04T25 is three tokens
+///
+fn alphanumeric_tokens(dt: &str) -> Vec<(usize, &str)> {
+  let mut tokens = Vec::new();
+  let mut start: Option<(usize, bool)> = None;
+  for (index, c) in dt.char_indices().chain([(dt.len(), ' ')]) {
+    let class = c.is_alphanumeric().then(|| c.is_ascii_digit());
+    match (start, class) {
+      (Some((_, is_digit)), Some(c_is_digit)) if is_digit == c_is_digit => {}
+      _ => {
+        if let Some((token_start, _)) = start {
+          tokens.push((token_start, &dt[token_start..index]));
+        }
+        start = class.map(|is_digit| (index, is_digit));
+      }
+    }
+  }
+  tokens
+}
+
+///
+/// Error for a component of the normalised date-time, located at the first token in the input with the same text or value
+///
+fn component_error(dt: &str, component: &str, expected: &'static str) -> FuzzyParseError {
+  let value = component.parse::<u64>().ok();
+  alphanumeric_tokens(dt)
+    .into_iter()
+    .find(|(_, token)| *token == component || (value.is_some() && token.parse::<u64>().ok() == value))
+    .map(|(position, token)| FuzzyParseError::new(position, token, expected))
+    .unwrap_or_else(|| FuzzyParseError::new(dt.len() - dt.trim_start().len(), dt.trim(), expected))
+}
+
+///
+/// First problem that stops a string from being parsed by the fuzzy parser with the given options
+///
+pub(crate) fn locate_fuzzy_parse_error(dt: &str, options: &FuzzyParseOptions) -> FuzzyParseError {
+  let leading = dt.len() - dt.trim_start().len();
+  if dt.trim().is_empty() {
+    return FuzzyParseError::new(dt.len(), "", "a date");
+  }
+  let Some((local_dt, offset_secs)) = split_utc_offset(dt) else {
+    let index = dt.rfind(['+', '-', '−']).unwrap_or(leading);
+    return FuzzyParseError::new(index, dt[index..].trim(), "a UTC offset from -23:59 to +23:59");
+  };
+  let local_dt = match offset_secs {
+    Some(_) => local_dt,
+    None => match split_tz_abbreviation(local_dt, &options.tz_abbreviations.unwrap_or(UTC_NAMES)) {
+      Ok((rest, _)) => rest,
+      Err(error) => {
+        let index = dt.trim_end().rfind(' ').map(|index| index + 1).unwrap_or(leading);
+        let expected = match error {
+          TzAbbreviationError::Ambiguous => "an unambiguous timezone abbreviation",
+          TzAbbreviationError::Unknown => "a timezone abbreviation in the table set with with_tz_abbreviations()",
+        };
+        return FuzzyParseError::new(index, dt[index..].trim(), expected);
+      }
+    },
+  };
+  if slash_date_to_iso_string(local_dt, options.date_order).is_some_and(|iso_dt| iso_dt.contains("-00-00")) {
+    let date_part = local_dt.split([' ', 'T']).next().unwrap_or(local_dt);
+    return FuzzyParseError::new(leading, date_part, "a date order for a day and month that could be swapped");
+  }
+  let normalized = normalize_fuzzy_iso_string_with(local_dt, options);
+  let unsigned = normalized.strip_prefix('-').unwrap_or(&normalized);
+  let (date_part, time_part) = unsigned.split_once(' ').unwrap_or((unsigned, ""));
+  let date_parts: Vec<&str> = date_part.split('-').collect();
+  let time_parts: Vec<&str> = time_part.split(':').collect();
+  let year = date_parts.first().and_then(|year| year.parse::<i64>().ok());
+  let month = date_parts.get(1).and_then(|month| month.parse::<u32>().ok()).filter(|month| (1..=12).contains(month));
+  let Some(year) = year else {
+    return component_error(dt, date_parts.first().unwrap_or(&""), "a year");
+  };
+  let Some(month) = month else {
+    return component_error(dt, date_parts.get(1).unwrap_or(&""), "a month from 1 to 12");
+  };
+  let max_day = month_length(month, is_gregorian_leap_year(if normalized.starts_with('-') { -year } else { year }));
+  let checks: [(Option<&&str>, u32, u32, &'static str); 4] = [
+    (date_parts.get(2), 1, max_day, "a day that exists in the month"),
+    (time_parts.first(), 0, 23, "an hour from 0 to 23"),
+    (time_parts.get(1), 0, 59, "a minute from 0 to 59"),
+    (time_parts.get(2), 0, 60, "a second from 0 to 60"),
+  ];
+  for (component, min, max, expected) in checks {
+    let component = component.copied().unwrap_or("");
+    if !component.parse::<u32>().is_ok_and(|value| (min..=max).contains(&value)) {
+      return component_error(dt, component, expected);
+    }
+  }
+  FuzzyParseError::new(leading, dt.trim(), "an ISO 8601 date-time")
+}
+
+/// Parse a fuzzy date-time string as iso_fuzzy_string_to_datetime_with() does, with an error giving the position
+/// and text of the first token that failed and what was expected, e.g. for messages in forms
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let error = parse_fuzzy_datetime("2022-13-04 18:00", &FuzzyParseOptions::default()).unwrap_err();
+/// assert_eq!((error.position, error.token.as_str(), error.expected), (5, "13", "a month from 1 to 12"));
+/// ```
+///
+pub fn parse_fuzzy_datetime(dt: &str, options: &FuzzyParseOptions) -> Result<NaiveDateTime, FuzzyParseError> {
+  if dt.trim().is_empty() {
+    return Err(locate_fuzzy_parse_error(dt, options));
+  }
+  iso_fuzzy_string_to_datetime_with(dt, options).map_err(|_| locate_fuzzy_parse_error(dt, options))
+}
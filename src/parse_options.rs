@@ -0,0 +1,224 @@
+use chrono::{Duration, Months, NaiveDateTime};
+use crate::names::month_name_to_numeric_string;
+use crate::{julian_day_to_datetime, LocaleNames, TzAbbreviationTable};
+
+///
+/// Order of the day, month and year in numeric dates such as 04/11/1877
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+  /// Day, month, year, e.g. 04/11/1877 is 4 November 1877
+  DMY,
+  /// Month, day, year, e.g. 04/11/1877 is 11 April 1877
+  MDY,
+  /// Year, month, day, e.g. 1877/11/04
+  YMD,
+}
+
+///
+/// Source of bare numbers in fuzzy date-time strings, such as values imported from spreadsheets
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericHint {
+  /// Serials from 1 to 80000 in Excel's 1900 date system, e.g. 44808.75 for 2022-09-04 18:00
+  ExcelSerial,
+}
+
+///
+/// Options for the fuzzy date-time parser. The default accepts only unambiguous input
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuzzyParseOptions {
+  /// Order of slash-separated dates whose day and month could be swapped. Without it they are rejected
+  pub date_order: Option<DateOrder>,
+  /// Date-time in UTC that relative dates such as "yesterday" or "+3 days" are resolved against, the current time if None
+  pub reference: Option<NaiveDateTime>,
+  /// Point in the period that a year or year-month without a day is completed to
+  pub partial_date: PartialDatePolicy,
+  /// First year of the 100-year window that two-digit years such as 24-05-07 fall in, 1970 by default so 69 is 2069 and 70 is 1970.
+  /// If None two-digit years are read as years 0 to 99
+  pub two_digit_year_pivot: Option<i32>,
+  /// Timezone abbreviations recognised after the time, e.g. 18:30 EST. If None only Z, UTC and numeric offsets are applied
+  pub tz_abbreviations: Option<TzAbbreviationTable>,
+  /// How bare numbers are read before the unix timestamp and year rules, e.g. 44808.75 as an Excel serial
+  pub numeric_hint: Option<NumericHint>,
+}
+
+impl FuzzyParseOptions {
+  /// Default first year of the window for two-digit years
+  pub const DEFAULT_TWO_DIGIT_YEAR_PIVOT: i32 = 1970;
+}
+
+impl Default for FuzzyParseOptions {
+  fn default() -> Self {
+    FuzzyParseOptions {
+      date_order: None,
+      reference: None,
+      partial_date: PartialDatePolicy::StartOf,
+      two_digit_year_pivot: Some(FuzzyParseOptions::DEFAULT_TWO_DIGIT_YEAR_PIVOT),
+      tz_abbreviations: None,
+      numeric_hint: None,
+    }
+  }
+}
+
+impl FuzzyParseOptions {
+  pub fn with_date_order(mut self, order: DateOrder) -> Self {
+    self.date_order = Some(order);
+    self
+  }
+
+  pub fn with_reference(mut self, reference: NaiveDateTime) -> Self {
+    self.reference = Some(reference);
+    self
+  }
+
+  /// Set the first year of the window for two-digit years, or None to read them as years 0 to 99
+  pub fn with_two_digit_year_pivot(mut self, pivot: Option<i32>) -> Self {
+    self.two_digit_year_pivot = pivot;
+    self
+  }
+
+  pub fn with_tz_abbreviations(mut self, table: TzAbbreviationTable) -> Self {
+    self.tz_abbreviations = Some(table);
+    self
+  }
+
+  pub fn with_numeric_hint(mut self, hint: NumericHint) -> Self {
+    self.numeric_hint = Some(hint);
+    self
+  }
+
+  pub fn with_partial_date(mut self, policy: PartialDatePolicy) -> Self {
+    self.partial_date = policy;
+    self
+  }
+
+  /// Resolve relative dates against a Julian Day rather than a NaiveDateTime.
+  /// A Julian Day out of range is ignored, keeping any reference set before
+  pub fn with_reference_jd(mut self, jd: f64) -> Self {
+    if let Ok(reference) = julian_day_to_datetime(jd) {
+      self.reference = Some(reference);
+    }
+    self
+  }
+}
+
+///
+/// Day, month and year strings of a slash-separated date in the given order or, without one,
+/// in the only order that yields a valid day and month. Returns None if the order is ambiguous
+///
+fn resolve_slash_date(parts: [&str; 3], order: Option<DateOrder>) -> Option<(&str, &str, &str)> {
+  let [first, second, third] = parts;
+  let order = match order {
+    Some(order) => order,
+    None if first.len() >= 3 => DateOrder::YMD,
+    None => {
+      let first_value: u32 = first.parse().ok()?;
+      let second_value: u32 = second.parse().ok()?;
+      if first_value > 12 || first_value == second_value {
+        DateOrder::DMY
+      } else if second_value > 12 {
+        DateOrder::MDY
+      } else {
+        return None;
+      }
+    }
+  };
+  Some(match order {
+    DateOrder::DMY => (third, second, first),
+    DateOrder::MDY => (third, first, second),
+    DateOrder::YMD => (first, second, third),
+  })
+}
+
+///
+/// Rewrite a numeric date separated by slashes such as 04/11/1877 or 1877/11/04 18:30 in ISO order for the fuzzy parser,
+/// or None if the string does not start with such a date. Dates that are ambiguous without a date order
+/// are rewritten with month 00 so that they fail rather than being guessed
+///
+pub(crate) fn slash_date_to_iso_string(dt: &str, order: Option<DateOrder>) -> Option<String> {
+  let trimmed = dt.trim();
+  let (date_part, time_part) = match trimmed.find([' ', 'T']) {
+    Some(index) => trimmed.split_at(index),
+    None => (trimmed, ""),
+  };
+  let parts: Vec<&str> = date_part.split('/').collect();
+  if parts.len() != 3 || !parts.iter().all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())) {
+    return None;
+  }
+  let (year, month, day) = resolve_slash_date([parts[0], parts[1], parts[2]], order).unwrap_or((parts[2], "00", "00"));
+  // two-digit years are left for the window in the fuzzy parser
+  let year = if year.len() == 2 { year.to_string() } else { format!("{:0>4}", year) };
+  Some(format!("{}-{:0>2}-{:0>2}{}", year, month, day, time_part))
+}
+
+///
+/// How a partial date such as 1999 or 1999-06 is completed to a date-time
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartialDatePolicy {
+  /// Midnight at the start of the year or month
+  #[default]
+  StartOf,
+  /// Midpoint of the year or month, e.g. 1999-06 is 1999-06-16 00:00:00
+  MiddleOf,
+  /// Last second of the year or month, e.g. 1999 is 1999-12-31 23:59:59
+  EndOf,
+}
+
+///
+/// Number of months in the period of a partial date string, 12 for a year such as 1999 or -0043
+/// and 1 for a year and month such as 1999-06, or None for a full date
+///
+fn partial_date_months(dt: &str) -> Option<u32> {
+  let unsigned = dt.trim().strip_prefix('-').unwrap_or(dt.trim());
+  let is_number = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+  match unsigned.split_once('-') {
+    None if is_number(unsigned) => Some(12),
+    Some((year, month)) if is_number(year) && is_number(month) && month.len() <= 2 => Some(1),
+    _ => None,
+  }
+}
+
+///
+/// Whether a date-time string is a year or a year and month without a day, with or without a month name
+///
+pub(crate) fn is_partial_date(dt: &str) -> bool {
+  let numeric_dt = month_name_to_numeric_string(dt, &LocaleNames::ENGLISH);
+  partial_date_months(numeric_dt.as_deref().unwrap_or(dt)).is_some()
+}
+
+///
+/// Move the start of a partial date, as parsed, to the point in the period given by the policy.
+/// Full dates are returned unchanged. Returns None if the result is out of range
+///
+pub(crate) fn complete_partial_date(dt: &str, start: NaiveDateTime, policy: PartialDatePolicy) -> Option<NaiveDateTime> {
+  let numeric_dt = month_name_to_numeric_string(dt, &LocaleNames::ENGLISH);
+  let months = match (policy, partial_date_months(numeric_dt.as_deref().unwrap_or(dt))) {
+    (PartialDatePolicy::StartOf, _) | (_, None) => return Some(start),
+    (_, Some(months)) => months,
+  };
+  let next = start.checked_add_months(Months::new(months))?;
+  match policy {
+    PartialDatePolicy::MiddleOf => start.checked_add_signed((next - start) / 2),
+    _ => next.checked_sub_signed(Duration::seconds(1)),
+  }
+}
+
+/// Four-digit year for a two-digit year in the 100-year window starting at the pivot year
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(expand_two_digit_year(24, 1970), 2024);
+/// assert_eq!(expand_two_digit_year(70, 1970), 1970);
+/// assert_eq!(expand_two_digit_year(50, 1950), 1950);
+/// ```
+///
+pub fn expand_two_digit_year(year: u32, pivot: i32) -> i32 {
+  let century = pivot - pivot.rem_euclid(100);
+  let expanded = century + (year % 100) as i32;
+  if expanded < pivot { expanded + 100 } else { expanded }
+}
@@ -0,0 +1,138 @@
+use std::ops::{BitOr, BitOrAssign};
+use chrono::{Duration, NaiveDateTime, NaiveTime};
+use crate::epoch_strings::epoch_string_to_datetime;
+use crate::excel_serial::excel_serial_string_to_datetime;
+use crate::parse_options::{is_partial_date, slash_date_to_iso_string};
+use crate::relative_dates::resolve_relative_keyword;
+use crate::twelve_hour_clock::twelve_hour_to_24_hour_string;
+use crate::{normalize_fuzzy_iso_string_with, parse_fuzzy_datetime, split_offset_with_options, unix_millis_to_julian_day, FuzzyParseError, FuzzyParseOptions, NumericHint};
+
+///
+/// Set of assumptions the fuzzy parser made in reading a date-time string
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParseFlags(u8);
+
+impl ParseFlags {
+  /// No assumptions were made
+  pub const NONE: ParseFlags = ParseFlags(0);
+  /// There was no Z, UTC, offset or timezone abbreviation, so the time was read as UTC
+  pub const ASSUMED_UTC: ParseFlags = ParseFlags(1);
+  /// There was no time, so midnight was used
+  pub const ASSUMED_START_OF_DAY: ParseFlags = ParseFlags(2);
+  /// The day and month of a slash-separated date were ordered by their values without a date order in the options
+  pub const GUESSED_MONTH_ORDER: ParseFlags = ParseFlags(4);
+  /// A two-digit year was placed in the window of the two-digit year pivot
+  pub const EXPANDED_TWO_DIGIT_YEAR: ParseFlags = ParseFlags(8);
+  /// A year or year and month without a day was completed with the partial date policy
+  pub const COMPLETED_PARTIAL_DATE: ParseFlags = ParseFlags(16);
+
+  /// Whether all the flags in other are set
+  pub fn contains(&self, other: ParseFlags) -> bool {
+    self.0 & other.0 == other.0
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0 == 0
+  }
+
+  /// Flags as bits for storage
+  pub fn bits(&self) -> u8 {
+    self.0
+  }
+}
+
+impl BitOr for ParseFlags {
+  type Output = ParseFlags;
+
+  fn bitor(self, other: ParseFlags) -> ParseFlags {
+    ParseFlags(self.0 | other.0)
+  }
+}
+
+impl BitOrAssign for ParseFlags {
+  fn bitor_assign(&mut self, other: ParseFlags) {
+    self.0 |= other.0;
+  }
+}
+
+///
+/// Result of the fuzzy parser with what it settled on, for auditing its assumptions
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyParseReport {
+  /// Date-time in UTC
+  pub datetime: NaiveDateTime,
+  pub jd: f64,
+  /// ISO 8601 date-time in the local time of the input with its offset, or Z for UTC, e.g. 2022-09-04T20:00:00+02:00
+  pub normalized: String,
+  /// Offset in seconds east of UTC given in the input, if any
+  pub offset_secs: Option<i32>,
+  pub flags: ParseFlags,
+}
+
+///
+/// ISO 8601 string for a UTC date-time in the local time of an offset
+///
+fn format_normalized(datetime: NaiveDateTime, offset_secs: i32) -> String {
+  let local = datetime + Duration::seconds(offset_secs as i64);
+  let designator = if offset_secs == 0 {
+    "Z".to_string()
+  } else {
+    let sign = if offset_secs < 0 { '-' } else { '+' };
+    format!("{}{:02}:{:02}", sign, offset_secs.abs() / 3600, offset_secs.abs() / 60 % 60)
+  };
+  format!("{}{}", local.format("%Y-%m-%dT%H:%M:%S%.f"), designator)
+}
+
+///
+/// Whether a date-time string without its offset has a time of day
+///
+fn has_time_of_day(local_dt: &str) -> bool {
+  let chars: Vec<char> = local_dt.chars().collect();
+  local_dt.contains(':')
+    || twelve_hour_to_24_hour_string(local_dt).is_some()
+    || chars.windows(2).any(|w| w[0] == 'T' && w[1].is_ascii_digit())
+}
+
+/// Parse a fuzzy date-time string as parse_fuzzy_datetime() does and report the normalised ISO 8601 string it settled on
+/// and flags for the assumptions made, such as reading the time as UTC or ordering an ambiguous day and month
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let report = parse_fuzzy_datetime_report("25/12/22", &FuzzyParseOptions::default()).unwrap();
+/// assert_eq!(report.normalized, "2022-12-25T00:00:00Z");
+/// assert!(report.flags.contains(ParseFlags::ASSUMED_UTC | ParseFlags::ASSUMED_START_OF_DAY));
+/// assert!(report.flags.contains(ParseFlags::GUESSED_MONTH_ORDER | ParseFlags::EXPANDED_TWO_DIGIT_YEAR));
+/// ```
+///
+pub fn parse_fuzzy_datetime_report(dt: &str, options: &FuzzyParseOptions) -> Result<FuzzyParseReport, FuzzyParseError> {
+  let datetime = parse_fuzzy_datetime(dt, options)?;
+  let jd = unix_millis_to_julian_day(datetime.and_utc().timestamp_millis());
+  // unix timestamps, Excel serials and relative dates are exact instants
+  let is_excel_serial = options.numeric_hint == Some(NumericHint::ExcelSerial) && excel_serial_string_to_datetime(dt).is_some();
+  if is_excel_serial || epoch_string_to_datetime(dt).is_some() || resolve_relative_keyword(dt, datetime).is_some() {
+    return Ok(FuzzyParseReport { datetime, jd, normalized: format_normalized(datetime, 0), offset_secs: None, flags: ParseFlags::NONE });
+  }
+  let (local_dt, offset_secs) = split_offset_with_options(dt, options).unwrap_or((dt, None));
+  let mut flags = ParseFlags::NONE;
+  if offset_secs.is_none() {
+    flags |= ParseFlags::ASSUMED_UTC;
+  }
+  if is_partial_date(local_dt) {
+    flags |= ParseFlags::COMPLETED_PARTIAL_DATE;
+  } else if !has_time_of_day(local_dt) && (datetime + Duration::seconds(offset_secs.unwrap_or(0) as i64)).time() == NaiveTime::MIN {
+    flags |= ParseFlags::ASSUMED_START_OF_DAY;
+  }
+  let slash_first_part = local_dt.trim().split('/').next().unwrap_or("");
+  if options.date_order.is_none() && slash_date_to_iso_string(local_dt, None).is_some() && slash_first_part.len() < 3 {
+    flags |= ParseFlags::GUESSED_MONTH_ORDER;
+  }
+  let literal_years = options.with_two_digit_year_pivot(None);
+  if options.two_digit_year_pivot.is_some() && normalize_fuzzy_iso_string_with(local_dt, options) != normalize_fuzzy_iso_string_with(local_dt, &literal_years) {
+    flags |= ParseFlags::EXPANDED_TWO_DIGIT_YEAR;
+  }
+  Ok(FuzzyParseReport { datetime, jd, normalized: format_normalized(datetime, offset_secs.unwrap_or(0)), offset_secs, flags })
+}
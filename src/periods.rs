@@ -0,0 +1,219 @@
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::WeekStart;
+
+///
+/// Julian Day Number of the local calendar day containing a Julian Day, in the local time given by an offset in seconds
+///
+fn local_jdn(jd: f64, offset_secs: i32) -> i64 {
+  jd_to_jdn(jd + offset_secs as f64 / 86400.0)
+}
+
+///
+/// Julian Day (UTC) of local midnight at the start of a local calendar day
+///
+fn local_midnight_jd(jdn: i64, offset_secs: i32) -> f64 {
+  jdn as f64 - 0.5 - offset_secs as f64 / 86400.0
+}
+
+/// Julian Day of local midnight at the start of the week containing a Julian Day,
+/// in the local time given by an offset in seconds and with weeks beginning on the given day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Wednesday 4 September 2024 12:00 UTC is in the week from Monday 2 September
+/// assert_eq!(start_of_week_jd(2460558.0, 0, WeekStart::Monday), 2460555.5);
+/// assert_eq!(start_of_week_jd(2460558.0, 0, WeekStart::Sunday), 2460554.5);
+/// ```
+///
+pub fn start_of_week_jd(jd: f64, offset_secs: i32, week_start: WeekStart) -> f64 {
+  let jdn = local_jdn(jd, offset_secs);
+  let days_into_week = (jdn_weekday_index(jdn) as i64 - week_start.weekday_index() as i64).rem_euclid(7);
+  local_midnight_jd(jdn - days_into_week, offset_secs)
+}
+
+///
+/// Julian Day of local midnight at the end of the week containing a Julian Day, i.e. the exclusive start of the following week
+///
+pub fn end_of_week_jd(jd: f64, offset_secs: i32, week_start: WeekStart) -> f64 {
+  start_of_week_jd(jd, offset_secs, week_start) + 7.0
+}
+
+/// Julian Day of local midnight on the first day of the Gregorian month containing a Julian Day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(start_of_month_jd(2460558.0, 0), 2460554.5);
+/// // 1 September 2024 02:00 UTC is still 31 August in New York (UTC-4)
+/// assert_eq!(start_of_month_jd(2460554.5833333335, -14400), 2460523.5 + 4.0 / 24.0);
+/// ```
+///
+pub fn start_of_month_jd(jd: f64, offset_secs: i32) -> f64 {
+  let (year, month, _) = jdn_to_gregorian(local_jdn(jd, offset_secs));
+  local_midnight_jd(gregorian_to_jdn(year, month, 1), offset_secs)
+}
+
+///
+/// Julian Day of local midnight at the end of the month containing a Julian Day, i.e. the exclusive start of the following month
+///
+pub fn end_of_month_jd(jd: f64, offset_secs: i32) -> f64 {
+  let (year, month, _) = jdn_to_gregorian(local_jdn(jd, offset_secs));
+  let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+  local_midnight_jd(gregorian_to_jdn(next_year, next_month, 1), offset_secs)
+}
+
+/// Quarter of the Gregorian year (1 to 4) containing a Julian Day in local time
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(quarter_of(2460558.0, 0), 3);
+/// ```
+///
+pub fn quarter_of(jd: f64, offset_secs: i32) -> u32 {
+  let (_, month, _) = jdn_to_gregorian(local_jdn(jd, offset_secs));
+  (month - 1) / 3 + 1
+}
+
+///
+/// Julian Day of local midnight on the first day of the quarter containing a Julian Day
+///
+pub fn start_of_quarter_jd(jd: f64, offset_secs: i32) -> f64 {
+  let (year, month, _) = jdn_to_gregorian(local_jdn(jd, offset_secs));
+  local_midnight_jd(gregorian_to_jdn(year, (month - 1) / 3 * 3 + 1, 1), offset_secs)
+}
+
+///
+/// Julian Day of local midnight at the end of the quarter containing a Julian Day, i.e. the exclusive start of the following quarter
+///
+pub fn end_of_quarter_jd(jd: f64, offset_secs: i32) -> f64 {
+  let (year, month, _) = jdn_to_gregorian(local_jdn(jd, offset_secs));
+  let quarter_start_month = (month - 1) / 3 * 3 + 1;
+  let (next_year, next_month) = if quarter_start_month == 10 { (year + 1, 1) } else { (year, quarter_start_month + 3) };
+  local_midnight_jd(gregorian_to_jdn(next_year, next_month, 1), offset_secs)
+}
+
+/// Julian Day of local midnight (00:00) on the local calendar day containing a Julian Day.
+/// Julian Days begin at noon UTC, so this snaps to .5 in UTC rather than truncating
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 2024-09-04 06:00 UTC
+/// assert_eq!(start_of_day_jd(2460557.75, 0), 2460557.5);
+/// // 2024-09-04 23:00 UTC is already 5 September in Berlin (UTC+2), which began at 22:00 UTC
+/// assert_eq!(start_of_day_jd(2460558.4583333335, 7200), 2460558.5 - 2.0 / 24.0);
+/// ```
+///
+pub fn start_of_day_jd(jd: f64, offset_secs: i32) -> f64 {
+  local_midnight_jd(local_jdn(jd, offset_secs), offset_secs)
+}
+
+/// Julian Day of local noon (12:00) on the local calendar day containing a Julian Day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(noon_jd(2460557.75, 0), 2460558.0);
+/// ```
+///
+pub fn noon_jd(jd: f64, offset_secs: i32) -> f64 {
+  start_of_day_jd(jd, offset_secs) + 0.5
+}
+
+/// Number of days in the Gregorian month containing a Julian Day in local time
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // February 2024
+/// assert_eq!(days_in_month(2460355.5, 0), 29);
+/// ```
+///
+pub fn days_in_month(jd: f64, offset_secs: i32) -> u32 {
+  let (year, month, _) = jdn_to_gregorian(local_jdn(jd, offset_secs));
+  month_length(month, is_gregorian_leap_year(year))
+}
+
+///
+/// Whether a Julian Day falls on the last day of a Gregorian month in local time
+///
+pub fn is_last_day_of_month(jd: f64, offset_secs: i32) -> bool {
+  let (year, month, day) = jdn_to_gregorian(local_jdn(jd, offset_secs));
+  day == month_length(month, is_gregorian_leap_year(year))
+}
+
+/// Whether the year containing a Julian Day is a leap year in a calendar, e.g. ProlepticGregorian or ProlepticJulian.
+/// Returns false if the calendar does not support the Julian Day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 1 March 1900 is in a common year in the Gregorian calendar but a leap year in the Julian calendar
+/// assert!(!is_leap_year_at(2415079.5, &ProlepticGregorian));
+/// assert!(is_leap_year_at(2415079.5, &ProlepticJulian));
+/// ```
+///
+pub fn is_leap_year_at(jd: f64, calendar: &impl Calendar) -> bool {
+  calendar.julian_day_to_date(jd).is_some_and(|(year, _, _)| calendar.is_leap_year(year))
+}
+
+/// Number of days in the year containing a Julian Day in a calendar, or None if the calendar does not support the Julian Day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(days_in_year_at(2460558.0, &ProlepticGregorian), Some(366));
+/// ```
+///
+pub fn days_in_year_at(jd: f64, calendar: &impl Calendar) -> Option<u32> {
+  let (year, _, _) = calendar.julian_day_to_date(jd)?;
+  calendar.days_in_year(year)
+}
+
+///
+/// Rules for numbering the weeks of a month
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekOfMonthRule {
+  /// Row of a month calendar grid: the week containing the 1st is week 1 and a new week begins on each week start day (1 to 6)
+  #[default]
+  CalendarRows,
+  /// Consecutive 7-day blocks from the 1st regardless of weekday, so days 15 to 21 are always week 3 (1 to 5)
+  SevenDayBlocks,
+}
+
+/// Week of the Gregorian month (from 1) containing a Julian Day in local time, with weeks beginning on the given day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Saturday 7 September 2024 is in the 1st row of a Sunday-first calendar but the 2nd row of a Monday-first calendar
+/// assert_eq!(week_of_month(2460560.5, 0, WeekStart::Sunday, WeekOfMonthRule::CalendarRows), 1);
+/// assert_eq!(week_of_month(2460560.5, 0, WeekStart::Monday, WeekOfMonthRule::CalendarRows), 2);
+/// assert_eq!(week_of_month(2460560.5, 0, WeekStart::Monday, WeekOfMonthRule::SevenDayBlocks), 1);
+/// ```
+///
+pub fn week_of_month(jd: f64, offset_secs: i32, week_start: WeekStart, rule: WeekOfMonthRule) -> u32 {
+  let jdn = local_jdn(jd, offset_secs);
+  let (_, _, day) = jdn_to_gregorian(jdn);
+  match rule {
+    WeekOfMonthRule::CalendarRows => {
+      let first_jdn = jdn - day as i64 + 1;
+      let lead_days = (jdn_weekday_index(first_jdn) as i64 - week_start.weekday_index() as i64).rem_euclid(7) as u32;
+      (day - 1 + lead_days) / 7 + 1
+    },
+    WeekOfMonthRule::SevenDayBlocks => (day - 1) / 7 + 1,
+  }
+}
@@ -0,0 +1,174 @@
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::astro::solar_noon_jd;
+use crate::seasons::march_equinox_jd;
+use crate::is_supported_jd;
+
+/// Julian Day Number of 1 Farvardin 1 AP projected back with the 33-year arithmetic cycle (18 March 622 in the Julian calendar)
+const PERSIAN_EPOCH_JDN: i64 = 1948320;
+
+/// Longitude of Tehran in degrees, whose apparent noon decides the start of the year under the astronomical rule
+const TEHRAN_LONGITUDE_DEG: f64 = 51.42;
+
+/// Iran Standard Time offset from UTC in days (UTC+3:30)
+const IRAN_STANDARD_TIME_OFFSET_DAYS: f64 = 3.5 / 24.0;
+
+///
+/// Rules for deciding leap years in the Persian (Solar Hijri) calendar
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersianLeapRule {
+  /// 33-year arithmetic cycle with leap years where (25 * year + 11) mod 33 < 8, matching the official calendar from 1178 to 1633 AP
+  Arithmetic33,
+  /// The year begins on the day of the March equinox if it occurs before apparent noon in Tehran, otherwise on the next day
+  Astronomical,
+}
+
+///
+/// Persian (Solar Hijri / Jalali) calendar as used in Iran and Afghanistan, with 31-day months from Farvardin to Shahrivar,
+/// 30-day months from Mehr to Bahman and a 29 or 30-day Esfand
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PersianCalendar {
+  pub rule: PersianLeapRule,
+}
+
+impl Default for PersianCalendar {
+  fn default() -> Self {
+    PersianCalendar { rule: PersianLeapRule::Arithmetic33 }
+  }
+}
+
+impl PersianCalendar {
+  pub fn new(rule: PersianLeapRule) -> Self {
+    PersianCalendar { rule }
+  }
+
+  /// Whether a year AP has 366 days, with a 30-day Esfand
+  pub fn is_leap_year(&self, year: i32) -> bool {
+    self.year_start_jdn(year as i64 + 1) - self.year_start_jdn(year as i64) == 366
+  }
+
+  /// Number of days in a month of a year AP
+  pub fn days_in_month(&self, year: i32, month: u32) -> u32 {
+    match month {
+      1..=6 => 31,
+      7..=11 => 30,
+      _ => if self.is_leap_year(year) { 30 } else { 29 },
+    }
+  }
+
+  /// Julian Day at 00:00 UTC for a Persian date, or None if the month or day is out of range
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // Nowruz 1403 AP was 20 March 2024
+  /// assert_eq!(PersianCalendar::default().date_to_julian_day(1403, 1, 1), Some(2460389.5));
+  /// ```
+  pub fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    if !(1..=12).contains(&month) || day < 1 || day > self.days_in_month(year, month) {
+      return None;
+    }
+    let month_offset = if month <= 7 { 31 * (month - 1) } else { 30 * (month - 1) + 6 };
+    Some((self.year_start_jdn(year as i64) + month_offset as i64 + day as i64 - 1) as f64 - 0.5)
+  }
+
+  /// Persian date as (year, month, day) for the UTC calendar day containing a Julian Day,
+  /// or None for NaN, infinite or unsupported Julian Days
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(PersianCalendar::default().julian_day_to_date(2460389.5), Some((1403, 1, 1)));
+  /// ```
+  pub fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let jdn = jd_to_jdn(jd);
+    let (gregorian_year, _, _) = jdn_to_gregorian(jdn);
+    let mut year = gregorian_year - 620;
+    while self.year_start_jdn(year) > jdn {
+      year -= 1;
+    }
+    let day_of_year = (jdn - self.year_start_jdn(year)) as u32;
+    let (month, day) = if day_of_year < 186 {
+      (day_of_year / 31 + 1, day_of_year % 31 + 1)
+    } else {
+      ((day_of_year - 6) / 30 + 1, (day_of_year - 6) % 30 + 1)
+    };
+    Some((i32::try_from(year).ok()?, month, day))
+  }
+
+  ///
+  /// Julian Day Number of 1 Farvardin (Nowruz) of a year AP
+  ///
+  fn year_start_jdn(&self, year: i64) -> i64 {
+    match self.rule {
+      PersianLeapRule::Arithmetic33 => {
+        let elapsed = year - 1;
+        let leap_days = elapsed.div_euclid(33) * 8 + (1..=elapsed.rem_euclid(33)).filter(|position| is_arithmetic_leap_position(*position)).count() as i64;
+        PERSIAN_EPOCH_JDN + 365 * elapsed + leap_days
+      },
+      PersianLeapRule::Astronomical => {
+        let equinox = march_equinox_jd((year + 621) as i32);
+        let local_jdn = jd_to_jdn(equinox + IRAN_STANDARD_TIME_OFFSET_DAYS);
+        let noon = solar_noon_jd(local_jdn as f64 - 0.5, TEHRAN_LONGITUDE_DEG);
+        if equinox <= noon { local_jdn } else { local_jdn + 1 }
+      },
+    }
+  }
+}
+
+impl Calendar for PersianCalendar {
+  fn months_in_year(&self, _year: i32) -> u32 {
+    12
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    (1..=12).contains(&month).then(|| PersianCalendar::days_in_month(self, year, month))
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    PersianCalendar::is_leap_year(self, year)
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    PersianCalendar::date_to_julian_day(self, year, month, day)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    PersianCalendar::julian_day_to_date(self, jd)
+  }
+}
+
+///
+/// Leap years of the 33-year arithmetic cycle, counted from a cycle position of 1
+///
+fn is_arithmetic_leap_position(position: i64) -> bool {
+  (25 * position + 11).rem_euclid(33) < 8
+}
+
+/// Convert a Julian Day to a Persian date (year, month, day) using the 33-year arithmetic cycle,
+/// or None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let (year, month, day) = julian_day_to_persian(2460258.488768587).unwrap();
+/// ```
+///
+pub fn julian_day_to_persian(jd: f64) -> Option<(i32, u32, u32)> {
+  PersianCalendar::default().julian_day_to_date(jd)
+}
+
+///
+/// Convert a Persian date to the Julian Day at 00:00 UTC using the 33-year arithmetic cycle
+///
+pub fn persian_to_julian_day(year: i32, month: u32, day: u32) -> Option<f64> {
+  PersianCalendar::default().date_to_julian_day(year, month, day)
+}
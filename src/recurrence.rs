@@ -0,0 +1,132 @@
+use chrono::Weekday;
+use crate::julian_day_to_weekday_index;
+
+///
+/// How often a recurrence repeats, with an interval of 1 or more
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+  /// Every N days
+  Daily(u32),
+  /// Every N weeks, counted in 7-day periods from the start
+  Weekly(u32),
+}
+
+///
+/// Iterator over Julian Days generated from a start Julian Day with a simple recurrence rule,
+/// similar to a subset of iCalendar RRULE. Each occurrence keeps the time of day of the start
+///
+/// ### Example:
+/// ```
+/// use chrono::Weekday;
+/// use julian_day_converter::*;
+///
+/// // every other week on Monday and Thursday from Monday 2 September 2024, 4 times
+/// let dates: Vec<f64> = Recurrence::new(2460555.5, Frequency::Weekly(2))
+///   .on_weekdays(&[Weekday::Mon, Weekday::Thu])
+///   .count_limit(4)
+///   .collect();
+/// assert_eq!(dates, vec![2460555.5, 2460558.5, 2460569.5, 2460572.5]);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+  start_jd: f64,
+  frequency: Frequency,
+  weekdays: Option<[bool; 7]>,
+  offset_secs: i32,
+  until_jd: Option<f64>,
+  count: Option<usize>,
+  period: i64,
+  day: i64,
+  emitted: usize,
+  empty_periods: u32,
+}
+
+impl Recurrence {
+  pub fn new(start_jd: f64, frequency: Frequency) -> Self {
+    Recurrence {
+      start_jd,
+      frequency,
+      weekdays: None,
+      offset_secs: 0,
+      until_jd: None,
+      count: None,
+      period: 0,
+      day: 0,
+      emitted: 0,
+      empty_periods: 0,
+    }
+  }
+
+  /// Only include days falling on these weekdays. With a weekly frequency every matching day of each selected week is included
+  pub fn on_weekdays(mut self, weekdays: &[Weekday]) -> Self {
+    let mut days = [false; 7];
+    for weekday in weekdays {
+      days[weekday.num_days_from_sunday() as usize] = true;
+    }
+    self.weekdays = Some(days);
+    self
+  }
+
+  /// Offset in seconds from UTC of the local time in which weekdays are matched
+  pub fn with_offset(mut self, offset_secs: i32) -> Self {
+    self.offset_secs = offset_secs;
+    self
+  }
+
+  /// Stop after the last occurrence on or before this Julian Day
+  pub fn until(mut self, jd: f64) -> Self {
+    self.until_jd = Some(jd);
+    self
+  }
+
+  /// Stop after this number of occurrences
+  pub fn count_limit(mut self, count: usize) -> Self {
+    self.count = Some(count);
+    self
+  }
+
+  fn period_days(&self) -> (i64, i64) {
+    match self.frequency {
+      Frequency::Daily(interval) => (interval.max(1) as i64, 1),
+      Frequency::Weekly(interval) => (7 * interval.max(1) as i64, if self.weekdays.is_some() { 7 } else { 1 }),
+    }
+  }
+}
+
+impl Iterator for Recurrence {
+  type Item = f64;
+
+  fn next(&mut self) -> Option<f64> {
+    let (period_length, span) = self.period_days();
+    loop {
+      if self.count.is_some_and(|count| self.emitted >= count) {
+        return None;
+      }
+      if self.day >= span {
+        self.day = 0;
+        self.period += 1;
+        self.empty_periods += 1;
+        // the weekday pattern repeats every 7 periods, so no later period can match either
+        if self.empty_periods > 7 {
+          return None;
+        }
+      }
+      let jd = self.start_jd + (self.period * period_length + self.day) as f64;
+      self.day += 1;
+      if self.until_jd.is_some_and(|until_jd| jd > until_jd) {
+        return None;
+      }
+      let matches = match self.weekdays {
+        Some(days) => days[julian_day_to_weekday_index(jd, self.offset_secs) as usize],
+        None => true,
+      };
+      if matches {
+        self.emitted += 1;
+        self.empty_periods = 0;
+        return Some(jd);
+      }
+    }
+  }
+}
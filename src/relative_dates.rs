@@ -0,0 +1,32 @@
+use chrono::{Duration, NaiveDateTime, NaiveTime};
+use crate::human_duration_to_duration;
+
+///
+/// Resolve a relative date-time such as "now", "today", "yesterday", "tomorrow", "+3 days", "-2 weeks", "in 6 hours"
+/// or "2 days ago" against a reference date-time in UTC, or None if the string is not relative.
+/// Today, yesterday and tomorrow are at midnight, while offsets keep the reference time of day
+///
+pub(crate) fn resolve_relative_keyword(dt: &str, reference: NaiveDateTime) -> Option<NaiveDateTime> {
+  let lower = dt.trim().to_lowercase();
+  let midnight = reference.date().and_time(NaiveTime::MIN);
+  match lower.as_str() {
+    "now" => return Some(reference),
+    "today" => return Some(midnight),
+    "yesterday" => return midnight.checked_sub_signed(Duration::days(1)),
+    "tomorrow" => return midnight.checked_add_signed(Duration::days(1)),
+    _ => {}
+  }
+  let (is_past, duration_str) = if let Some(rest) = lower.strip_prefix('+') {
+    (false, rest)
+  } else if let Some(rest) = lower.strip_prefix('-') {
+    (true, rest)
+  } else if let Some(rest) = lower.strip_prefix("in ") {
+    (false, rest)
+  } else if let Some(rest) = lower.strip_suffix(" ago") {
+    (true, rest)
+  } else {
+    return None;
+  };
+  let duration = human_duration_to_duration(duration_str)?;
+  if is_past { reference.checked_sub_signed(duration) } else { reference.checked_add_signed(duration) }
+}
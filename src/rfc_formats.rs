@@ -0,0 +1,138 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, SecondsFormat};
+use crate::{expand_two_digit_year, julian_day_to_unix_millis, unix_millis_to_julian_day, FuzzyParseOptions, JulianDayError};
+
+///
+/// Date-time with a fixed UTC offset for a Julian Day, or None if the offset or Julian Day is out of range
+///
+fn julian_day_to_fixed_offset(jd: f64, offset_secs: i32) -> Option<DateTime<FixedOffset>> {
+  if !jd.is_finite() {
+    return None;
+  }
+  let offset = FixedOffset::east_opt(offset_secs)?;
+  DateTime::from_timestamp_millis(julian_day_to_unix_millis(jd)).map(|dt| dt.with_timezone(&offset))
+}
+
+/// Convert a strict RFC 3339 date-time such as 2022-09-04T20:00:00.250+02:00 to a Julian Day, applying its offset.
+/// Unlike the fuzzy parser every part must be present. Fractions of a second are kept to the millisecond
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(rfc3339_to_julian_day("2022-09-04T20:00:00+02:00"), Ok(2459827.25));
+/// assert_eq!(rfc3339_to_julian_day("2022-09-04 18:00"), Err(JulianDayError::InvalidFormat));
+/// ```
+///
+pub fn rfc3339_to_julian_day(dt_str: &str) -> Result<f64, JulianDayError> {
+  DateTime::parse_from_rfc3339(dt_str.trim())
+    .map(|dt| unix_millis_to_julian_day(dt.timestamp_millis()))
+    .map_err(|_| JulianDayError::InvalidFormat)
+}
+
+/// Convert a strict RFC 2822 date-time such as "Sun, 04 Sep 2022 20:00:00 +0200", as used in email headers, to a Julian Day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(rfc2822_to_julian_day("Sun, 04 Sep 2022 20:00:00 +0200"), Ok(2459827.25));
+/// ```
+///
+pub fn rfc2822_to_julian_day(dt_str: &str) -> Result<f64, JulianDayError> {
+  DateTime::parse_from_rfc2822(dt_str.trim())
+    .map(|dt| unix_millis_to_julian_day(dt.timestamp_millis()))
+    .map_err(|_| JulianDayError::InvalidFormat)
+}
+
+/// Format a Julian Day as an RFC 3339 date-time with milliseconds in the local time given by an offset in seconds from UTC,
+/// using Z for UTC. Returns None if the Julian Day or offset is out of range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_rfc3339(2459827.25, 0), Some("2022-09-04T18:00:00.000Z".to_string()));
+/// assert_eq!(julian_day_to_rfc3339(2459827.25, 19800), Some("2022-09-04T23:30:00.000+05:30".to_string()));
+/// ```
+///
+pub fn julian_day_to_rfc3339(jd: f64, offset_secs: i32) -> Option<String> {
+  julian_day_to_fixed_offset(jd, offset_secs).map(|dt| dt.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Format a Julian Day as an RFC 2822 date-time, to the second, in the local time given by an offset in seconds from UTC.
+/// Returns None if the Julian Day or offset is out of range, including years RFC 2822 cannot express
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_rfc2822(2459827.25, 7200), Some("Sun, 4 Sep 2022 20:00:00 +0200".to_string()));
+/// ```
+///
+pub fn julian_day_to_rfc2822(jd: f64, offset_secs: i32) -> Option<String> {
+  let dt = julian_day_to_fixed_offset(jd, offset_secs)?;
+  // chrono panics in to_rfc2822() for years it cannot represent in 4 digits
+  if !(0..=9999).contains(&dt.year()) {
+    return None;
+  }
+  Some(dt.to_rfc2822())
+}
+
+///
+/// Date-time of an RFC 850 date such as "Sunday, 06-Nov-94 08:49:37 GMT", with the two-digit year
+/// in the window from 1970 to 2069 as in the fuzzy parser
+///
+fn parse_rfc850_date(dt_str: &str) -> Option<NaiveDateTime> {
+  let (weekday, rest) = dt_str.split_once(", ")?;
+  let (date_part, time_part) = rest.split_once(' ')?;
+  let mut date_parts = date_part.split('-');
+  let (day, month, year) = (date_parts.next()?, date_parts.next()?, date_parts.next()?);
+  if year.len() != 2 || date_parts.next().is_some() {
+    return None;
+  }
+  let year = expand_two_digit_year(year.parse().ok()?, FuzzyParseOptions::DEFAULT_TWO_DIGIT_YEAR_PIVOT);
+  let full_dt = format!("{}, {}-{}-{} {}", weekday, day, month, year, time_part);
+  NaiveDateTime::parse_from_str(&full_dt, "%A, %d-%b-%Y %H:%M:%S GMT").ok()
+}
+
+/// Convert an HTTP-date, as used in Date, Last-Modified and Expires headers, to a Julian Day.
+/// All three forms in RFC 9110 are accepted: IMF-fixdate "Sun, 06 Nov 1994 08:49:37 GMT",
+/// RFC 850 "Sunday, 06-Nov-94 08:49:37 GMT" with two-digit years from 1970 to 2069, and asctime "Sun Nov  6 08:49:37 1994"
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let jd = http_date_to_julian_day("Sun, 04 Sep 2022 18:00:00 GMT").unwrap();
+/// assert_eq!(jd, 2459827.25);
+/// assert_eq!(http_date_to_julian_day("Sunday, 04-Sep-22 18:00:00 GMT"), Ok(jd));
+/// assert_eq!(http_date_to_julian_day("Sun Sep  4 18:00:00 2022"), Ok(jd));
+/// ```
+///
+pub fn http_date_to_julian_day(dt_str: &str) -> Result<f64, JulianDayError> {
+  let trimmed = dt_str.trim();
+  NaiveDateTime::parse_from_str(trimmed, "%a, %d %b %Y %H:%M:%S GMT")
+    .ok()
+    .or_else(|| parse_rfc850_date(trimmed))
+    .or_else(|| NaiveDateTime::parse_from_str(trimmed, "%a %b %e %H:%M:%S %Y").ok())
+    .map(|dt| unix_millis_to_julian_day(dt.and_utc().timestamp_millis()))
+    .ok_or(JulianDayError::InvalidFormat)
+}
+
+/// Format a Julian Day as an IMF-fixdate HTTP-date such as "Sun, 04 Sep 2022 18:00:00 GMT", truncated to the second.
+/// Returns None if the year is outside 0 to 9999
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_http_date(2459827.25), Some("Sun, 04 Sep 2022 18:00:00 GMT".to_string()));
+/// ```
+///
+pub fn julian_day_to_http_date(jd: f64) -> Option<String> {
+  let dt = julian_day_to_fixed_offset(jd, 0)?;
+  if !(0..=9999).contains(&dt.year()) {
+    return None;
+  }
+  Some(dt.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+}
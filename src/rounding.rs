@@ -0,0 +1,135 @@
+use crate::JULIAN_DAY_UNIX_EPOCH_DAYS;
+
+///
+/// How to round a fraction of a time unit when converting a Julian Day to an integer timestamp
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+  /// Nearest value, with ties to the even value, so float noise either side of a whole unit is absorbed
+  #[default]
+  HalfEven,
+  /// Toward zero, as the `as` cast does
+  Truncate,
+  /// Toward negative infinity, i.e. the start of the unit containing the instant
+  Floor,
+  /// Toward positive infinity
+  Ceil,
+}
+
+impl RoundingMode {
+  pub fn apply(&self, value: f64) -> f64 {
+    match self {
+      RoundingMode::HalfEven => value.round_ties_even(),
+      RoundingMode::Truncate => value.trunc(),
+      RoundingMode::Floor => value.floor(),
+      RoundingMode::Ceil => value.ceil(),
+    }
+  }
+}
+
+///
+/// Convert a Julian Day to a whole number of units since the unix epoch, converting the calendar day with integer arithmetic
+/// and rounding only the time of day. Saturates at the limits of i128 for huge Julian Days
+///
+pub(crate) fn julian_day_to_unix_units_wide(jd: f64, units_per_day: i64, mode: RoundingMode) -> i128 {
+  let day = (jd + 0.5).floor();
+  let days = (day as i128).saturating_sub((JULIAN_DAY_UNIX_EPOCH_DAYS + 0.5) as i128);
+  // the time of day is positive, so truncating a negative total rounds its fraction up
+  let mode = match mode {
+    RoundingMode::Truncate => if days < 0 { RoundingMode::Ceil } else { RoundingMode::Floor },
+    _ => mode,
+  };
+  days.saturating_mul(units_per_day as i128).saturating_add(mode.apply((jd + 0.5 - day) * units_per_day as f64) as i128)
+}
+
+///
+/// Convert a Julian Day to a whole number of units since the unix epoch, saturating outside the i64 range
+///
+pub(crate) fn julian_day_to_unix_units(jd: f64, units_per_day: i64, mode: RoundingMode) -> i64 {
+  julian_day_to_unix_units_wide(jd, units_per_day, mode).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Convert a Julian Day to a unix timestamp in seconds with a rounding mode
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 2024-09-04 17:59:59.6 UTC
+/// let jd = 2460558.25 - 0.4 / 86400.0;
+/// assert_eq!(julian_day_to_unixtime_rounded(jd, RoundingMode::HalfEven), 1_725_472_800);
+/// assert_eq!(julian_day_to_unixtime_rounded(jd, RoundingMode::Floor), 1_725_472_799);
+/// ```
+///
+pub fn julian_day_to_unixtime_rounded(jd: f64, mode: RoundingMode) -> i64 {
+  julian_day_to_unix_units(jd, 86_400, mode)
+}
+
+///
+/// Convert a Julian Day to a unix timestamp in milliseconds with a rounding mode, see julian_day_to_unix_millis()
+///
+pub fn julian_day_to_unix_millis_rounded(jd: f64, mode: RoundingMode) -> i64 {
+  julian_day_to_unix_units(jd, 86_400_000, mode)
+}
+
+///
+/// Units of time for rounding Julian Days
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+  Millisecond,
+  Second,
+  Minute,
+  Hour,
+  /// Calendar day from midnight UTC
+  Day,
+}
+
+impl TimeUnit {
+  /// Number of units in a day
+  pub fn per_day(&self) -> i64 {
+    match self {
+      TimeUnit::Millisecond => 86_400_000,
+      TimeUnit::Second => 86_400,
+      TimeUnit::Minute => 1_440,
+      TimeUnit::Hour => 24,
+      TimeUnit::Day => 1,
+    }
+  }
+}
+
+///
+/// Julian Day of a whole number of units since the unix epoch
+///
+pub(crate) fn unix_units_to_julian_day(units: i64, units_per_day: i64) -> f64 {
+  JULIAN_DAY_UNIX_EPOCH_DAYS + units.div_euclid(units_per_day) as f64 + units.rem_euclid(units_per_day) as f64 / units_per_day as f64
+}
+
+/// Round a Julian Day to the nearest whole unit of UTC time, working in timestamp space to avoid float artifacts
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 2024-09-04 17:59:59.6 UTC
+/// let jd = 2460558.25 - 0.4 / 86400.0;
+/// assert_eq!(round_jd_to(jd, TimeUnit::Second), 2460558.25);
+/// assert_eq!(round_jd_to(jd, TimeUnit::Day), 2460558.5);
+/// ```
+///
+pub fn round_jd_to(jd: f64, unit: TimeUnit) -> f64 {
+  unix_units_to_julian_day(julian_day_to_unix_units(jd, unit.per_day(), RoundingMode::HalfEven), unit.per_day())
+}
+
+/// Truncate a Julian Day to the start of the unit of UTC time containing it
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(truncate_jd_to(2460558.27, TimeUnit::Hour), 2460558.25);
+/// ```
+///
+pub fn truncate_jd_to(jd: f64, unit: TimeUnit) -> f64 {
+  unix_units_to_julian_day(julian_day_to_unix_units(jd, unit.per_day(), RoundingMode::Floor), unit.per_day())
+}
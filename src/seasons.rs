@@ -0,0 +1,109 @@
+use crate::astro::*;
+
+///
+/// Equinoxes and solstices marking the start of the astronomical seasons in the northern hemisphere
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeasonEvent {
+  MarchEquinox,
+  JuneSolstice,
+  SeptemberEquinox,
+  DecemberSolstice,
+}
+
+/// Polynomial coefficients for the mean equinoxes and solstices of years -1000 to +1000 (Meeus, table 27.A)
+const SEASONS_BEFORE_1000: [[f64; 5]; 4] = [
+  [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071],
+  [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025],
+  [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074],
+  [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006],
+];
+
+/// Polynomial coefficients for the mean equinoxes and solstices of years 1000 to 3000 (Meeus, table 27.B)
+const SEASONS_AFTER_1000: [[f64; 5]; 4] = [
+  [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+  [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+  [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+  [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+];
+
+/// Periodic terms A, B and C for the correction of the mean equinoxes and solstices (Meeus, table 27.C)
+const SEASON_PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+  (485.0, 324.96, 1934.136),
+  (203.0, 337.23, 32964.467),
+  (199.0, 342.08, 20.186),
+  (182.0, 27.85, 445267.112),
+  (156.0, 73.14, 45036.886),
+  (136.0, 171.52, 22518.443),
+  (77.0, 222.54, 65928.934),
+  (74.0, 296.72, 3034.906),
+  (70.0, 243.58, 9037.513),
+  (58.0, 119.81, 33718.147),
+  (52.0, 297.17, 150.678),
+  (50.0, 21.02, 2281.226),
+  (45.0, 247.54, 29929.562),
+  (44.0, 325.15, 31555.956),
+  (29.0, 60.93, 4443.417),
+  (18.0, 155.12, 67555.328),
+  (17.0, 288.79, 4562.452),
+  (16.0, 198.04, 62894.029),
+  (14.0, 199.76, 31436.921),
+  (12.0, 95.39, 14577.848),
+  (12.0, 287.11, 31931.756),
+  (12.0, 320.81, 34777.259),
+  (9.0, 227.73, 1222.114),
+  (8.0, 15.45, 16859.074),
+];
+
+/// Julian Day (TT) of an equinox or solstice in a given astronomical year using Meeus' polynomial series (chapter 27),
+/// accurate to about a minute for years -1000 to +3000
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let solstice_jd = season_event_jd(2024, SeasonEvent::JuneSolstice);
+/// ```
+///
+pub fn season_event_jd(year: i32, event: SeasonEvent) -> f64 {
+  let (coefficients, y) = if year < 1000 {
+    (&SEASONS_BEFORE_1000, year as f64 / 1000.0)
+  } else {
+    (&SEASONS_AFTER_1000, (year as f64 - 2000.0) / 1000.0)
+  };
+  let c = coefficients[event as usize];
+  let mean_jd = c[0] + y * (c[1] + y * (c[2] + y * (c[3] + y * c[4])));
+  let t = julian_centuries(mean_jd);
+  let w = (35999.373 * t - 2.47).to_radians();
+  let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+  let s: f64 = SEASON_PERIODIC_TERMS.iter().map(|(a, b, c)| a * (b + c * t).to_radians().cos()).sum();
+  mean_jd + 0.00001 * s / delta_lambda
+}
+
+///
+/// Julian Day (TT) of the March (northward) equinox in a given year
+///
+pub fn march_equinox_jd(year: i32) -> f64 {
+  season_event_jd(year, SeasonEvent::MarchEquinox)
+}
+
+///
+/// Julian Day (TT) of the June solstice in a given year
+///
+pub fn june_solstice_jd(year: i32) -> f64 {
+  season_event_jd(year, SeasonEvent::JuneSolstice)
+}
+
+///
+/// Julian Day (TT) of the September (southward) equinox in a given year
+///
+pub fn september_equinox_jd(year: i32) -> f64 {
+  season_event_jd(year, SeasonEvent::SeptemberEquinox)
+}
+
+///
+/// Julian Day (TT) of the December solstice in a given year
+///
+pub fn december_solstice_jd(year: i32) -> f64 {
+  season_event_jd(year, SeasonEvent::DecemberSolstice)
+}
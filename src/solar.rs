@@ -0,0 +1,196 @@
+use crate::astro::*;
+use crate::is_supported_jd;
+
+/// Solar zenith angle in degrees at sunrise and sunset, allowing for atmospheric refraction and the solar disc radius
+pub const SUNRISE_ZENITH_DEG: f64 = 90.833;
+
+/// Apparent declination of the Sun in degrees for a Julian Day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let declination = solar_declination(2460117.0);
+/// ```
+///
+pub fn solar_declination(jd: f64) -> f64 {
+  let t = julian_centuries(jd);
+  let omega = (125.04 - 1934.136 * t).to_radians();
+  let obliquity = (mean_obliquity_of_ecliptic(t) + 0.00256 * omega.cos()).to_radians();
+  (obliquity.sin() * sun_apparent_longitude(t).to_radians().sin()).asin().to_degrees()
+}
+
+///
+/// Hour angle in degrees at which the Sun reaches the given zenith angle, or None if it never does on that day
+///
+fn sun_hour_angle(jd: f64, lat_deg: f64, zenith_deg: f64) -> Option<f64> {
+  let lat = lat_deg.to_radians();
+  let dec = solar_declination(jd).to_radians();
+  let cos_ha = zenith_deg.to_radians().cos() / (lat.cos() * dec.cos()) - lat.tan() * dec.tan();
+  if (-1.0..=1.0).contains(&cos_ha) {
+    Some(cos_ha.acos().to_degrees())
+  } else {
+    None
+  }
+}
+
+///
+/// Julian Day when the Sun crosses the given zenith angle before (rising) or after (setting) solar noon.
+/// The hour angle is evaluated at solar noon and then refined at the approximate event time
+///
+pub(crate) fn sun_zenith_crossing_jd(date_jd: f64, lat_deg: f64, lng_deg: f64, zenith_deg: f64, rising: bool) -> Option<f64> {
+  if !is_supported_jd(date_jd) {
+    return None;
+  }
+  let noon = solar_noon_jd(date_jd, lng_deg);
+  let sign = if rising { -1.0 } else { 1.0 };
+  let approx_jd = noon + sign * sun_hour_angle(noon, lat_deg, zenith_deg)? / 360.0;
+  let ha = sun_hour_angle(approx_jd, lat_deg, zenith_deg)?;
+  let midnight = utc_midnight_jd(date_jd);
+  let minutes = 720.0 - 4.0 * (lng_deg - sign * ha) - equation_of_time(approx_jd);
+  Some(midnight + minutes / 1440.0)
+}
+
+/// Julian Day of sunrise on the UTC date containing date_jd for a latitude and longitude in degrees
+/// (north and east positive), following the NOAA solar calculator equations.
+/// Returns None during polar day or polar night when the Sun does not rise.
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Sunrise in Greenwich on the June solstice
+/// if let Some(jd) = sunrise_jd(2460116.5, 51.4769, 0.0) {
+///   println!("The sun rises at {} Julian days", jd);
+/// }
+/// ```
+///
+pub fn sunrise_jd(date_jd: f64, lat_deg: f64, lng_deg: f64) -> Option<f64> {
+  sun_zenith_crossing_jd(date_jd, lat_deg, lng_deg, SUNRISE_ZENITH_DEG, true)
+}
+
+/// Julian Day of sunset on the UTC date containing date_jd for a latitude and longitude in degrees
+/// (north and east positive), following the NOAA solar calculator equations.
+/// Returns None during polar day or polar night when the Sun does not set.
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // Sunset in Greenwich on the June solstice
+/// if let Some(jd) = sunset_jd(2460116.5, 51.4769, 0.0) {
+///   println!("The sun sets at {} Julian days", jd);
+/// }
+/// ```
+///
+pub fn sunset_jd(date_jd: f64, lat_deg: f64, lng_deg: f64) -> Option<f64> {
+  sun_zenith_crossing_jd(date_jd, lat_deg, lng_deg, SUNRISE_ZENITH_DEG, false)
+}
+
+///
+/// Sunrise and sunset as a tuple of optional Julian Days, see sunrise_jd() and sunset_jd()
+///
+pub fn sunrise_sunset_jd(date_jd: f64, lat_deg: f64, lng_deg: f64) -> (Option<f64>, Option<f64>) {
+  (sunrise_jd(date_jd, lat_deg, lng_deg), sunset_jd(date_jd, lat_deg, lng_deg))
+}
+
+///
+/// Solar depression angle below the horizon that defines the start and end of twilight
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Twilight {
+  /// Sun 6º below the horizon
+  Civil,
+  /// Sun 12º below the horizon
+  Nautical,
+  /// Sun 18º below the horizon
+  Astronomical,
+  /// Any other depression angle in degrees, e.g. 15º for some religious observances
+  Custom(f64),
+}
+
+impl Twilight {
+  /// solar depression angle below the geometric horizon in degrees
+  pub fn depression_deg(&self) -> f64 {
+    match self {
+      Twilight::Civil => 6.0,
+      Twilight::Nautical => 12.0,
+      Twilight::Astronomical => 18.0,
+      Twilight::Custom(deg) => *deg,
+    }
+  }
+}
+
+///
+/// Start (dawn) and end (dusk) of twilight as optional Julian Days.
+/// Either may be None at high latitudes when the Sun never reaches the depression angle
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwilightTimes {
+  pub dawn: Option<f64>,
+  pub dusk: Option<f64>,
+}
+
+/// Julian Days of dawn and dusk for the given twilight definition on the UTC date containing date_jd
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let civil = twilight_jd(2460116.5, 51.4769, 0.0, Twilight::Civil);
+/// if let Some(dawn) = civil.dawn {
+///   println!("Civil twilight starts at {} Julian days", dawn);
+/// }
+/// ```
+///
+pub fn twilight_jd(date_jd: f64, lat_deg: f64, lng_deg: f64, twilight: Twilight) -> TwilightTimes {
+  let zenith = 90.0 + twilight.depression_deg();
+  TwilightTimes {
+    dawn: sun_zenith_crossing_jd(date_jd, lat_deg, lng_deg, zenith, true),
+    dusk: sun_zenith_crossing_jd(date_jd, lat_deg, lng_deg, zenith, false),
+  }
+}
+
+///
+/// Full daily light timeline in chronological order, with each event as an optional Julian Day
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarTimeline {
+  pub astronomical_dawn: Option<f64>,
+  pub nautical_dawn: Option<f64>,
+  pub civil_dawn: Option<f64>,
+  pub sunrise: Option<f64>,
+  pub solar_noon: f64,
+  pub sunset: Option<f64>,
+  pub civil_dusk: Option<f64>,
+  pub nautical_dusk: Option<f64>,
+  pub astronomical_dusk: Option<f64>,
+}
+
+/// Calculate all twilight boundaries, sunrise, solar noon and sunset in one call
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let timeline = solar_timeline(2460116.5, 51.4769, 0.0);
+/// println!("Solar noon is at {} Julian days", timeline.solar_noon);
+/// ```
+///
+pub fn solar_timeline(date_jd: f64, lat_deg: f64, lng_deg: f64) -> SolarTimeline {
+  let astronomical = twilight_jd(date_jd, lat_deg, lng_deg, Twilight::Astronomical);
+  let nautical = twilight_jd(date_jd, lat_deg, lng_deg, Twilight::Nautical);
+  let civil = twilight_jd(date_jd, lat_deg, lng_deg, Twilight::Civil);
+  let (sunrise, sunset) = sunrise_sunset_jd(date_jd, lat_deg, lng_deg);
+  SolarTimeline {
+    astronomical_dawn: astronomical.dawn,
+    nautical_dawn: nautical.dawn,
+    civil_dawn: civil.dawn,
+    sunrise,
+    solar_noon: solar_noon_jd(date_jd, lng_deg),
+    sunset,
+    civil_dusk: civil.dusk,
+    nautical_dusk: nautical.dusk,
+    astronomical_dusk: astronomical.dusk,
+  }
+}
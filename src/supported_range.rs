@@ -0,0 +1,161 @@
+use std::ops::RangeInclusive;
+use chrono::NaiveDateTime;
+use crate::*;
+
+/// Earliest Julian Day convertible to chrono::NaiveDateTime (-262143-01-01 00:00:00 UTC)
+pub const MIN_SUPPORTED_JD: f64 = -94024704.5;
+
+/// Latest Julian Day convertible to chrono::NaiveDateTime at whole-second precision (+262142-12-31 23:59:59 UTC)
+pub const MAX_SUPPORTED_JD: f64 = 97466824.5 - 1.0 / 86400.0;
+
+/// Range of Julian Days that convert to chrono::NaiveDateTime and unix timestamps without error
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert!(supported_jd_range().contains(&2460558.5));
+/// ```
+///
+pub fn supported_jd_range() -> RangeInclusive<f64> {
+  MIN_SUPPORTED_JD..=MAX_SUPPORTED_JD
+}
+
+///
+/// Whether a Julian Day is finite and within the supported range
+///
+pub fn is_supported_jd(jd: f64) -> bool {
+  supported_jd_range().contains(&jd)
+}
+
+/// Clamp a Julian Day to the supported range, with NaN clamped to the earliest supported Julian Day
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(clamp_jd(1e12), MAX_SUPPORTED_JD);
+/// assert_eq!(clamp_jd(2460558.5), 2460558.5);
+/// ```
+///
+pub fn clamp_jd(jd: f64) -> f64 {
+  if jd.is_nan() {
+    MIN_SUPPORTED_JD
+  } else {
+    jd.clamp(MIN_SUPPORTED_JD, MAX_SUPPORTED_JD)
+  }
+}
+
+/// Convert a Julian Day to a chrono::NaiveDateTime, clamping values beyond the supported range to the earliest or latest
+/// supported instant instead of failing. NaN saturates to the earliest instant
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_datetime_saturating(1e12).to_string(), "+262142-12-31 23:59:59");
+/// ```
+///
+pub fn julian_day_to_datetime_saturating(jd: f64) -> NaiveDateTime {
+  julian_day_to_datetime(clamp_jd(jd)).unwrap_or(NaiveDateTime::MIN)
+}
+
+///
+/// Convert a Julian Day to a unix timestamp in seconds, clamped to the supported range. NaN saturates to the earliest instant
+///
+pub fn julian_day_to_unixtime_saturating(jd: f64) -> i64 {
+  julian_day_to_datetime_saturating(jd).and_utc().timestamp()
+}
+
+///
+/// Convert a unix timestamp in seconds to a Julian Day, clamping the timestamp to the supported range
+///
+pub fn unixtime_to_julian_day_saturating(ts: i64) -> f64 {
+  let min_ts = NaiveDateTime::MIN.and_utc().timestamp();
+  let max_ts = julian_day_to_unixtime(MAX_SUPPORTED_JD);
+  unixtime_to_julian_day(ts.clamp(min_ts, max_ts))
+}
+
+///
+/// Bounds for validating Julian Days. JdBounds::CHRONO (the default) matches chrono::NaiveDateTime,
+/// JdBounds::EXTENDED covers the chrono-free civil decomposition of julian_day_to_civil(),
+/// and applications may set narrower bounds of their own with JdBounds::new(), which ensures the minimum does not exceed the maximum
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JdBounds {
+  min_jd: f64,
+  max_jd: f64,
+}
+
+impl JdBounds {
+  pub const CHRONO: JdBounds = JdBounds { min_jd: MIN_SUPPORTED_JD, max_jd: MAX_SUPPORTED_JD };
+
+  pub const EXTENDED: JdBounds = JdBounds { min_jd: MIN_EXTENDED_JD, max_jd: MAX_EXTENDED_JD };
+
+  /// Custom bounds, or None if either bound is not finite or the minimum exceeds the maximum
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // 1900-01-01 to 2100-01-01
+  /// let bounds = JdBounds::new(2415020.5, 2488069.5).unwrap();
+  /// assert!(bounds.contains(2460558.5));
+  /// assert_eq!(bounds.clamp(0.0), 2415020.5);
+  /// ```
+  pub fn new(min_jd: f64, max_jd: f64) -> Option<Self> {
+    (is_finite_jd(min_jd) && is_finite_jd(max_jd) && min_jd <= max_jd).then_some(JdBounds { min_jd, max_jd })
+  }
+
+  /// Earliest Julian Day within the bounds
+  pub fn min_jd(&self) -> f64 {
+    self.min_jd
+  }
+
+  /// Latest Julian Day within the bounds
+  pub fn max_jd(&self) -> f64 {
+    self.max_jd
+  }
+
+  pub fn range(&self) -> RangeInclusive<f64> {
+    self.min_jd..=self.max_jd
+  }
+
+  /// Whether a Julian Day is finite and within the bounds
+  pub fn contains(&self, jd: f64) -> bool {
+    self.range().contains(&jd)
+  }
+
+  /// Clamp a Julian Day to the bounds, with NaN clamped to the minimum
+  pub fn clamp(&self, jd: f64) -> f64 {
+    if jd.is_nan() {
+      self.min_jd
+    } else {
+      jd.clamp(self.min_jd, self.max_jd)
+    }
+  }
+
+  /// Decompose a Julian Day into civil date and time fields with julian_day_to_civil() if it is within the bounds
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // a million years from now is beyond chrono but within the extended range
+  /// let civil = JdBounds::EXTENDED.to_civil(2460558.5 + 365.2425 * 1e6).unwrap();
+  /// assert_eq!(civil.year, 1_002_024);
+  /// assert_eq!(JdBounds::CHRONO.to_civil(2460558.5 + 365.2425 * 1e6), Err(JulianDayError::OutOfRange));
+  /// ```
+  pub fn to_civil(&self, jd: f64) -> Result<CivilDateTime, JulianDayError> {
+    if !self.contains(jd) {
+      return Err(if is_finite_jd(jd) { JulianDayError::OutOfRange } else { JulianDayError::NonFinite });
+    }
+    julian_day_to_civil(jd).ok_or(JulianDayError::OutOfRange)
+  }
+}
+
+impl Default for JdBounds {
+  fn default() -> Self {
+    JdBounds::CHRONO
+  }
+}
@@ -0,0 +1,36 @@
+use chrono::{Offset, TimeZone};
+use chrono_tz::Tz;
+use crate::{julian_day_to_datetime, julian_day_to_weekday_index};
+
+/// Offset in seconds from UTC of an IANA timezone such as "America/New_York" at a Julian Day,
+/// including daylight saving time and historical changes from the timezone database.
+/// Returns None if the timezone name is unknown or the Julian Day is out of range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // New York observes EDT (UTC-4) in September
+/// assert_eq!(utc_offset_secs_in_tz(2459827.25, "America/New_York"), Some(-14400));
+/// ```
+///
+pub fn utc_offset_secs_in_tz(jd: f64, tz_name: &str) -> Option<i32> {
+  let tz: Tz = tz_name.parse().ok()?;
+  let utc = julian_day_to_datetime(jd).ok()?;
+  Some(tz.offset_from_utc_datetime(&utc).fix().local_minus_utc())
+}
+
+/// Weekday index (Sun = 0, Mon = 1 ... Sat = 6) of a Julian Day in an IANA timezone such as "America/New_York",
+/// using the historically correct UTC offset. Returns None if the timezone name is unknown or the Julian Day is out of range
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// // 2022-09-05 02:00 UTC is still Sunday evening in New York
+/// assert_eq!(weekday_index_in_tz(2459827.5833333335, "America/New_York"), Some(0));
+/// ```
+///
+pub fn weekday_index_in_tz(jd: f64, tz_name: &str) -> Option<u8> {
+  utc_offset_secs_in_tz(jd, tz_name).map(|offset_secs| julian_day_to_weekday_index(jd, offset_secs))
+}
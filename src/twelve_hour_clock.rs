@@ -0,0 +1,29 @@
+///
+/// Rewrite a date-time with a 12-hour clock time such as "2022-09-04 6:30 PM" or "2022-09-04 6.30pm"
+/// in 24-hour form for the fuzzy ISO parser, or None if the string does not end with am or pm.
+/// 12 am is midnight and 12 pm is noon. Hours outside 1 to 12 are left to fail in the time parser
+///
+pub(crate) fn twelve_hour_to_24_hour_string(dt: &str) -> Option<String> {
+  let trimmed = dt.trim();
+  let lower = trimmed.to_lowercase();
+  let (is_pm, suffix_len) = [("a.m.", false), ("p.m.", true), ("am", false), ("pm", true)]
+    .iter()
+    .find(|(suffix, _)| lower.ends_with(suffix))
+    .map(|(suffix, is_pm)| (*is_pm, suffix.len()))?;
+  let rest = trimmed[..trimmed.len() - suffix_len].trim_end();
+  let (date_part, time_part) = rest.rsplit_once([' ', 'T'])?;
+  let time_parts: Vec<&str> = if time_part.contains(':') {
+    // a dot after colons separates fractional seconds, which the fuzzy parser ignores
+    time_part.split('.').next()?.split(':').collect()
+  } else {
+    time_part.split('.').collect()
+  };
+  if time_parts.len() > 3 || !time_parts.iter().all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())) {
+    return None;
+  }
+  let hour: u32 = time_parts[0].parse().ok()?;
+  let hour_24 = if (1..=12).contains(&hour) { hour % 12 + if is_pm { 12 } else { 0 } } else { 99 };
+  let minute = time_parts.get(1).unwrap_or(&"00");
+  let second = time_parts.get(2).unwrap_or(&"00");
+  Some(format!("{} {:02}:{}:{}", date_part.trim_end(), hour_24, minute, second))
+}
@@ -0,0 +1,122 @@
+use std::ops::{Add, Sub};
+use crate::JULIAN_DAY_UNIX_EPOCH_DAYS;
+
+const NANOS_PER_DAY: i64 = 86_400_000_000_000;
+
+///
+/// Julian Day split into two parts as in SOFA and astropy, where the sum jd1 + jd2 is the Julian Day.
+/// A single f64 resolves only about 20 microseconds near the present epoch, while keeping the day and the fraction apart
+/// preserves nanoseconds. Constructors normalise jd1 to midnight UTC (a whole day + 0.5) and jd2 to a fraction in [0, 1)
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoPartJD {
+  pub jd1: f64,
+  pub jd2: f64,
+}
+
+impl TwoPartJD {
+  /// Construct from two parts in any split, normalising them
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(TwoPartJD::new(2460558.0, 0.25), TwoPartJD { jd1: 2460557.5, jd2: 0.75 });
+  /// ```
+  pub fn new(jd1: f64, jd2: f64) -> Self {
+    let day = (jd1 - 0.5).floor();
+    let fraction = jd2 + (jd1 - 0.5 - day);
+    let carry = fraction.floor();
+    TwoPartJD { jd1: day + carry + 0.5, jd2: fraction - carry }
+  }
+
+  /// Construct from a single Julian Day
+  pub fn from_jd(jd: f64) -> Self {
+    TwoPartJD::new(jd, 0.0)
+  }
+
+  /// Julian Day as a single f64, losing precision beyond about 20 microseconds
+  pub fn jd(&self) -> f64 {
+    self.jd1 + self.jd2
+  }
+
+  /// Construct from nanoseconds since 1970-01-01 00:00:00 UTC without rounding the day
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// let nanos = 1_725_451_200_123_456_789;
+  /// assert_eq!(TwoPartJD::from_unix_nanos(nanos).to_unix_nanos(), nanos);
+  /// ```
+  pub fn from_unix_nanos(nanos: i64) -> Self {
+    unix_nanos_to_julian_day_parts(nanos as i128)
+  }
+
+  /// Nanoseconds since 1970-01-01 00:00:00 UTC, rounded to the nearest nanosecond.
+  /// Saturates outside the i64 range of about 1677 to 2262 CE
+  pub fn to_unix_nanos(&self) -> i64 {
+    julian_day_parts_to_unix_nanos(self).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+  }
+}
+
+impl From<f64> for TwoPartJD {
+  fn from(jd: f64) -> Self {
+    TwoPartJD::from_jd(jd)
+  }
+}
+
+/// Add a number of days, adding whole days and the fraction to separate parts
+impl Add<f64> for TwoPartJD {
+  type Output = TwoPartJD;
+
+  fn add(self, days: f64) -> TwoPartJD {
+    let whole_days = days.floor();
+    TwoPartJD::new(self.jd1 + whole_days, self.jd2 + (days - whole_days))
+  }
+}
+
+/// Subtract a number of days
+impl Sub<f64> for TwoPartJD {
+  type Output = TwoPartJD;
+
+  fn sub(self, days: f64) -> TwoPartJD {
+    self + -days
+  }
+}
+
+/// Difference in days, subtracting the whole days and fractions separately
+impl Sub<TwoPartJD> for TwoPartJD {
+  type Output = f64;
+
+  fn sub(self, other: TwoPartJD) -> f64 {
+    (self.jd1 - other.jd1) + (self.jd2 - other.jd2)
+  }
+}
+
+/// Convert nanoseconds since 1970-01-01 00:00:00 UTC to a two-part Julian Day using i128 arithmetic,
+/// so timestamps from tracing systems keep nanosecond precision across the whole i64 range and beyond
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// let nanos: i128 = 1_725_451_200_000_000_001;
+/// assert_eq!(julian_day_parts_to_unix_nanos(&unix_nanos_to_julian_day_parts(nanos)), nanos);
+/// ```
+///
+pub fn unix_nanos_to_julian_day_parts(nanos: i128) -> TwoPartJD {
+  let days = nanos.div_euclid(NANOS_PER_DAY as i128);
+  let nanos_of_day = nanos.rem_euclid(NANOS_PER_DAY as i128);
+  TwoPartJD { jd1: JULIAN_DAY_UNIX_EPOCH_DAYS + days as f64, jd2: nanos_of_day as f64 / NANOS_PER_DAY as f64 }
+}
+
+///
+/// Convert a two-part Julian Day to nanoseconds since 1970-01-01 00:00:00 UTC, rounded to the nearest nanosecond, using i128 arithmetic
+///
+pub fn julian_day_parts_to_unix_nanos(jd: &TwoPartJD) -> i128 {
+  let normalized = TwoPartJD::new(jd.jd1, jd.jd2);
+  let days = (normalized.jd1 - JULIAN_DAY_UNIX_EPOCH_DAYS).round() as i128;
+  let nanos_of_day = (normalized.jd2 * NANOS_PER_DAY as f64).round() as i128;
+  days.saturating_mul(NANOS_PER_DAY as i128).saturating_add(nanos_of_day)
+}
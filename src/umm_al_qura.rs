@@ -0,0 +1,176 @@
+use crate::calendar::Calendar;
+use crate::civil::*;
+use crate::is_supported_jd;
+
+/// First year AH covered by the Umm al-Qura tables (1882-11-12 CE)
+pub const UMM_AL_QURA_FIRST_YEAR: i32 = 1300;
+
+/// Last year AH covered by the Umm al-Qura tables (ending 2174-11-25 CE)
+pub const UMM_AL_QURA_LAST_YEAR: i32 = 1600;
+
+/// Julian Day Number of 1 Muharram 1300 AH
+const UMM_AL_QURA_EPOCH_JDN: i64 = 2408762;
+
+/// Month lengths for each year from 1300 to 1600 AH, where bit 0 (Muharram) to bit 11 (Dhu al-Hijjah) is set for 30-day months.
+/// Derived from the KACST month lengths as tabulated in ICU
+const UMM_AL_QURA_MONTHS: [u16; 301] = [
+  0x555, 0x2AB, 0x937, 0x2B6, 0x576, 0x36C, 0xB55, 0xAAA, 0x956, 0x49E,
+  0x95D, 0x2BA, 0x5B5, 0x3AA, 0xB4B, 0xA96, 0x52E, 0x2AD, 0x56D, 0xB5A,
+  0x752, 0xF25, 0xE8A, 0xD16, 0xA56, 0xAB5, 0x6B4, 0xDA9, 0xB92, 0xB25,
+  0x64B, 0xA9B, 0x35A, 0x6D9, 0x5D4, 0xDA5, 0xD4A, 0xA95, 0x536, 0x975,
+  0x2F4, 0x6E9, 0x6D4, 0x6A9, 0x535, 0x25D, 0x4BD, 0x9BA, 0x3B4, 0xB69,
+  0xB2A, 0xA55, 0x4AD, 0xA5D, 0x2DA, 0x6D9, 0xEAA, 0xE94, 0xD2A, 0xC56,
+  0x4AE, 0xA6D, 0x56A, 0xD55, 0xD4A, 0xA93, 0x52B, 0xA5B, 0x53A, 0x6B5,
+  0xEA9, 0xD52, 0xD29, 0xA55, 0x4AD, 0x56D, 0xAEA, 0x6E4, 0xED1, 0xDA2,
+  0xAAA, 0x95A, 0x2DA, 0x5B9, 0xBB2, 0x764, 0x6C9, 0x555, 0x2AB, 0x4DB,
+  0xABA, 0x5B4, 0xDA9, 0xD52, 0xAA5, 0x92D, 0x26D, 0x8ED, 0x2DA, 0xAD5,
+  0xAA5, 0xA4B, 0x497, 0x937, 0x2B6, 0x975, 0xD69, 0xD52, 0xC95, 0x92B,
+  0x25B, 0x4DB, 0x9D5, 0x5D2, 0xDA5, 0xD4A, 0xA95, 0x54D, 0xAAD, 0x3AA,
+  0xBD2, 0xBC4, 0xB89, 0xA95, 0x52D, 0x5AD, 0xB6A, 0x6D4, 0xDC9, 0xD92,
+  0xAA6, 0x956, 0x2AE, 0x56D, 0x36A, 0xB55, 0xAAA, 0x94D, 0x49D, 0x95D,
+  0x2BA, 0x5B5, 0x5AA, 0xD55, 0xA9A, 0x92E, 0x26E, 0x55D, 0xADA, 0x6D4,
+  0x6A5, 0xB27, 0xA4D, 0x4AD, 0x56D, 0xB5A, 0x754, 0xF49, 0xE92, 0xD26,
+  0xA56, 0x356, 0x6B5, 0xBAA, 0xB92, 0xB25, 0x68B, 0xA9B, 0x55A, 0xADA,
+  0x5B4, 0xDA9, 0xB52, 0xA9A, 0x536, 0x276, 0x575, 0xAF2, 0x6D4, 0x6A9,
+  0x555, 0x2AD, 0x4BD, 0x9BA, 0x574, 0xB69, 0xB52, 0xA95, 0x52D, 0xA5D,
+  0x4DA, 0xAD9, 0x6B2, 0xE95, 0xE2A, 0xC96, 0x92E, 0xAAD, 0x56A, 0xD65,
+  0xD4A, 0xD15, 0x62B, 0xC5B, 0x53A, 0x6B5, 0xDB2, 0xD64, 0xD29, 0xA55,
+  0x4AD, 0x96D, 0xAEA, 0x6E8, 0xED1, 0xDA4, 0xD4A, 0xA6A, 0x2DA, 0x5B9,
+  0xB72, 0xB68, 0x6D1, 0x655, 0x4AB, 0x95B, 0x2BA, 0x5B5, 0xDA9, 0xD52,
+  0xCA6, 0x94E, 0x46E, 0x95D, 0x4DA, 0xAD5, 0xAAA, 0xA4D, 0x49B, 0x937,
+  0x4B6, 0x975, 0xD6A, 0xD52, 0xAA5, 0x94B, 0x2AB, 0x55B, 0xAD9, 0x5D2,
+  0xDC5, 0xD92, 0xB25, 0x555, 0xAB5, 0x5B4, 0xBA9, 0x7A2, 0x745, 0x593,
+  0xAAB, 0x4D6, 0x9D6, 0x5D2, 0xBA5, 0xB4A, 0xA95, 0x4AD, 0x15D, 0x2DD,
+  0x9DA, 0x5B4, 0x5A9, 0x52D, 0x25B, 0x8B7, 0x176, 0x56D, 0xB6A, 0xACA,
+  0xA96, 0x52B, 0x15B, 0x2BB, 0x5B6, 0xDAA, 0xB94, 0xD46, 0xA8D, 0x52D,
+  0xA9D, 0x55A, 0x755, 0x749, 0xF13, 0xE4A, 0xA96, 0x556, 0x6B5, 0xBAA,
+  0xB94,
+];
+
+///
+/// The Umm al-Qura Hijri calendar used in Saudi Arabia, based on lookup tables of predicted month lengths.
+/// Only years 1300 to 1600 AH are supported, and conversions outside that range return None
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UmmAlQura;
+
+impl UmmAlQura {
+  /// Whether a year AH falls within the range of the lookup tables
+  pub fn is_supported_year(&self, year: i32) -> bool {
+    (UMM_AL_QURA_FIRST_YEAR..=UMM_AL_QURA_LAST_YEAR).contains(&year)
+  }
+
+  /// Number of days (29 or 30) in a month, or None outside the supported range
+  pub fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    if self.is_supported_year(year) && (1..=12).contains(&month) {
+      let mask = UMM_AL_QURA_MONTHS[(year - UMM_AL_QURA_FIRST_YEAR) as usize];
+      Some(if mask & (1 << (month - 1)) != 0 { 30 } else { 29 })
+    } else {
+      None
+    }
+  }
+
+  /// Number of days (354 or 355) in a year, or None outside the supported range
+  pub fn days_in_year(&self, year: i32) -> Option<u32> {
+    if self.is_supported_year(year) {
+      Some(354 + UMM_AL_QURA_MONTHS[(year - UMM_AL_QURA_FIRST_YEAR) as usize].count_ones() - 6)
+    } else {
+      None
+    }
+  }
+
+  /// Julian Day at 00:00 UTC for an Umm al-Qura date, or None if the date is invalid or outside the supported range
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// // 1 Ramadan 1445 AH was 11 March 2024
+  /// assert_eq!(UmmAlQura.date_to_julian_day(1445, 9, 1), Some(2460380.5));
+  /// ```
+  pub fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    if day < 1 || day > self.days_in_month(year, month)? {
+      return None;
+    }
+    let month_offset: u32 = (1..month).map(|m| self.days_in_month(year, m).unwrap_or(29)).sum();
+    Some((self.year_start_jdn(year) + month_offset as i64 + day as i64 - 1) as f64 - 0.5)
+  }
+
+  /// Umm al-Qura date as (year, month, day) for the UTC calendar day containing a Julian Day,
+  /// or None outside the supported range
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(UmmAlQura.julian_day_to_date(2460380.5), Some((1445, 9, 1)));
+  /// ```
+  pub fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    if !is_supported_jd(jd) {
+      return None;
+    }
+    let jdn = jd_to_jdn(jd);
+    if jdn < UMM_AL_QURA_EPOCH_JDN {
+      return None;
+    }
+    let mut year = UMM_AL_QURA_FIRST_YEAR;
+    let mut remaining = jdn - UMM_AL_QURA_EPOCH_JDN;
+    while remaining >= self.days_in_year(year)? as i64 {
+      remaining -= self.days_in_year(year)? as i64;
+      year += 1;
+    }
+    let mut month = 1;
+    while remaining >= self.days_in_month(year, month)? as i64 {
+      remaining -= self.days_in_month(year, month)? as i64;
+      month += 1;
+    }
+    Some((year, month, remaining as u32 + 1))
+  }
+
+  ///
+  /// Julian Day Number of 1 Muharram of a supported year
+  ///
+  fn year_start_jdn(&self, year: i32) -> i64 {
+    UMM_AL_QURA_EPOCH_JDN + (UMM_AL_QURA_FIRST_YEAR..year).filter_map(|y| self.days_in_year(y)).map(|days| days as i64).sum::<i64>()
+  }
+}
+
+impl Calendar for UmmAlQura {
+  fn months_in_year(&self, _year: i32) -> u32 {
+    12
+  }
+
+  fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+    UmmAlQura::days_in_month(self, year, month)
+  }
+
+  fn is_leap_year(&self, year: i32) -> bool {
+    UmmAlQura::days_in_year(self, year) == Some(355)
+  }
+
+  fn date_to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<f64> {
+    UmmAlQura::date_to_julian_day(self, year, month, day)
+  }
+
+  fn julian_day_to_date(&self, jd: f64) -> Option<(i32, u32, u32)> {
+    UmmAlQura::julian_day_to_date(self, jd)
+  }
+
+  fn days_in_year(&self, year: i32) -> Option<u32> {
+    UmmAlQura::days_in_year(self, year)
+  }
+}
+
+///
+/// Convert a Julian Day to an Umm al-Qura date (year, month, day) if within 1300 to 1600 AH
+///
+pub fn julian_day_to_umm_al_qura(jd: f64) -> Option<(i32, u32, u32)> {
+  UmmAlQura.julian_day_to_date(jd)
+}
+
+///
+/// Convert an Umm al-Qura date to the Julian Day at 00:00 UTC if valid and within 1300 to 1600 AH
+///
+pub fn umm_al_qura_to_julian_day(year: i32, month: u32, day: u32) -> Option<f64> {
+  UmmAlQura.date_to_julian_day(year, month, day)
+}
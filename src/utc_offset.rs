@@ -0,0 +1,149 @@
+/// Parse a UTC offset such as +05:30, -0800 or +01 to seconds east of UTC, or None if it is not a valid offset
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(parse_utc_offset("+05:30"), Some(19800));
+/// assert_eq!(parse_utc_offset("-0800"), Some(-28800));
+/// ```
+///
+pub fn parse_utc_offset(offset_str: &str) -> Option<i32> {
+  let trimmed = offset_str.trim();
+  let sign = match trimmed.chars().next()? {
+    '+' => 1,
+    '-' | '−' => -1,
+    _ => return None,
+  };
+  let digits: String = trimmed.chars().skip(1).filter(|c| *c != ':').collect();
+  let colons = trimmed.matches(':').count();
+  let valid_colon = colons == 0 || (colons == 1 && trimmed.chars().nth(3) == Some(':') && digits.len() == 4);
+  if !valid_colon || !(digits.len() == 2 || digits.len() == 4) || !digits.chars().all(|c| c.is_ascii_digit()) {
+    return None;
+  }
+  let hours: i32 = digits[..2].parse().ok()?;
+  let minutes: i32 = if digits.len() == 4 { digits[2..].parse().ok()? } else { 0 };
+  if hours > 23 || minutes > 59 {
+    return None;
+  }
+  Some(sign * (hours * 3600 + minutes * 60))
+}
+
+///
+/// Whether a date-time string has a time of day, so a trailing sign can only belong to a UTC offset
+/// rather than to a year-month-day date
+///
+fn has_time_part(dt: &str) -> bool {
+  let chars: Vec<char> = dt.chars().collect();
+  dt.contains(':') || chars.windows(3).any(|w| w[0].is_ascii_digit() && (w[1] == 'T' || w[1] == ' ') && w[2].is_ascii_digit())
+}
+
+///
+/// Split a trailing UTC designator from a date-time string: Z, UTC or a numeric offset such as +05:30 or -0800.
+/// Returns the remaining string and the offset in seconds east of UTC, which is None if no designator was found,
+/// or None overall if a numeric offset after the time is out of range
+///
+pub(crate) fn split_utc_offset(dt: &str) -> Option<(&str, Option<i32>)> {
+  let trimmed = dt.trim();
+  if let Some(rest) = trimmed.strip_suffix(['Z', 'z']) {
+    if rest.ends_with(|c: char| c.is_ascii_digit()) {
+      return Some((rest, Some(0)));
+    }
+  }
+  if trimmed.len() > 3 && trimmed.is_char_boundary(trimmed.len() - 3) && trimmed[trimmed.len() - 3..].eq_ignore_ascii_case("UTC") {
+    return Some((trimmed[..trimmed.len() - 3].trim_end(), Some(0)));
+  }
+  if let Some(index) = trimmed.rfind(['+', '-', '−']) {
+    let (rest, offset_str) = trimmed.split_at(index);
+    // an offset may follow UTC, as in 12:00 UTC+05:30
+    let rest = rest.trim_end();
+    let rest = rest.strip_suffix("UTC").map(str::trim_end).unwrap_or(rest);
+    let offset_like = offset_str.chars().skip(1).all(|c| c.is_ascii_digit() || c == ':');
+    if has_time_part(rest) && offset_like {
+      return parse_utc_offset(offset_str).map(|offset_secs| (rest, Some(offset_secs)));
+    }
+  }
+  Some((trimmed, None))
+}
+
+///
+/// Why a timezone abbreviation could not be converted to an offset
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TzAbbreviationError {
+  /// The abbreviation is not in the table
+  Unknown,
+  /// The abbreviation has more than one offset in the table, e.g. IST for India, Israel and Ireland
+  Ambiguous,
+}
+
+///
+/// Table of timezone abbreviations and their offsets in seconds east of UTC.
+/// An abbreviation listed with more than one offset is ambiguous, so a custom table can resolve it by listing only one
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TzAbbreviationTable {
+  pub entries: &'static [(&'static str, i32)],
+}
+
+impl TzAbbreviationTable {
+  pub const COMMON: TzAbbreviationTable = TzAbbreviationTable {
+    entries: &[
+      ("UTC", 0), ("UT", 0), ("GMT", 0), ("Z", 0),
+      ("EST", -18000), ("EDT", -14400), ("CST", -21600), ("CDT", -18000), ("MST", -25200), ("MDT", -21600),
+      ("PST", -28800), ("PDT", -25200), ("AKST", -32400), ("AKDT", -28800), ("HST", -36000),
+      ("AST", -14400), ("ADT", -10800), ("NST", -12600), ("NDT", -9000),
+      ("WET", 0), ("WEST", 3600), ("BST", 3600), ("IST", 3600), ("CET", 3600), ("CEST", 7200),
+      ("EET", 7200), ("EEST", 10800), ("MSK", 10800), ("WAT", 3600), ("SAST", 7200), ("EAT", 10800),
+      ("IST", 7200), ("AST", 10800), ("PKT", 18000), ("IST", 19800), ("BST", 21600), ("ICT", 25200), ("WIB", 25200),
+      ("CST", 28800), ("HKT", 28800), ("SGT", 28800), ("AWST", 28800), ("JST", 32400), ("KST", 32400),
+      ("ACST", 34200), ("AEST", 36000), ("AEDT", 39600), ("NZST", 43200), ("NZDT", 46800),
+    ],
+  };
+
+  /// Offset in seconds east of UTC for an abbreviation, ignoring case
+  ///
+  /// ### Example:
+  /// ```
+  /// use julian_day_converter::*;
+  ///
+  /// assert_eq!(TzAbbreviationTable::COMMON.lookup("EDT"), Ok(-14400));
+  /// assert_eq!(TzAbbreviationTable::COMMON.lookup("IST"), Err(TzAbbreviationError::Ambiguous));
+  /// ```
+  pub fn lookup(&self, abbreviation: &str) -> Result<i32, TzAbbreviationError> {
+    let mut matches = self.entries.iter().filter(|(name, _)| name.eq_ignore_ascii_case(abbreviation)).map(|(_, offset_secs)| *offset_secs);
+    let offset_secs = matches.next().ok_or(TzAbbreviationError::Unknown)?;
+    if matches.any(|other| other != offset_secs) {
+      return Err(TzAbbreviationError::Ambiguous);
+    }
+    Ok(offset_secs)
+  }
+}
+
+impl Default for TzAbbreviationTable {
+  fn default() -> Self {
+    TzAbbreviationTable::COMMON
+  }
+}
+
+/// Names of UTC read after the time of day when no table of abbreviations is set
+pub(crate) const UTC_NAMES: TzAbbreviationTable = TzAbbreviationTable { entries: &[("GMT", 0), ("UT", 0)] };
+
+/// Words after the time of day that later stages of the fuzzy parser read as 12-hour clock or era labels
+const TIME_SUFFIX_LABELS: [&str; 7] = ["AM", "PM", "BCE", "BC", "AD", "CE", "BE"];
+
+///
+/// Split a trailing timezone abbreviation in the table from a date-time string with a time of day.
+/// Returns the remaining string and the offset, with no offset if the string has no trailing word or it is a 12-hour clock
+/// or era label, or an error if the word is not in the table or is ambiguous, so a local time is never read as UTC
+///
+pub(crate) fn split_tz_abbreviation<'a>(dt: &'a str, table: &TzAbbreviationTable) -> Result<(&'a str, Option<i32>), TzAbbreviationError> {
+  let trimmed = dt.trim();
+  if let Some((rest, word)) = trimmed.rsplit_once(' ') {
+    let is_label = TIME_SUFFIX_LABELS.iter().any(|label| word.eq_ignore_ascii_case(label));
+    if has_time_part(rest) && word.chars().all(|c| c.is_ascii_alphabetic()) && !is_label {
+      return table.lookup(word).map(|offset_secs| (rest.trim_end(), Some(offset_secs)));
+    }
+  }
+  Ok((trimmed, None))
+}
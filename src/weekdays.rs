@@ -0,0 +1,100 @@
+use chrono::Weekday;
+use crate::civil::*;
+use crate::julian_day_to_weekday_index;
+
+/// Julian Day of the next occurrence of a weekday after a Julian Day in the local time given by an offset in seconds,
+/// keeping the time of day. If inclusive is true and the Julian Day already falls on that weekday, it is returned unchanged
+///
+/// ### Example:
+/// ```
+/// use chrono::Weekday;
+/// use julian_day_converter::*;
+///
+/// // from Sunday 2022-09-04 09:00 UTC to Monday 2022-09-05 09:00 UTC
+/// assert_eq!(next_weekday_jd(2459826.875, Weekday::Mon, 0, false), 2459827.875);
+/// ```
+///
+pub fn next_weekday_jd(jd: f64, weekday: Weekday, offset_secs: i32, inclusive: bool) -> f64 {
+  let current = julian_day_to_weekday_index(jd, offset_secs) as i64;
+  let days = (weekday.num_days_from_sunday() as i64 - current).rem_euclid(7);
+  jd + if days == 0 && !inclusive { 7.0 } else { days as f64 }
+}
+
+///
+/// Julian Day of the previous occurrence of a weekday before a Julian Day, see next_weekday_jd()
+///
+pub fn previous_weekday_jd(jd: f64, weekday: Weekday, offset_secs: i32, inclusive: bool) -> f64 {
+  let current = julian_day_to_weekday_index(jd, offset_secs) as i64;
+  let days = (current - weekday.num_days_from_sunday() as i64).rem_euclid(7);
+  jd - if days == 0 && !inclusive { 7.0 } else { days as f64 }
+}
+
+/// Julian Day at 00:00 UTC of the nth occurrence (from 1) of a weekday in a Gregorian month,
+/// or None if the month is invalid or has fewer occurrences, e.g. the 4th Thursday in November
+///
+/// ### Example:
+/// ```
+/// use chrono::Weekday;
+/// use julian_day_converter::*;
+///
+/// // Thanksgiving 2024 fell on 28 November
+/// assert_eq!(nth_weekday_of_month(2024, 11, Weekday::Thu, 4), Some(2460642.5));
+/// assert_eq!(nth_weekday_of_month(2024, 11, Weekday::Thu, 5), None);
+/// ```
+///
+pub fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: u32) -> Option<f64> {
+  if n < 1 || !(1..=12).contains(&month) {
+    return None;
+  }
+  let first_jdn = gregorian_to_jdn(year as i64, month, 1);
+  let first_offset = (weekday.num_days_from_sunday() as i64 - jdn_weekday_index(first_jdn) as i64).rem_euclid(7);
+  let day = first_offset + 7 * (n as i64 - 1) + 1;
+  if day > month_length(month, is_gregorian_leap_year(year as i64)) as i64 {
+    return None;
+  }
+  Some((first_jdn + day - 1) as f64 - 0.5)
+}
+
+/// Julian Day at 00:00 UTC of the last occurrence of a weekday in a Gregorian month, or None if the month is invalid,
+/// e.g. the last Monday in May
+///
+/// ### Example:
+/// ```
+/// use chrono::Weekday;
+/// use julian_day_converter::*;
+///
+/// // Memorial Day 2024 fell on 27 May
+/// assert_eq!(last_weekday_of_month(2024, 5, Weekday::Mon), Some(2460457.5));
+/// ```
+///
+pub fn last_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> Option<f64> {
+  if !(1..=12).contains(&month) {
+    return None;
+  }
+  let last_jdn = gregorian_to_jdn(year as i64, month, month_length(month, is_gregorian_leap_year(year as i64)));
+  let days_back = (jdn_weekday_index(last_jdn) as i64 - weekday.num_days_from_sunday() as i64).rem_euclid(7);
+  Some((last_jdn - days_back) as f64 - 0.5)
+}
+
+/// Number of times a weekday occurs from the local calendar day containing start_jd up to but excluding the day containing end_jd,
+/// in the local time given by an offset in seconds. Computed arithmetically, so long ranges cost no more than short ones
+///
+/// ### Example:
+/// ```
+/// use chrono::Weekday;
+/// use julian_day_converter::*;
+///
+/// // Fridays from 1 January 2024 to 1 January 2025
+/// assert_eq!(count_weekday_in_range(2460310.5, 2460676.5, Weekday::Fri, 0), 52);
+/// ```
+///
+pub fn count_weekday_in_range(start_jd: f64, end_jd: f64, weekday: Weekday, offset_secs: i32) -> u64 {
+  let offset_days = offset_secs as f64 / 86400.0;
+  let start_jdn = jd_to_jdn(start_jd + offset_days);
+  let days = jd_to_jdn(end_jd + offset_days) - start_jdn;
+  if days <= 0 {
+    return 0;
+  }
+  let first_offset = (weekday.num_days_from_sunday() as i64 - jdn_weekday_index(start_jdn) as i64).rem_euclid(7);
+  (days / 7 + if first_offset < days % 7 { 1 } else { 0 }) as u64
+}
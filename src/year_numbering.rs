@@ -0,0 +1,144 @@
+use crate::civil::*;
+use crate::is_supported_jd;
+use crate::civil_time::civil_to_julian_day;
+use crate::calendar::{ordinal_to_calendar_date, parse_calendar_parts, ProlepticGregorian};
+
+///
+/// Year numbering systems that differ from the Common Era (proleptic Gregorian, astronomical years) by a fixed offset
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearNumbering {
+  /// Common Era with astronomical year numbering
+  CommonEra,
+  /// Holocene or Human Era (HE = CE + 10000)
+  Holocene,
+  /// Thai Buddhist Era (BE = CE + 543), with years starting on 1 January as in Thailand since 1941
+  BuddhistEra,
+  /// Republic of China (Minguo) calendar (ROC = CE - 1911), used in Taiwan
+  Minguo,
+}
+
+impl YearNumbering {
+  /// Number of years added to a Common Era year
+  pub fn offset(&self) -> i64 {
+    match self {
+      YearNumbering::CommonEra => 0,
+      YearNumbering::Holocene => 10000,
+      YearNumbering::BuddhistEra => 543,
+      YearNumbering::Minguo => -1911,
+    }
+  }
+
+  /// Convert a Common Era year to this year numbering
+  pub fn from_common_era(&self, year: i64) -> i64 {
+    year + self.offset()
+  }
+
+  /// Convert a year in this year numbering to the Common Era
+  pub fn to_common_era(&self, year: i64) -> i64 {
+    year - self.offset()
+  }
+}
+
+/// Format a Julian Day as YYYY-mm-dd HH:MM:SS in the Gregorian calendar with years in the given numbering,
+/// or None for NaN, infinite or unsupported Julian Days
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(julian_day_to_era_string(2460557.5, YearNumbering::Holocene), Some("12024-09-04 00:00:00".to_string()));
+/// ```
+///
+pub fn julian_day_to_era_string(jd: f64, numbering: YearNumbering) -> Option<String> {
+  if !is_supported_jd(jd) {
+    return None;
+  }
+  let (jdn, secs) = jd_to_jdn_and_seconds(jd);
+  let (year, month, day) = jdn_to_gregorian(jdn);
+  Some(format_civil(numbering.from_common_era(year), month, day, secs))
+}
+
+/// Parse an ISO-8601-like date-time string with the same fuzzy rules as iso_fuzzy_string_to_datetime(),
+/// reading the year in the given numbering, and return its Julian Day or None if the string is not a valid date.
+/// Dates labelled with their own era, such as "民國113年9月4日" or "พ.ศ. 2567-09-04", are read in that era instead
+///
+/// ### Example:
+/// ```
+/// use julian_day_converter::*;
+///
+/// assert_eq!(era_string_to_julian_day("12024-09-04", YearNumbering::Holocene), Some(2460557.5));
+/// assert_eq!(era_string_to_julian_day("民國113年9月4日", YearNumbering::Minguo), Some(2460557.5));
+/// ```
+///
+pub fn era_string_to_julian_day(dt_str: &str, numbering: YearNumbering) -> Option<f64> {
+  // the fuzzy normaliser has already converted a labelled year to the Common Era
+  let numbering = if has_era_label(dt_str) { YearNumbering::CommonEra } else { numbering };
+  let (year, month, day, hour, minute, second) = parse_calendar_parts(dt_str, |year, day_of_year| {
+    let (ce_year, month, day) = ordinal_to_calendar_date(&ProlepticGregorian, i32::try_from(numbering.to_common_era(year)).ok()?, day_of_year)?;
+    Some((numbering.from_common_era(ce_year as i64), month, day))
+  })?;
+  civil_to_julian_day(numbering.to_common_era(year), month, day, hour, minute, second, 0)
+}
+
+///
+/// Whether a date string labels its year with the Minguo calendar or the Buddhist Era
+///
+fn has_era_label(dt: &str) -> bool {
+  minguo_to_common_era_string(dt).is_some() || buddhist_era_to_common_era_string(dt).is_some()
+}
+
+/// Prefixes marking a year in the Minguo calendar
+const MINGUO_PREFIXES: [&str; 3] = ["民國", "民国", "ROC"];
+
+///
+/// Rewrite a Minguo date such as "民國113年9月4日" or "ROC 113/09/04 14:30" as a Common Era date string
+/// for the fuzzy ISO parser, or None if the string has no Minguo prefix
+///
+pub(crate) fn minguo_to_common_era_string(dt: &str) -> Option<String> {
+  let trimmed = dt.trim();
+  let rest = MINGUO_PREFIXES.iter().find_map(|prefix| trimmed.strip_prefix(prefix))?.trim_start();
+  let (year_str, date_rest) = match rest.split_once('年') {
+    Some((year_str, month_day)) => {
+      let (month, day_time) = month_day.split_once('月')?;
+      let (day, time) = day_time.split_once('日').unwrap_or((day_time, ""));
+      (year_str, format!("-{}-{}{}", month.trim(), day.trim(), time))
+    },
+    None => {
+      let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+      (&rest[..end], rest[end..].replacen('/', "-", 2))
+    },
+  };
+  let year = YearNumbering::Minguo.to_common_era(year_str.trim().parse().ok()?);
+  Some(format!("{:04}{}", year, date_rest))
+}
+
+/// Labels marking a year in the Thai Buddhist Era, with the Thai abbreviation พ.ศ. (Phutthasakarat) first
+const BUDDHIST_ERA_LABELS: [&str; 3] = ["พ.ศ.", "B.E.", "BE"];
+
+///
+/// Rewrite a date labelled with the Thai Buddhist Era, e.g. "พ.ศ. 2567-09-04", "2567-09-04 BE" or "4/9/2567 B.E. 14:30",
+/// as a Common Era date string for the fuzzy ISO parser, or None if the string has no Buddhist Era label.
+/// The year is the first number of four or more digits
+///
+pub(crate) fn buddhist_era_to_common_era_string(dt: &str) -> Option<String> {
+  let mut tokens: Vec<&str> = dt.split_whitespace().collect();
+  let (index, remainder) = tokens.iter().enumerate().find_map(|(index, token)| {
+    let label = BUDDHIST_ERA_LABELS.iter().find(|label| token.to_uppercase().starts_with(*label))?;
+    let remainder = token.get(label.len()..)?;
+    (remainder.is_empty() || remainder.starts_with(|c: char| c.is_ascii_digit())).then_some((index, remainder))
+  })?;
+  tokens[index] = remainder;
+  let rest = tokens.into_iter().filter(|token| !token.is_empty()).collect::<Vec<&str>>().join(" ");
+  let mut start = 0;
+  let (year_start, year_end) = loop {
+    let run_start = start + rest[start..].find(|c: char| c.is_ascii_digit())?;
+    let run_end = rest[run_start..].find(|c: char| !c.is_ascii_digit()).map_or(rest.len(), |end| run_start + end);
+    if run_end - run_start >= 4 {
+      break (run_start, run_end);
+    }
+    start = run_end;
+  };
+  let year = YearNumbering::BuddhistEra.to_common_era(rest[year_start..year_end].parse().ok()?);
+  Some(format!("{}{:04}{}", &rest[..year_start], year, &rest[year_end..]))
+}
@@ -1,5 +1,7 @@
+#![allow(clippy::needless_borrow)]
+
 use julian_day_converter::*;
-use chrono::{NaiveDateTime, NaiveDate, NaiveTime};
+use chrono::{NaiveDateTime, NaiveDate, NaiveTime, Weekday};
 
 #[cfg(test)]
 
@@ -69,4 +71,1930 @@ fn test_julian_day_datetime_utc() {
   let expected_datetime_string = "2023-09-06T09:00:00".to_string();
   let result = datetime.format("%Y-%m-%dT%H:%M:%S").to_string();
   assert_eq!(expected_datetime_string, result);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_sidereal_time() {
+  // Meeus, Astronomical Algorithms, example 12.b: 1987-04-10 19:21:00 UT => 8h 34m 57.0896s
+  let jd = 2446896.30625;
+  let expected_gmst = 8.0 + 34.0 / 60.0 + 57.0896 / 3600.0;
+  assert!((gmst(jd) - expected_gmst).abs() < 1e-6);
+  // 90º East is 6 hours ahead of Greenwich
+  assert!((lst(jd, 90.0) - (expected_gmst + 6.0)).abs() < 1e-6);
+  // 150º West is 10 hours behind, wrapping around to the previous sidereal day
+  assert!((lst(jd, -150.0) - (expected_gmst + 14.0)).abs() < 1e-6);
+}
+
+#[test]
+fn test_equation_of_time() {
+  // the sundial is about 16m 25s fast in early November and 14m 15s slow in mid February
+  let november_3 = datetime_to_julian_day("2023-11-03 12:00").unwrap();
+  assert!((equation_of_time(november_3) - 16.43).abs() < 0.1);
+  let february_11 = datetime_to_julian_day("2023-02-11 12:00").unwrap();
+  assert!((equation_of_time(february_11) + 14.2).abs() < 0.1);
+}
+
+#[test]
+fn test_solar_noon_jd() {
+  // Greenwich on 2023-11-03: the sun transits at about 11:43:35 UTC
+  let noon_jd = solar_noon_jd(datetime_to_julian_day("2023-11-03 18:00").unwrap(), 0.0);
+  let expected_jd = datetime_to_julian_day("2023-11-03 11:43:35").unwrap();
+  assert!((noon_jd - expected_jd).abs() < 10.0 / 86400.0);
+  // 90ºW transits 6 hours later
+  let western_noon_jd = solar_noon_jd(noon_jd, -90.0);
+  assert!((western_noon_jd - noon_jd - 0.25).abs() < 1.0 / 86400.0);
+}
+
+#[cfg(feature = "solar")]
+#[test]
+fn test_sunrise_sunset() {
+  // Greenwich on the June solstice: sunrise at about 03:43 UTC and sunset at about 20:21 UTC
+  let date_jd = datetime_to_julian_day("2023-06-21").unwrap();
+  let (sunrise, sunset) = sunrise_sunset_jd(date_jd, 51.4769, 0.0);
+  let expected_sunrise = datetime_to_julian_day("2023-06-21 03:43").unwrap();
+  let expected_sunset = datetime_to_julian_day("2023-06-21 20:21").unwrap();
+  assert!((sunrise.unwrap() - expected_sunrise).abs() < 2.0 / 1440.0);
+  assert!((sunset.unwrap() - expected_sunset).abs() < 2.0 / 1440.0);
+  // Tromsø has polar day in June and polar night in December
+  assert_eq!(sunrise_jd(date_jd, 69.65, 18.96), None);
+  let december_jd = datetime_to_julian_day("2023-12-21").unwrap();
+  assert_eq!(sunset_jd(december_jd, 69.65, 18.96), None);
+}
+
+#[cfg(feature = "solar")]
+#[test]
+fn test_twilight_times() {
+  // Greenwich on the equinox: civil dawn about 05:30 UTC and civil dusk about 18:45 UTC
+  let date_jd = datetime_to_julian_day("2023-03-20").unwrap();
+  let civil = twilight_jd(date_jd, 51.4769, 0.0, Twilight::Civil);
+  assert!((civil.dawn.unwrap() - datetime_to_julian_day("2023-03-20 05:30").unwrap()).abs() < 3.0 / 1440.0);
+  assert!((civil.dusk.unwrap() - datetime_to_julian_day("2023-03-20 18:45").unwrap()).abs() < 3.0 / 1440.0);
+  let timeline = solar_timeline(date_jd, 51.4769, 0.0);
+  let events = [timeline.astronomical_dawn, timeline.nautical_dawn, timeline.civil_dawn, timeline.sunrise, Some(timeline.solar_noon), timeline.sunset, timeline.civil_dusk, timeline.nautical_dusk, timeline.astronomical_dusk];
+  let jds: Vec<f64> = events.iter().map(|e| e.unwrap()).collect();
+  assert!(jds.windows(2).all(|pair| pair[0] < pair[1]));
+  // London does not reach astronomical darkness around the June solstice
+  let june_jd = datetime_to_julian_day("2023-06-21").unwrap();
+  assert_eq!(twilight_jd(june_jd, 51.4769, 0.0, Twilight::Astronomical).dawn, None);
+}
+
+#[test]
+fn test_moon_phases() {
+  // Meeus, example 49.a: new moon of 1977-02-18 at JDE 2443192.65118
+  assert!((next_new_moon_jd(2443190.0).unwrap() - 2443192.65118).abs() < 0.001);
+  // full moon of 2024-01-25 17:54 UTC and the new moon that started Brown lunation 1250 on 2024-01-11 11:57 UTC
+  let full_moon = next_full_moon_jd(datetime_to_julian_day("2024-01-20").unwrap()).unwrap();
+  assert!((full_moon - datetime_to_julian_day("2024-01-25 17:54").unwrap()).abs() < 5.0 / 1440.0);
+  assert!((moon_phase_fraction(full_moon) - 0.5).abs() < 0.005);
+  assert!(moon_illumination(full_moon) > 0.999);
+  assert_eq!(lunation_number(datetime_to_julian_day("2024-01-11 11:00").unwrap()), Some(1249));
+  assert_eq!(lunation_number(datetime_to_julian_day("2024-01-11 13:00").unwrap()), Some(1250));
+  assert!(moon_age_days(datetime_to_julian_day("2024-01-12 12:00").unwrap()) < 1.5);
+  // the searches stop at the supported range instead of overflowing the lunation index
+  for jd in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e300, -1e300] {
+    assert_eq!(next_new_moon_jd(jd), None);
+    assert_eq!(next_full_moon_jd(jd), None);
+    assert_eq!(lunation_number(jd), None);
+  }
+  assert!(next_new_moon_jd(MIN_SUPPORTED_JD).is_some());
+  assert!(next_full_moon_jd(MAX_SUPPORTED_JD - SYNODIC_MONTH_DAYS).is_some());
+}
+
+#[test]
+fn test_equinoxes_and_solstices() {
+  // Meeus, example 27.a: June solstice of 1962 at JDE 2437837.39245
+  assert!((june_solstice_jd(1962) - 2437837.39245).abs() < 0.0001);
+  // 2024 March equinox at 03:06 UTC and December solstice at 09:20 UTC (about 69 seconds earlier than TT)
+  assert!((march_equinox_jd(2024) - datetime_to_julian_day("2024-03-20 03:07").unwrap()).abs() < 2.0 / 1440.0);
+  assert!((december_solstice_jd(2024) - datetime_to_julian_day("2024-12-21 09:21").unwrap()).abs() < 2.0 / 1440.0);
+  assert!(september_equinox_jd(500) > june_solstice_jd(500));
+}
+
+#[test]
+fn test_heliocentric_and_barycentric_corrections() {
+  // at the March equinox the Sun lies towards RA 0º, Dec 0º at about 0.996 AU
+  let jd = datetime_to_julian_day("2023-03-20 21:24").unwrap();
+  let towards_sun_secs = (hjd(jd, 0.0, 0.0) - jd) * 86400.0;
+  let away_from_sun_secs = (hjd(jd, 180.0, 0.0) - jd) * 86400.0;
+  assert!((towards_sun_secs + 497.0).abs() < 1.0);
+  assert!((away_from_sun_secs - 497.0).abs() < 1.0);
+  // the ecliptic pole is perpendicular to the Earth's orbit
+  assert!(((hjd(jd, 270.0, 66.56) - jd) * 86400.0).abs() < 1.0);
+  // BJD_TDB adds 69.184 seconds for TT - UTC and differs from HJD by a few seconds for the barycentre offset
+  let tdb_offset_secs = (bjd_tdb(jd, 180.0, 0.0) - hjd(jd, 180.0, 0.0)) * 86400.0;
+  assert!((tdb_offset_secs - 69.184).abs() < 5.0);
+}
+
+#[test]
+fn test_easter_dates() {
+  assert_eq!(easter_jd(2024), datetime_to_julian_day("2024-03-31").unwrap());
+  assert_eq!(easter_date(2025), NaiveDate::from_ymd_opt(2025, 4, 20));
+  assert_eq!(easter_date(1818), NaiveDate::from_ymd_opt(1818, 3, 22));
+  assert_eq!(easter_date(2038), NaiveDate::from_ymd_opt(2038, 4, 25));
+  // the computus floors rather than truncates for years before 1 AD (astronomical year numbering)
+  assert_eq!(easter_date(-1), NaiveDate::from_ymd_opt(-1, 4, 18));
+  for year in [-1, -101, -1001, -4713] {
+    assert_eq!(easter_date(year).unwrap().format("%a").to_string(), "Sun");
+  }
+  // Orthodox Easter falls on the same day in 2025 and five weeks later in 2024
+  assert_eq!(orthodox_easter_date(2025), easter_date(2025));
+  assert_eq!(orthodox_easter_date(2024), NaiveDate::from_ymd_opt(2024, 5, 5));
+  assert_eq!(orthodox_easter_jd(2024), easter_jd(2024) + 35.0);
+}
+
+#[test]
+fn test_computus_quantities() {
+  // the Gregorian epact is the age of the moon on 1 January: new moons fell on 2000-01-06 and 2024-01-11
+  assert_eq!(epact(2000), 24);
+  assert_eq!(epact(2024), 19);
+  assert_eq!(golden_number(2000), 6);
+  assert_eq!(dominical_letter(2000), "BA");
+  assert_eq!(dominical_letter(2023), "A");
+  assert_eq!(dominical_letter(1900), "G");
+  let quantities = computus_at(datetime_to_julian_day("2024-09-04").unwrap()).unwrap();
+  assert_eq!(quantities.indiction, 2);
+  assert_eq!(quantities.solar_cycle, 17);
+  assert_eq!(computus_at(1e300), None);
+  assert_eq!(computus_at(f64::NAN), None);
+}
+
+#[test]
+fn test_julian_calendar_conversions() {
+  // the Julian calendar was 10 days behind in 1582 and 13 days behind from 1900 to 2099
+  let reform_jd = datetime_to_julian_day("1582-10-15").unwrap();
+  assert_eq!(julian_day_to_julian_calendar(reform_jd), Some((1582, 10, 5)));
+  assert_eq!(julian_calendar_to_julian_day(2024, 8, 22), datetime_to_julian_day("2024-09-04").ok());
+  // year 0 (1 BC) and 1900 are Julian leap years
+  assert!(julian_calendar_to_julian_day(0, 2, 29).is_some());
+  assert!(julian_calendar_to_julian_day(1900, 2, 29).is_some());
+  assert_eq!(julian_calendar_to_julian_day(1901, 2, 29), None);
+  // Julian Day 0 is 1 January 4713 BC in the Julian calendar
+  assert_eq!(julian_day_to_julian_calendar(0.0), Some((-4712, 1, 1)));
+  let date = NaiveDate::from_julian_calendar(1752, 9, 2).unwrap();
+  assert_eq!(date, NaiveDate::from_ymd_opt(1752, 9, 13).unwrap());
+  assert_eq!(date.and_hms_opt(18, 0, 0).unwrap().to_julian_calendar(), (1752, 9, 2));
+}
+
+#[test]
+fn test_gregorian_reform_switchover() {
+  let britain = GregorianReform::BRITAIN;
+  assert_eq!(britain.gap_days(), 11);
+  assert_eq!(GregorianReform::ROME.gap_days(), 10);
+  let last_julian_jd = britain.parse("1752-09-02").unwrap();
+  assert_eq!(britain.parse("1752-09-14"), Ok(last_julian_jd + 1.0));
+  assert_eq!(britain.parse("1752-09-05"), Err(HybridDateError::SkippedByReform));
+  assert_eq!(britain.parse("1752-09-31"), Err(HybridDateError::InvalidDate));
+  assert_eq!(britain.format(last_julian_jd + 1.25), Some("1752-09-14 06:00:00".to_string()));
+  // Gregorian dates after the reform match chrono
+  let jd = datetime_to_julian_day("1969-07-20 20:17:40").unwrap();
+  assert_eq!(britain.format(jd), Some("1969-07-20 20:17:40".to_string()));
+  assert_eq!(britain.parse("1969-07-20 20:17:40"), Ok(jd));
+  // Julius Caesar was assassinated on the Ides of March 44 BC (astronomical year -43)
+  let ides_jd = GregorianReform::ROME.parse("-0043-03-15").unwrap();
+  assert_eq!(julian_day_to_julian_calendar(ides_jd), Some((-43, 3, 15)));
+  assert_eq!(GregorianReform::ROME.format(ides_jd), Some("-0043-03-15 00:00:00".to_string()));
+  let france = GregorianReform::new(1582, 12, 20).unwrap();
+  assert_eq!(france.julian_day_to_date(france.reform_jd() - 1.0), Some((1582, 12, 9)));
+}
+
+#[test]
+fn test_dual_dating() {
+  let britain = GregorianReform::BRITAIN;
+  assert_eq!(britain.format_dual(britain.parse("1732-02-11").unwrap()), Some("11/22 February 1731/32".to_string()));
+  assert_eq!(britain.format_dual(britain.parse("1700-02-25").unwrap()), Some("25 February/7 March 1699/1700".to_string()));
+  assert_eq!(britain.format_dual(britain.parse("1745-07-01").unwrap()), Some("1/12 July 1745".to_string()));
+  assert_eq!(britain.format_dual(britain.parse("1740-12-25").unwrap()), Some("25 December 1740/5 January 1741".to_string()));
+  assert_eq!(britain.format_dual(britain.parse("1752-09-14").unwrap()), Some("14 September 1752".to_string()));
+}
+
+#[test]
+fn test_tabular_islamic_calendar() {
+  // 1 Muharram 1 AH was Friday 16 July 622 in the Julian calendar
+  assert_eq!(islamic_to_julian_day(1, 1, 1), julian_calendar_to_julian_day(622, 7, 16));
+  let astronomical = TabularIslamic::new(IslamicLeapPattern::TypeII, IslamicEpoch::Astronomical);
+  assert_eq!(astronomical.date_to_julian_day(1, 1, 1), julian_calendar_to_julian_day(622, 7, 15));
+  // the Type II scheme matches the closed formula epoch + 354 (y - 1) + floor((3 + 11 y) / 30)
+  for year in [-50, 1, 2, 29, 30, 31, 1000, 1445, 1446, 1500] {
+    let expected = 1948440 + 354 * (year as i64 - 1) + (3 + 11 * year as i64).div_euclid(30);
+    assert_eq!(islamic_to_julian_day(year, 1, 1), Some(expected as f64 - 0.5));
+  }
+  let jd = datetime_to_julian_day("2024-03-11").unwrap();
+  let (year, month, day) = julian_day_to_islamic(jd).unwrap();
+  assert_eq!(islamic_to_julian_day(year, month, day), Some(jd));
+  assert_eq!((year, month), (1445, 9));
+  // Dhu al-Hijjah has 30 days in leap years only, which differ between schemes
+  assert!(islamic_to_julian_day(1446, 12, 30).is_none());
+  assert!(TabularIslamic::default().is_leap_year(1445));
+  assert!(!TabularIslamic::new(IslamicLeapPattern::TypeI, IslamicEpoch::Civil).is_leap_year(1426));
+  assert!(TabularIslamic::default().is_leap_year(1426));
+}
+
+#[test]
+fn test_umm_al_qura_calendar() {
+  // 1 Muharram 1300 AH was 1882-11-12 and 1 Muharram 1446 AH was 2024-07-07, a day before the tabular calendar
+  assert_eq!(umm_al_qura_to_julian_day(1300, 1, 1), datetime_to_julian_day("1882-11-12").ok());
+  assert_eq!(umm_al_qura_to_julian_day(1446, 1, 1), datetime_to_julian_day("2024-07-07").ok());
+  assert_eq!(islamic_to_julian_day(1446, 1, 1), datetime_to_julian_day("2024-07-08").ok());
+  let jd = datetime_to_julian_day("2174-11-25").unwrap();
+  assert_eq!(umm_al_qura_to_julian_day(1600, 12, UmmAlQura.days_in_month(1600, 12).unwrap()), Some(jd));
+  assert_eq!(julian_day_to_umm_al_qura(jd + 1.0), None);
+  assert_eq!(julian_day_to_umm_al_qura(datetime_to_julian_day("1882-11-11").unwrap()), None);
+  assert_eq!(umm_al_qura_to_julian_day(1601, 1, 1), None);
+  let eid_jd = datetime_to_julian_day("2024-04-10 18:00").unwrap();
+  assert_eq!(julian_day_to_umm_al_qura(eid_jd), Some((1445, 10, 1)));
+}
+
+#[test]
+fn test_persian_calendar() {
+  // Nowruz 1403 AP fell on 20 March 2024 and 1403 was a leap year with 30 days in Esfand
+  assert_eq!(persian_to_julian_day(1403, 1, 1), datetime_to_julian_day("2024-03-20").ok());
+  assert_eq!(persian_to_julian_day(1403, 12, 30), datetime_to_julian_day("2025-03-20").ok());
+  assert_eq!(persian_to_julian_day(1404, 12, 30), None);
+  assert_eq!(julian_day_to_persian(datetime_to_julian_day("2024-09-04 18:00").unwrap()), Some((1403, 6, 14)));
+  assert_eq!(julian_day_to_persian(datetime_to_julian_day("1979-02-11").unwrap()), Some((1357, 11, 22)));
+  // both rules agree in the modern era
+  let astronomical = PersianCalendar::new(PersianLeapRule::Astronomical);
+  for year in 1340..1420 {
+    assert_eq!(astronomical.date_to_julian_day(year, 1, 1), persian_to_julian_day(year, 1, 1));
+  }
+}
+
+#[test]
+fn test_coptic_calendar() {
+  // 1 Thout 1 AM was 29 August 284 in the Julian calendar
+  assert_eq!(coptic_to_julian_day(1, 1, 1), julian_calendar_to_julian_day(284, 8, 29));
+  // Coptic new year 1741 fell on 11 September 2024 after a leap year with 6 epagomenal days
+  assert!(is_coptic_leap_year(1739));
+  assert_eq!(coptic_to_julian_day(1739, 13, 6), datetime_to_julian_day("2023-09-11").ok());
+  assert_eq!(coptic_to_julian_day(1741, 1, 1), datetime_to_julian_day("2024-09-11").ok());
+  assert_eq!(coptic_to_julian_day(1740, 13, 6), None);
+  assert_eq!(julian_day_to_coptic(datetime_to_julian_day("2024-09-10 23:00").unwrap()), Some((1740, 13, 5)));
+}
+
+#[test]
+fn test_ethiopic_calendar() {
+  // the Ethiopian calendar runs 276 years behind the Coptic calendar on the same days
+  let jd = datetime_to_julian_day("2024-09-04 18:00").unwrap();
+  let (coptic_year, coptic_month, coptic_day) = julian_day_to_coptic(jd).unwrap();
+  assert_eq!(julian_day_to_ethiopic(jd, EthiopicEra::AmeteMihret), Some((coptic_year + 276, coptic_month, coptic_day)));
+  assert_eq!(julian_day_to_ethiopic(jd, EthiopicEra::AmeteMihret), Some((2016, 12, 29)));
+  assert_eq!(ethiopic_to_julian_day(1, 1, 1, EthiopicEra::AmeteMihret), julian_calendar_to_julian_day(8, 8, 29));
+  assert_eq!(ethiopic_to_julian_day(5501, 1, 1, EthiopicEra::AmeteAlem), ethiopic_to_julian_day(1, 1, 1, EthiopicEra::AmeteMihret));
+  // 2015 was a leap year with 6 days in Pagumē
+  assert!(ethiopic_to_julian_day(2015, 13, 6, EthiopicEra::AmeteMihret).is_some());
+  assert!(ethiopic_to_julian_day(2016, 13, 6, EthiopicEra::AmeteMihret).is_none());
+  // converting the earliest Amete Alem years to Amete Mihret would overflow
+  assert_eq!(ethiopic_to_julian_day(i32::MIN, 1, 1, EthiopicEra::AmeteAlem), None);
+}
+
+#[test]
+fn test_mayan_long_count() {
+  // the end of the 13th baktun on 2012-12-21 was 13.0.0.0.0 4 Ajaw 3 K'ank'in
+  let jd = datetime_to_julian_day("2012-12-21 12:00").unwrap();
+  assert_eq!(julian_day_to_long_count(jd, GMT_CORRELATION).unwrap().to_string(), "13.0.0.0.0");
+  assert_eq!(julian_day_to_tzolkin(jd, GMT_CORRELATION).unwrap().to_string(), "4 Ajaw");
+  assert_eq!(julian_day_to_haab(jd, GMT_CORRELATION).unwrap().to_string(), "3 K'ank'in");
+  // the creation date was 4 Ajaw 8 Kumk'u on 6 September 3114 BC in the Julian calendar
+  let creation: LongCount = "0.0.0.0.0".parse().unwrap();
+  let creation_jd = long_count_to_julian_day(&creation, GMT_CORRELATION);
+  assert_eq!(julian_day_to_haab(creation_jd, GMT_CORRELATION).unwrap().to_string(), "8 Kumk'u");
+  assert_eq!(julian_calendar_to_julian_day(-3113, 9, 6), Some(creation_jd));
+  // alternative correlations shift the Long Count by whole days
+  assert_eq!(julian_day_to_long_count(jd, GMT_CORRELATION + 2).unwrap().to_string(), "12.19.19.17.18");
+  assert_eq!("9.12.11.18.0".parse::<LongCount>(), Err(JulianDayError::InvalidFormat));
+  assert_eq!("9.12.11".parse::<LongCount>(), Err(JulianDayError::InvalidFormat));
+  // calendar round positions are validated on construction
+  assert_eq!(Tzolkin::new(4, 20), julian_day_to_tzolkin(jd, GMT_CORRELATION));
+  assert_eq!(Tzolkin::new(1, 0), None);
+  assert_eq!(Tzolkin::new(14, 1), None);
+  assert_eq!(Haab::new(3, 14), julian_day_to_haab(jd, GMT_CORRELATION));
+  assert_eq!(Haab::new(0, 0), None);
+  assert_eq!(Haab::new(5, 19), None);
+  assert_eq!(julian_day_to_long_count(1e300, GMT_CORRELATION), None);
+}
+
+#[test]
+fn test_bahai_calendar() {
+  // Naw-Rúz was fixed on 21 March until 171 BE and follows the equinox in Tehran from 172 BE (2015)
+  assert_eq!(bahai_to_julian_day(1, 1, 1), datetime_to_julian_day("1844-03-21").ok());
+  assert_eq!(bahai_to_julian_day(172, 1, 1), datetime_to_julian_day("2015-03-21").ok());
+  assert_eq!(bahai_to_julian_day(174, 1, 1), datetime_to_julian_day("2017-03-20").ok());
+  assert_eq!(bahai_to_julian_day(183, 1, 1), datetime_to_julian_day("2026-03-21").ok());
+  // Ayyám-i-Há precedes the month of fasting (ʿAlá), which begins on 1 or 2 March
+  assert_eq!(bahai_ayyam_i_ha_days(180), 4);
+  assert_eq!(bahai_to_julian_day(180, 19, 1), datetime_to_julian_day("2024-03-01").ok());
+  assert_eq!(bahai_to_julian_day(180, AYYAM_I_HA, 5), None);
+  assert_eq!(julian_day_to_bahai(datetime_to_julian_day("2024-02-27 18:00").unwrap()), Some((180, AYYAM_I_HA, 2)));
+  assert_eq!(julian_day_to_bahai(datetime_to_julian_day("2024-03-19").unwrap()), Some((180, 19, 19)));
+}
+
+#[test]
+fn test_chinese_calendar() {
+  assert_eq!(chinese_new_year_jd(1900), datetime_to_julian_day("1900-01-31").ok());
+  assert_eq!(chinese_new_year_jd(2000), datetime_to_julian_day("2000-02-05").ok());
+  assert_eq!(chinese_new_year_jd(2025), datetime_to_julian_day("2025-01-29").ok());
+  assert_eq!(chinese_new_year_jd(2101), None);
+  // 2020 had a leap 4th month and 2033 a leap 11th month
+  assert_eq!(chinese_to_julian_day(2020, 4, true, 1), datetime_to_julian_day("2020-05-23").ok());
+  assert_eq!(chinese_to_julian_day(2020, 5, true, 1), None);
+  let date = julian_day_to_chinese(datetime_to_julian_day("2033-12-22").unwrap()).unwrap();
+  assert_eq!((date.year, date.month, date.leap_month, date.day), (2033, 11, true, 1));
+  let date = julian_day_to_chinese(datetime_to_julian_day("2024-09-17 20:00").unwrap()).unwrap();
+  assert_eq!(date.to_string(), "Jia-Chen 8-15");
+  assert_eq!(date.cycle_year(), 41);
+}
+
+#[test]
+fn test_holocene_years() {
+  assert_eq!(YearNumbering::Holocene.from_common_era(2024), 12024);
+  assert_eq!(YearNumbering::Holocene.to_common_era(1), -9999);
+  let jd = datetime_to_julian_day("2024-09-04 18:30").unwrap();
+  let he_string = julian_day_to_era_string(jd, YearNumbering::Holocene).unwrap();
+  assert_eq!(he_string, "12024-09-04 18:30:00");
+  assert_eq!(era_string_to_julian_day(&he_string, YearNumbering::Holocene), Some(jd));
+  assert_eq!(era_string_to_julian_day("12023-02-29", YearNumbering::Holocene), None);
+  assert_eq!(julian_day_to_era_string(1e300, YearNumbering::Holocene), None);
+  assert_eq!(julian_day_to_era_string(f64::NAN, YearNumbering::Holocene), None);
+}
+
+#[test]
+fn test_buddhist_era_years() {
+  assert_eq!(YearNumbering::BuddhistEra.from_common_era(2024), 2567);
+  let jd = datetime_to_julian_day("2024-02-29 09:15").unwrap();
+  assert_eq!(julian_day_to_era_string(jd, YearNumbering::BuddhistEra), Some("2567-02-29 09:15:00".to_string()));
+  assert_eq!(era_string_to_julian_day("2567-02-29 09:15", YearNumbering::BuddhistEra), Some(jd));
+  // 2566 BE (2023 CE) was not a leap year
+  assert_eq!(era_string_to_julian_day("2566-02-29", YearNumbering::BuddhistEra), None);
+  // Buddhist Era dates are recognised by the fuzzy parser with a พ.ศ. or BE label
+  assert_eq!(datetime_to_julian_day("2567-02-29 09:15 BE").ok(), Some(jd));
+  assert_eq!(datetime_to_julian_day("พ.ศ. 2567-02-29 09:15").ok(), Some(jd));
+  assert_eq!(datetime_to_julian_day("พ.ศ.2567-02-29T09:15").ok(), Some(jd));
+  assert_eq!(datetime_to_julian_day("29 Feb 2567 B.E. 09:15").ok(), Some(jd));
+  let dmy = FuzzyParseOptions::default().with_date_order(DateOrder::DMY);
+  assert_eq!(fuzzy_string_to_julian_day_with("29/2/2567 BE 09:15", &dmy), Ok(jd));
+  // a prefixed พ.ศ. slash date keeps the parser options
+  assert_eq!(fuzzy_string_to_julian_day_with("พ.ศ. 04/09/2567", &dmy), Ok(2460557.5));
+  let mdy = FuzzyParseOptions::default().with_date_order(DateOrder::MDY);
+  assert_eq!(fuzzy_string_to_julian_day_with("พ.ศ. 04/09/2567", &mdy), Ok(2460409.5));
+  assert!(datetime_to_julian_day("2566-02-29 BE").is_err());
+  assert!(datetime_to_julian_day("2024-02-29 Berlin").is_err());
+  // a labelled Buddhist Era year is not offset a second time
+  assert_eq!(era_string_to_julian_day("พ.ศ. 2567-09-04", YearNumbering::BuddhistEra), Some(2460557.5));
+  assert_eq!(era_string_to_julian_day("2567-09-04 BE", YearNumbering::BuddhistEra), Some(2460557.5));
+}
+
+#[test]
+fn test_japanese_eras() {
+  // Heisei began on 8 January 1989, the day after the last day of Shōwa 64
+  let jd = datetime_to_julian_day("1989-01-07").unwrap();
+  assert_eq!(julian_day_to_japanese_era_string(jd), Some("昭和64年1月7日".to_string()));
+  assert_eq!(julian_day_to_japanese_era_string(jd + 1.0), Some("平成元年1月8日".to_string()));
+  assert_eq!(japanese_era_string_to_julian_day("平成1年1月8日"), Some(jd + 1.0));
+  assert_eq!(japanese_era_string_to_julian_day("平成元年1月7日"), None);
+  // dates after an era ended are rejected
+  assert_eq!(japanese_era_string_to_julian_day("昭和64年1月7日"), Some(jd));
+  assert_eq!(japanese_era_string_to_julian_day("昭和64年1月8日"), None);
+  assert_eq!(japanese_era_string_to_julian_day("昭和100年1月1日"), None);
+  assert_eq!(japanese_era_string_to_julian_day("平成31年4月30日"), datetime_to_julian_day("2019-04-30").ok());
+  assert_eq!(japanese_era_string_to_julian_day("平成31年5月1日"), None);
+  // the latest era has no end
+  assert_eq!(japanese_era_string_to_julian_day("令和100年1月1日"), datetime_to_julian_day("2118-01-01").ok());
+  assert_eq!(japanese_era_string_to_julian_day("令和6年2月30日"), None);
+  assert_eq!(julian_day_to_japanese_era_string(datetime_to_julian_day("1868-01-01").unwrap()), None);
+  // a custom era appended to the table
+  let table = JapaneseEraTable::default().with_era(JapaneseEra::new("未来", "Mirai", 2100, 1, 1));
+  assert_eq!(table.format(datetime_to_julian_day("2100-06-01").unwrap()), Some("未来元年6月1日".to_string()));
+  assert_eq!(table.era_year(datetime_to_julian_day("2099-06-01").unwrap()).map(|(era, year)| (era.romaji, year)), Some(("Reiwa", 81)));
+  // era years that overflow or run past the next era are rejected
+  assert_eq!(japanese_era_string_to_julian_day("令和9223372036854775807年1月1日"), None);
+  assert_eq!(japanese_era_string_to_julian_day("令和92233720368547758年1月1日"), None);
+  assert_eq!(table.parse("令和2147483647年1月1日"), None);
+  assert_eq!(table.era_year(1e300), None);
+  assert_eq!(table.format(-1e300), None);
+}
+
+#[test]
+fn test_minguo_years() {
+  let jd = datetime_to_julian_day("2024-09-04").unwrap();
+  assert_eq!(julian_day_to_era_string(jd, YearNumbering::Minguo), Some("0113-09-04 00:00:00".to_string()));
+  assert_eq!(era_string_to_julian_day("113-09-04", YearNumbering::Minguo), Some(jd));
+  // Minguo dates are recognised by the fuzzy parser
+  assert_eq!(datetime_to_julian_day("民國113年9月4日").ok(), Some(jd));
+  assert_eq!(datetime_to_julian_day("民国113年9月4日 12:00").ok(), Some(jd + 0.5));
+  assert_eq!(datetime_to_julian_day("ROC 113/09/04 06:00").ok(), Some(jd + 0.25));
+  assert!(datetime_to_julian_day("民國113年2月30日").is_err());
+  // a labelled Minguo year is not offset a second time
+  assert_eq!(era_string_to_julian_day("民國113年9月4日", YearNumbering::Minguo), Some(jd));
+  assert_eq!(era_string_to_julian_day("ROC 113/09/04 06:00", YearNumbering::Minguo), Some(jd + 0.25));
+  assert_eq!(julian_day_to_era_string(1e300, YearNumbering::Minguo), None);
+}
+
+fn assert_calendar_round_trip(calendar: &impl Calendar, jd: f64) {
+  let (year, month, day) = calendar.julian_day_to_date(jd).unwrap();
+  assert_eq!(calendar.date_to_julian_day(year, month, day), Some(jd));
+  assert!(day <= calendar.days_in_month(year, month).unwrap());
+  let formatted = calendar.format_julian_day(jd + 0.25).unwrap();
+  assert_eq!(calendar.parse_julian_day(&formatted), Some(jd + 0.25));
+}
+
+#[test]
+fn test_calendar_trait() {
+  let jd = datetime_to_julian_day("2024-09-04").unwrap();
+  assert_calendar_round_trip(&ProlepticGregorian, jd);
+  assert_calendar_round_trip(&ProlepticJulian, jd);
+  assert_calendar_round_trip(&GregorianReform::BRITAIN, jd);
+  assert_calendar_round_trip(&TabularIslamic::default(), jd);
+  assert_calendar_round_trip(&UmmAlQura, jd);
+  assert_calendar_round_trip(&PersianCalendar::default(), jd);
+  assert_calendar_round_trip(&CopticCalendar, jd);
+  assert_calendar_round_trip(&EthiopicCalendar::default(), jd);
+  assert_calendar_round_trip(&BahaiCalendar, jd);
+  assert_calendar_round_trip(&ChineseCalendar, jd);
+  assert_eq!(ProlepticGregorian.days_in_year(2024), Some(366));
+  assert_eq!(GregorianReform::BRITAIN.days_in_month(1752, 9), Some(19));
+  assert_eq!(Calendar::days_in_year(&GregorianReform::BRITAIN, 1752), Some(355));
+  assert_eq!(BahaiCalendar.days_in_year(180), Some(365));
+  // Ayyám-i-Há is the 19th month by position and ʿAlá the 20th
+  assert_eq!(BahaiCalendar.date_to_julian_day(181, 19, 1), bahai_to_julian_day(181, AYYAM_I_HA, 1));
+  assert_eq!(BahaiCalendar.julian_day_to_date(bahai_to_julian_day(181, 19, 19).unwrap()), Some((181, 20, 19)));
+  // 2023 had a leap 2nd month, the 3rd month by position
+  assert_eq!(ChineseCalendar.months_in_year(2023), 13);
+  assert_eq!(ChineseCalendar.date_to_julian_day(2023, 3, 1), chinese_to_julian_day(2023, 2, true, 1));
+  assert_eq!(convert_calendar_date(&PersianCalendar::default(), &ProlepticGregorian, 1403, 1, 1), Some((2024, 3, 20)));
+  // finite Julian Days beyond the supported range are rejected instead of wrapping the year
+  for jd in [1e18, -1e18, MAX_SUPPORTED_JD + 1.0] {
+    assert_eq!(ProlepticGregorian.julian_day_to_date(jd), None);
+    assert_eq!(ProlepticJulian.julian_day_to_date(jd), None);
+    assert_eq!(GregorianReform::BRITAIN.julian_day_to_date(jd), None);
+    assert_eq!(TabularIslamic::default().julian_day_to_date(jd), None);
+    assert_eq!(PersianCalendar::default().julian_day_to_date(jd), None);
+    assert_eq!(CopticCalendar.julian_day_to_date(jd), None);
+    assert_eq!(EthiopicCalendar::default().julian_day_to_date(jd), None);
+    assert_eq!(BahaiCalendar.julian_day_to_date(jd), None);
+  }
+  assert!(ProlepticGregorian.julian_day_to_date(MAX_SUPPORTED_JD).is_some());
+  let amete_alem = EthiopicCalendar { era: EthiopicEra::AmeteAlem };
+  assert_eq!(amete_alem.days_in_month(i32::MIN, 13), None);
+  assert_eq!(amete_alem.is_leap_year(i32::MIN + 3), amete_alem.is_leap_year(7515));
+}
+
+#[test]
+fn test_calendar_month_lengths_sum_to_year_length() {
+  fn assert_month_lengths(calendar: &impl Calendar, year: i32) {
+    let first_day = calendar.date_to_julian_day(year, 1, 1).unwrap();
+    let next_first_day = calendar.date_to_julian_day(year + 1, 1, 1).unwrap();
+    let month_days: u32 = (1..=calendar.months_in_year(year)).map(|month| calendar.days_in_month(year, month).unwrap()).sum();
+    assert_eq!(month_days as f64, next_first_day - first_day);
+    assert_eq!(calendar.days_in_year(year), Some(month_days));
+  }
+  for year in [180, 181, 182] {
+    assert_month_lengths(&BahaiCalendar, year);
+  }
+  assert_month_lengths(&ProlepticGregorian, 2024);
+  assert_month_lengths(&TabularIslamic::default(), 1446);
+  assert_month_lengths(&PersianCalendar::default(), 1403);
+  assert_month_lengths(&CopticCalendar, 1739);
+  assert_month_lengths(&ChineseCalendar, 2023);
+}
+
+#[test]
+fn test_iso_week_dates() {
+  // 1 January 2021 fell in the last week of 2020, which had 53 weeks
+  let jd = datetime_to_julian_day("2021-01-01 15:00").unwrap();
+  assert_eq!(julian_day_to_iso_week_date(jd), Some((2020, 53, 5)));
+  assert_eq!(iso_weeks_in_year(2020), 53);
+  assert_eq!(iso_weeks_in_year(2021), 52);
+  // 29 December 2025 is the Monday of week 1 of 2026
+  assert_eq!(format_iso_week_date(datetime_to_julian_day("2025-12-29").unwrap()), Some("2026-W01-1".to_string()));
+  assert_eq!(parse_iso_week_date("2026-W01-1"), datetime_to_julian_day("2025-12-29").ok());
+  assert_eq!(parse_iso_week_date("2021-W53-1"), None);
+  assert_eq!(parse_iso_week_date("2022-W35-8"), None);
+  assert_eq!(iso_week_date_to_julian_day(2020, 53, 5).and_then(julian_day_to_iso_week_date), Some((2020, 53, 5)));
+  assert_eq!(julian_day_to_iso_week_date(1e300), None);
+  assert_eq!(julian_day_to_iso_week_date(-1e300), None);
+}
+
+#[test]
+fn test_ordinal_dates() {
+  let jd = datetime_to_julian_day("2024-12-31 18:00").unwrap();
+  assert_eq!(julian_day_to_ordinal_date(jd), Some((2024, 366)));
+  assert_eq!(julian_day_to_fractional_ordinal(jd), Some((2024, 366.75)));
+  assert_eq!(fractional_ordinal_to_julian_day(2024, 366.75), Some(jd));
+  assert_eq!(ordinal_date_to_julian_day(2023, 366), None);
+  assert_eq!(format_ordinal_date(jd), Some("2024-366".to_string()));
+  // ordinal dates are accepted by the fuzzy parser
+  assert_eq!(datetime_to_julian_day("2022-247").ok(), datetime_to_julian_day("2022-09-04").ok());
+  assert_eq!(datetime_to_julian_day("2024-366T18:00").ok(), Some(jd));
+  assert!(datetime_to_julian_day("2023-366").is_err());
+  assert_eq!(datetime_to_julian_day("2022-09").ok(), datetime_to_julian_day("2022-09-01").ok());
+  assert_eq!(julian_day_to_ordinal_date(1e300), None);
+  assert_eq!(julian_day_to_ordinal_date(-1e300), None);
+}
+
+#[test]
+fn test_ordinal_dates_in_calendar_parsers() {
+  // days of year follow the year lengths of the calendar being parsed
+  assert_eq!(era_string_to_julian_day("2567-060", YearNumbering::BuddhistEra), Some(2460369.5));
+  assert_eq!(era_string_to_julian_day("2567-366", YearNumbering::BuddhistEra), Some(2460675.5));
+  assert_eq!(ProlepticJulian.parse_julian_day("1900-060"), ProlepticJulian.date_to_julian_day(1900, 2, 29));
+  let persian = PersianCalendar::default();
+  assert_eq!(persian.parse_julian_day("1403-070 12:00"), persian.date_to_julian_day(1403, 3, 8).map(|jd| jd + 0.5));
+  assert_eq!(GregorianReform::ROME.parse("1582-288"), GregorianReform::ROME.date_to_julian_day(1582, 10, 25));
+  assert_eq!(ProlepticJulian.parse_julian_day("1901-366"), None);
+  assert_eq!(ordinal_date_to_julian_day(2024, 60), Some(2460369.5));
+}
+
+#[test]
+fn test_julian_day_to_civil() {
+  let civil = julian_day_to_civil(2460558.25).unwrap();
+  assert_eq!((civil.year, civil.month, civil.day, civil.hour, civil.minute, civil.second, civil.nanosecond), (2024, 9, 4, 18, 0, 0, 0));
+  // years far beyond chrono's range
+  let civil = julian_day_to_civil(-400_000_000.5).unwrap();
+  assert_eq!((civil.year, civil.month, civil.day, civil.hour), (-1099875, 2, 4, 0));
+  assert!(julian_day_to_datetime(-400_000_000.5).is_err());
+  // sub-second precision is limited to tens of microseconds in the current era
+  let civil = julian_day_to_civil(2460558.25 + 0.5 / 86400.0).unwrap();
+  assert_eq!(civil.second, 0);
+  assert!(civil.nanosecond.abs_diff(500_000_000) < 50_000);
+  // finite Julian Days beyond the extended range are rejected rather than overflowing the day arithmetic
+  assert!(julian_day_to_civil(MAX_EXTENDED_JD).is_some());
+  assert_eq!(julian_day_to_civil(3e18), None);
+  assert_eq!(julian_day_to_civil(1e300), None);
+  assert_eq!(julian_day_to_civil(-1e300), None);
+  let wide = JdBounds::new(-1e300, 1e300).unwrap();
+  assert_eq!(wide.to_civil(1e300), Err(JulianDayError::OutOfRange));
+}
+
+#[test]
+fn test_civil_to_julian_day() {
+  let jd = civil_to_julian_day(2024, 9, 4, 18, 15, 30, 0).unwrap();
+  assert_eq!(Some(jd), datetime_to_julian_day("2024-09-04 18:15:30").ok());
+  let civil = julian_day_to_civil(jd).unwrap();
+  assert_eq!(civil.to_julian_day(), Some(jd));
+  // years beyond chrono's range
+  assert_eq!(civil_to_julian_day(-1099875, 2, 4, 0, 0, 0, 0), Some(-400_000_000.5));
+  assert_eq!(civil_to_julian_day(2023, 2, 29, 0, 0, 0, 0), None);
+  assert_eq!(civil_to_julian_day(2024, 9, 4, 24, 0, 0, 0), None);
+  assert_eq!(civil_to_julian_day(2024, 9, 4, 0, 0, 0, 1_000_000_000), None);
+  // dates at the limits of the extended range round trip, while later or earlier years are rejected without overflowing
+  for limit_jd in [MIN_EXTENDED_JD, MAX_EXTENDED_JD] {
+    let civil = julian_day_to_civil(limit_jd).unwrap();
+    assert_eq!(civil.to_julian_day(), Some(limit_jd));
+    let beyond = civil.year + civil.year.signum();
+    assert_eq!(civil_to_julian_day(beyond, civil.month, civil.day, 0, 0, 0, 0), None);
+  }
+  assert_eq!(civil_to_julian_day(i64::MAX, 1, 1, 0, 0, 0, 0), None);
+  assert_eq!(civil_to_julian_day(i64::MIN, 12, 31, 0, 0, 0, 0), None);
+}
+
+#[test]
+fn test_historical_year_numbering() {
+  // the Ides of March 44 BC (proleptic Gregorian) in historical and astronomical numbering
+  let ides = datetime_to_julian_day("-0043-03-15").unwrap();
+  assert_eq!(datetime_to_julian_day("0044-03-15 BC").ok(), Some(ides));
+  assert_eq!(datetime_to_julian_day("44 BC-03-15").ok(), Some(ides));
+  assert_eq!(datetime_to_julian_day("44 b.c.").ok(), datetime_to_julian_day("-0043").ok());
+  // Julian Day 0 fell on 24 November 4714 BC in the proleptic Gregorian calendar
+  assert_eq!(datetime_to_julian_day("4714 BCE-11-24 12:00").ok(), Some(0.0));
+  assert_eq!(datetime_to_julian_day("AD 1066-10-14").ok(), datetime_to_julian_day("1066-10-14").ok());
+  assert!(datetime_to_julian_day("0 BC").is_err());
+  assert_eq!(julian_day_to_historical_string(ides + 0.5), Some("0044-03-15 12:00:00 BC".to_string()));
+  assert_eq!(julian_day_to_historical_string(datetime_to_julian_day("0000-12-31").unwrap()), Some("0001-12-31 00:00:00 BC".to_string()));
+  assert_eq!(astronomical_to_historical_year(1), (1, HistoricalEra::AnnoDomini));
+  assert_eq!(historical_to_astronomical_year(0, HistoricalEra::AnnoDomini), None);
+  assert_eq!(julian_day_to_historical_string(1e300), None);
+  assert_eq!(julian_day_to_historical_string(-1e300), None);
+}
+
+#[test]
+fn test_era_aware_formatting() {
+  let jd = datetime_to_julian_day("-0043-03-15 09:30").unwrap();
+  let formatted = format_julian_day_with_era(jd, EraNotation::BceCe).unwrap();
+  assert_eq!(formatted, "0044 BCE-03-15 09:30:00");
+  assert_eq!(datetime_to_julian_day(&formatted).ok(), Some(jd));
+  assert_eq!(format_julian_day_with_era(jd, EraNotation::BcAd), Some("0044 BC-03-15 09:30:00".to_string()));
+  assert_eq!(format_julian_day_with_era(datetime_to_julian_day("0001-01-01").unwrap(), EraNotation::BceCe), Some("0001-01-01 00:00:00".to_string()));
+  assert_eq!(format_julian_day_with_era(datetime_to_julian_day("0000-06-01").unwrap(), EraNotation::BceCe), Some("0001 BCE-06-01 00:00:00".to_string()));
+  assert_eq!(format_julian_day_with_era(1e300, EraNotation::BceCe), None);
+  assert_eq!(format_julian_day_with_era(-1e300, EraNotation::BcAd), None);
+}
+
+#[test]
+fn test_chrono_weekday() {
+  let dt = NaiveDateTime::from_jd(2459827.25).unwrap(); // Sunday 2022-09-04 18:00 UTC
+  assert_eq!(dt.weekday(0), Weekday::Sun);
+  assert_eq!(dt.weekday(36000), Weekday::Mon);
+  assert_eq!(julian_day_to_weekday(2459827.25, -72000), Weekday::Sat);
+  assert_eq!(weekday_index_to_weekday(3), Weekday::Wed);
+}
+
+#[test]
+fn test_weekday_and_month_names() {
+  // 2023-12-31 20:00 UTC is already Monday 1 January 2024 at UTC+5
+  let jd = datetime_to_julian_day("2023-12-31 20:00").unwrap();
+  assert_eq!(weekday_name(jd, 0, &LocaleNames::default()), Some("Sunday"));
+  assert_eq!(month_name(jd, 0, &LocaleNames::ENGLISH), Some("December"));
+  assert_eq!(weekday_name(jd, 18000, &LocaleNames::SPANISH), Some("lunes"));
+  assert_eq!(month_name(jd, 18000, &LocaleNames::SPANISH), Some("enero"));
+  for jd in [f64::NAN, f64::INFINITY, 1e300, -1e300] {
+    assert_eq!(weekday_name(jd, 0, &LocaleNames::ENGLISH), None);
+    assert_eq!(month_name(jd, 0, &LocaleNames::ENGLISH), None);
+  }
+  // a custom locale table
+  const DUTCH: LocaleNames = LocaleNames {
+    weekdays: ["zondag", "maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag"],
+    months: ["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"],
+  };
+  assert_eq!(weekday_name(jd, 18000, &DUTCH), Some("maandag"));
+}
+
+#[test]
+fn test_weekday_index_before_1970() {
+  // Wednesday 31 December 1969, including late evening UTC and west of Greenwich
+  let jd = datetime_to_julian_day("1969-12-31 23:00").unwrap();
+  assert_eq!(julian_day_to_weekday_index(jd, 0), 3);
+  assert_eq!(julian_day_to_weekday_index(jd, 7200), 4);
+  assert_eq!(julian_day_to_weekday_index(datetime_to_julian_day("1970-01-01 01:00").unwrap(), -7200), 3);
+  // the Apollo 11 landing on Sunday 20 July 1969
+  assert_eq!(julian_day_to_weekday_index(datetime_to_julian_day("1969-07-20 20:17").unwrap(), 0), 0);
+  // Thursday 4 October 1582 (Julian) was followed by Friday 15 October 1582 (Gregorian)
+  assert_eq!(julian_day_to_weekday_index(2299159.5, 0), 4);
+  assert_eq!(julian_day_to_weekday_index(2299160.5, 0), 5);
+  // BCE and negative Julian Days
+  assert_eq!(julian_day_to_weekday_index(datetime_to_julian_day("-0043-03-15").unwrap(), 0), 5);
+  assert_eq!(julian_day_to_weekday_index(-0.5, 0), 1);
+  assert_eq!(julian_day_to_weekday_index(-1.0, 0), 0);
+  // exactly 142,857 weeks before the Sunday starting at Julian Day -1.5
+  assert_eq!(julian_day_to_weekday_index(-1_000_000.5, 0), 0);
+  // any f64 yields an index rather than overflowing
+  for jd in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e300, -1e300] {
+    assert!(julian_day_to_weekday_index(jd, 0) < 7);
+  }
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_weekday_in_timezone() {
+  // Saturday 2024-03-09 23:30 in London is Sunday 00:30 in Paris (CET, UTC+1)
+  let jd = datetime_to_julian_day("2024-03-09 23:30").unwrap();
+  assert_eq!(weekday_index_in_tz(jd, "Europe/London"), Some(6));
+  assert_eq!(weekday_index_in_tz(jd, "Europe/Paris"), Some(0));
+  // Sydney switches from AEDT (UTC+11) to AEST (UTC+10) on the first Sunday of April
+  assert_eq!(utc_offset_secs_in_tz(datetime_to_julian_day("2024-03-01").unwrap(), "Australia/Sydney"), Some(39600));
+  assert_eq!(utc_offset_secs_in_tz(datetime_to_julian_day("2024-06-01").unwrap(), "Australia/Sydney"), Some(36000));
+  assert_eq!(weekday_index_in_tz(jd, "Mars/Olympus_Mons"), None);
+}
+
+#[test]
+fn test_unixtime_weekday_index() {
+  // Sunday 2022-09-04 18:00 UTC
+  let ts = 1662314400;
+  assert_eq!(unixtime_to_weekday_index(ts, 0), 0);
+  assert_eq!(unixtime_to_weekday_index(ts, 36000), 1);
+  assert_eq!(unix_millis_to_weekday_index(ts * 1000 + 999, 0), 0);
+  // one millisecond before the unix epoch is still Wednesday
+  assert_eq!(unix_millis_to_weekday_index(-1, 0), 3);
+  assert_eq!(unixtime_to_weekday_index(julian_day_to_unixtime(2299160.5), 0), 5);
+  // offsets at the ends of the i64 range do not overflow
+  assert!(unixtime_to_weekday_index(i64::MAX, 86400) < 7);
+  assert!(unixtime_to_weekday_index(i64::MIN, -86400) < 7);
+}
+
+#[test]
+fn test_iso_week_number() {
+  // Thursday 2015-01-01 starts week 1 of 2015; 2015 has 53 weeks
+  assert_eq!(julian_day_to_iso_week(datetime_to_julian_day("2015-01-01").unwrap(), 0), Some((2015, 1)));
+  assert_eq!(julian_day_to_iso_week(datetime_to_julian_day("2016-01-03").unwrap(), 0), Some((2015, 53)));
+  // Monday 2024-12-30 is in week 1 of 2025
+  assert_eq!(julian_day_to_iso_week(datetime_to_julian_day("2024-12-30").unwrap(), 0), Some((2025, 1)));
+  // Sunday 2024-12-29 22:00 UTC is already Monday in Tokyo
+  let jd = datetime_to_julian_day("2024-12-29 22:00").unwrap();
+  assert_eq!(julian_day_to_iso_week(jd, 0), Some((2024, 52)));
+  assert_eq!(julian_day_to_iso_week(jd, 32400), Some((2025, 1)));
+  // years beyond the supported range are not truncated
+  assert_eq!(julian_day_to_iso_week(1e18, 0), None);
+  assert_eq!(julian_day_to_iso_week(-1e18, 0), None);
+}
+
+#[test]
+fn test_week_rules() {
+  // Saturday 2022-01-01 and the following days under each rule
+  let new_year = datetime_to_julian_day("2022-01-01").unwrap();
+  assert_eq!(julian_day_to_week_number(new_year, 0, WeekRule::Iso), Some((2021, 52)));
+  assert_eq!(julian_day_to_week_number(new_year, 0, WeekRule::Us), Some((2022, 1)));
+  assert_eq!(julian_day_to_week_number(new_year, 0, WeekRule::MiddleEastern), Some((2022, 1)));
+  assert_eq!(julian_day_to_week_number(new_year + 1.0, 0, WeekRule::Us), Some((2022, 2)));
+  assert_eq!(julian_day_to_week_number(new_year + 1.0, 0, WeekRule::MiddleEastern), Some((2022, 1)));
+  assert_eq!(julian_day_to_week_number(new_year + 7.0, 0, WeekRule::MiddleEastern), Some((2022, 2)));
+  // a leap year starting on Saturday has 54 US weeks
+  assert_eq!(julian_day_to_week_number(datetime_to_julian_day("2000-12-31").unwrap(), 0, WeekRule::Us), Some((2000, 54)));
+  assert_eq!(julian_day_to_week_number(datetime_to_julian_day("2000-12-31").unwrap(), 0, WeekRule::Iso), Some((2000, 52)));
+  assert_eq!(julian_day_to_week_number(1e300, 0, WeekRule::Us), None);
+  assert_eq!(julian_day_to_week_number(-1e300, 0, WeekRule::MiddleEastern), None);
+}
+
+#[test]
+fn test_weekday_index_with_week_start() {
+  let dt = NaiveDateTime::from_jd(2459827.25).unwrap(); // Sunday 2022-09-04 18:00 UTC
+  assert_eq!(dt.weekday_index_from(0, WeekStart::Sunday), 0);
+  assert_eq!(dt.weekday_index_from(0, WeekStart::Monday), 6);
+  assert_eq!(dt.weekday_index_from(36000, WeekStart::Monday), 0);
+  assert_eq!(julian_day_to_weekday_index_from(2459827.25, 0, WeekStart::Saturday), 1);
+  assert_eq!(julian_day_to_weekday_index_from(2459827.25, 0, WeekStart::default()), 0);
+  assert_eq!(WeekRule::MiddleEastern.week_start(), WeekStart::Saturday);
+}
+
+#[test]
+fn test_next_and_previous_weekday() {
+  // Wednesday 2024-09-04 09:00 UTC
+  let jd = datetime_to_julian_day("2024-09-04 09:00").unwrap();
+  assert_eq!(next_weekday_jd(jd, Weekday::Mon, 0, false), datetime_to_julian_day("2024-09-09 09:00").unwrap());
+  assert_eq!(next_weekday_jd(jd, Weekday::Wed, 0, false), jd + 7.0);
+  assert_eq!(next_weekday_jd(jd, Weekday::Wed, 0, true), jd);
+  assert_eq!(previous_weekday_jd(jd, Weekday::Fri, 0, false), datetime_to_julian_day("2024-08-30 09:00").unwrap());
+  assert_eq!(previous_weekday_jd(jd, Weekday::Wed, 0, true), jd);
+  assert_eq!(previous_weekday_jd(jd, Weekday::Wed, 0, false), jd - 7.0);
+  // already Thursday at UTC+16, so the next Thursday is a week later
+  assert_eq!(next_weekday_jd(jd, Weekday::Thu, 57600, false), jd + 7.0);
+}
+
+#[test]
+fn test_nth_weekday_of_month() {
+  // Martin Luther King Jr. Day: 3rd Monday in January
+  assert_eq!(nth_weekday_of_month(2025, 1, Weekday::Mon, 3), datetime_to_julian_day("2025-01-20").ok());
+  // the first day of the month counts when it matches
+  assert_eq!(nth_weekday_of_month(2024, 9, Weekday::Sun, 1), datetime_to_julian_day("2024-09-01").ok());
+  assert_eq!(nth_weekday_of_month(2024, 2, Weekday::Thu, 5), datetime_to_julian_day("2024-02-29").ok());
+  assert_eq!(nth_weekday_of_month(2023, 2, Weekday::Wed, 5), None);
+  assert_eq!(nth_weekday_of_month(2024, 9, Weekday::Sun, 0), None);
+  assert_eq!(nth_weekday_of_month(2024, 13, Weekday::Sun, 1), None);
+  assert_eq!(last_weekday_of_month(2024, 2, Weekday::Thu), datetime_to_julian_day("2024-02-29").ok());
+  assert_eq!(last_weekday_of_month(2024, 8, Weekday::Mon), datetime_to_julian_day("2024-08-26").ok());
+  assert_eq!(last_weekday_of_month(-500, 3, Weekday::Sat).map(|jd| julian_day_to_weekday_index(jd, 0)), Some(6));
+}
+
+#[test]
+fn test_count_weekday_in_range() {
+  let start = datetime_to_julian_day("2024-09-01").unwrap();
+  let end = datetime_to_julian_day("2024-10-01").unwrap();
+  // September 2024 has five Sundays and Mondays and four of every other weekday
+  assert_eq!(count_weekday_in_range(start, end, Weekday::Sun, 0), 5);
+  assert_eq!(count_weekday_in_range(start, end, Weekday::Mon, 0), 5);
+  assert_eq!(count_weekday_in_range(start, end, Weekday::Tue, 0), 4);
+  assert_eq!(count_weekday_in_range(end, start, Weekday::Sun, 0), 0);
+  // at UTC-10 the range starts on Saturday 31 August
+  assert_eq!(count_weekday_in_range(start, end, Weekday::Sat, -36000), 5);
+  // matches counting day by day over a long range
+  let (start, end) = (2400000.5, 2460000.5);
+  let iterated = (0..(end - start) as i64).filter(|d| julian_day_to_weekday_index(start + *d as f64, 0) == 3).count() as u64;
+  assert_eq!(count_weekday_in_range(start, end, Weekday::Wed, 0), iterated);
+}
+
+#[test]
+fn test_business_days_between() {
+  let start = datetime_to_julian_day("2024-01-01").unwrap();
+  let end = datetime_to_julian_day("2025-01-01").unwrap();
+  assert_eq!(business_days_between(start, end, &Weekend::SATURDAY_SUNDAY), 262);
+  assert_eq!(weekend_days_between(start, end, &Weekend::SATURDAY_SUNDAY), 104);
+  assert_eq!(business_days_between(start, end, &Weekend::FRIDAY_SATURDAY), 262);
+  assert_eq!(business_days_between(start, end, &Weekend::new(&[Weekday::Sun])), 314);
+  assert_eq!(Weekend::default(), Weekend::new(&[Weekday::Sat, Weekday::Sun]));
+  // the time of day is ignored and reversed ranges are empty
+  assert_eq!(business_days_between(start + 0.75, start + 7.25, &Weekend::SATURDAY_SUNDAY), 5);
+  assert_eq!(business_days_between(end, start, &Weekend::SATURDAY_SUNDAY), 0);
+}
+
+#[test]
+fn test_business_day_arithmetic() {
+  let christmas = datetime_to_julian_day("2024-12-25").unwrap();
+  let calendar = HolidayCalendar::new(Weekend::SATURDAY_SUNDAY).with_holiday(christmas).with_holiday(christmas + 1.0);
+  assert!(!is_business_day(christmas, &calendar));
+  assert!(!is_business_day(datetime_to_julian_day("2024-12-28 10:00").unwrap(), &calendar));
+  assert!(is_business_day(datetime_to_julian_day("2024-12-27 10:00").unwrap(), &calendar));
+  // Tuesday 24 December 15:30 plus one business day skips the holidays and keeps the time
+  let start = datetime_to_julian_day("2024-12-24 15:30").unwrap();
+  assert_eq!(add_business_days(start, 1, &calendar), datetime_to_julian_day("2024-12-27 15:30").unwrap());
+  assert_eq!(add_business_days(start, 2, &calendar), datetime_to_julian_day("2024-12-30 15:30").unwrap());
+  assert_eq!(add_business_days(start + 3.0, -1, &calendar), start);
+  assert_eq!(add_business_days(start, 0, &calendar), start);
+  // with a Friday-Saturday weekend and no holidays, Thursday + 1 is Sunday
+  let gulf = HolidayCalendar::new(Weekend::FRIDAY_SATURDAY);
+  assert_eq!(add_business_days(datetime_to_julian_day("2024-12-26").unwrap(), 1, &gulf), datetime_to_julian_day("2024-12-29").unwrap());
+  assert_eq!(calendar.holiday_jdns().count(), 2);
+}
+
+#[test]
+fn test_recurrence() {
+  let start = datetime_to_julian_day("2024-09-04 09:00").unwrap();
+  let every_third_day: Vec<f64> = Recurrence::new(start, Frequency::Daily(3)).count_limit(3).collect();
+  assert_eq!(every_third_day, vec![start, start + 3.0, start + 6.0]);
+  // weekly until the end of September, inclusive of the end date
+  let until = datetime_to_julian_day("2024-09-25 09:00").unwrap();
+  assert_eq!(Recurrence::new(start, Frequency::Weekly(1)).until(until).count(), 4);
+  // weekdays only, skipping Saturday and Sunday
+  let weekdays: Vec<u8> = Recurrence::new(start, Frequency::Daily(1))
+    .on_weekdays(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri])
+    .count_limit(5)
+    .map(|jd| julian_day_to_weekday_index(jd, 0))
+    .collect();
+  assert_eq!(weekdays, vec![3, 4, 5, 1, 2]);
+  // a weekly rule on Fridays starting on a Wednesday begins two days later
+  let fridays: Vec<f64> = Recurrence::new(start, Frequency::Weekly(1)).on_weekdays(&[Weekday::Fri]).count_limit(2).collect();
+  assert_eq!(fridays, vec![start + 2.0, start + 9.0]);
+  // a rule that can never match ends instead of looping
+  assert_eq!(Recurrence::new(start, Frequency::Daily(7)).on_weekdays(&[Weekday::Mon]).next(), None);
+}
+
+#[test]
+fn test_week_boundaries() {
+  // Sunday 8 September 2024 23:30 UTC
+  let jd = datetime_to_julian_day("2024-09-08 23:30").unwrap();
+  assert_eq!(start_of_week_jd(jd, 0, WeekStart::Monday), datetime_to_julian_day("2024-09-02").unwrap());
+  assert_eq!(end_of_week_jd(jd, 0, WeekStart::Monday), datetime_to_julian_day("2024-09-09").unwrap());
+  assert_eq!(start_of_week_jd(jd, 0, WeekStart::Sunday), datetime_to_julian_day("2024-09-08").unwrap());
+  assert_eq!(start_of_week_jd(jd, 0, WeekStart::Saturday), datetime_to_julian_day("2024-09-07").unwrap());
+  // at UTC+2 it is already Monday, whose local midnight is 22:00 UTC on Sunday
+  assert_eq!(start_of_week_jd(jd, 7200, WeekStart::Monday), datetime_to_julian_day("2024-09-08 22:00").unwrap());
+  // the start of a week is in that week
+  let start = start_of_week_jd(jd, -18000, WeekStart::Sunday);
+  assert_eq!(start_of_week_jd(start, -18000, WeekStart::Sunday), start);
+}
+
+#[test]
+fn test_month_and_quarter_boundaries() {
+  let jd = datetime_to_julian_day("2024-11-15 18:00").unwrap();
+  assert_eq!(start_of_month_jd(jd, 0), datetime_to_julian_day("2024-11-01").unwrap());
+  assert_eq!(end_of_month_jd(jd, 0), datetime_to_julian_day("2024-12-01").unwrap());
+  assert_eq!(start_of_quarter_jd(jd, 0), datetime_to_julian_day("2024-10-01").unwrap());
+  assert_eq!(end_of_quarter_jd(jd, 0), datetime_to_julian_day("2025-01-01").unwrap());
+  assert_eq!(quarter_of(jd, 0), 4);
+  let december = datetime_to_julian_day("2024-12-31 12:00").unwrap();
+  assert_eq!(end_of_month_jd(december, 0), datetime_to_julian_day("2025-01-01").unwrap());
+  // 31 December 20:00 UTC is already the new year and first quarter in Tokyo (UTC+9)
+  let jd = datetime_to_julian_day("2024-12-31 20:00").unwrap();
+  assert_eq!(quarter_of(jd, 32400), 1);
+  assert_eq!(start_of_quarter_jd(jd, 32400), datetime_to_julian_day("2024-12-31 15:00").unwrap());
+  assert_eq!(start_of_month_jd(jd, 32400), start_of_quarter_jd(jd, 32400));
+}
+
+#[test]
+fn test_start_of_day_and_noon() {
+  let morning = datetime_to_julian_day("2024-09-04 06:00").unwrap();
+  let evening = datetime_to_julian_day("2024-09-04 18:00").unwrap();
+  let midnight = datetime_to_julian_day("2024-09-04").unwrap();
+  // either side of the JD day boundary at noon UTC
+  assert_eq!(start_of_day_jd(morning, 0), midnight);
+  assert_eq!(start_of_day_jd(evening, 0), midnight);
+  assert_eq!(noon_jd(evening, 0), midnight + 0.5);
+  assert_eq!(start_of_day_jd(midnight, 0), midnight);
+  // in Los Angeles (UTC-7) 06:00 UTC is still 3 September
+  assert_eq!(start_of_day_jd(morning, -25200), datetime_to_julian_day("2024-09-03 07:00").unwrap());
+  assert_eq!(noon_jd(morning, -25200), datetime_to_julian_day("2024-09-03 19:00").unwrap());
+  // negative Julian Days
+  assert_eq!(start_of_day_jd(-10.2, 0), -10.5);
+  assert_eq!(noon_jd(-10.7, 0), -11.0);
+}
+
+#[test]
+fn test_days_in_month_from_julian_day() {
+  assert_eq!(days_in_month(datetime_to_julian_day("2023-02-10").unwrap(), 0), 28);
+  assert_eq!(days_in_month(datetime_to_julian_day("2000-02-10").unwrap(), 0), 29);
+  assert_eq!(days_in_month(datetime_to_julian_day("1900-02-10").unwrap(), 0), 28);
+  assert_eq!(days_in_month(datetime_to_julian_day("2024-04-30 23:00").unwrap(), 3600), 31);
+  assert!(is_last_day_of_month(datetime_to_julian_day("2024-02-29 12:00").unwrap(), 0));
+  assert!(!is_last_day_of_month(datetime_to_julian_day("2023-02-28 22:00").unwrap(), 7200));
+  assert!(is_last_day_of_month(datetime_to_julian_day("2024-12-01 03:00").unwrap(), -14400));
+}
+
+#[test]
+fn test_leap_years_by_julian_day() {
+  let jd_2000 = datetime_to_julian_day("2000-06-01").unwrap();
+  let jd_2100 = datetime_to_julian_day("2100-06-01").unwrap();
+  assert!(is_leap_year_at(jd_2000, &ProlepticGregorian));
+  assert!(!is_leap_year_at(jd_2100, &ProlepticGregorian));
+  assert!(is_leap_year_at(jd_2100, &ProlepticJulian));
+  assert_eq!(days_in_year_at(jd_2100, &ProlepticGregorian), Some(365));
+  assert_eq!(days_in_year_at(jd_2100, &ProlepticJulian), Some(366));
+  // other calendars work too, but only within their supported range
+  assert_eq!(days_in_year_at(jd_2000, &CopticCalendar), Some(365));
+  assert_eq!(days_in_year_at(0.0, &UmmAlQura), None);
+  assert!(!is_leap_year_at(0.0, &UmmAlQura));
+}
+
+#[test]
+fn test_week_of_month() {
+  let rows = WeekOfMonthRule::CalendarRows;
+  // September 2024 begins on a Sunday and spans 6 rows in a Monday-first calendar
+  assert_eq!(week_of_month(datetime_to_julian_day("2024-09-01").unwrap(), 0, WeekStart::Monday, rows), 1);
+  assert_eq!(week_of_month(datetime_to_julian_day("2024-09-02").unwrap(), 0, WeekStart::Monday, rows), 2);
+  assert_eq!(week_of_month(datetime_to_julian_day("2024-09-30").unwrap(), 0, WeekStart::Monday, rows), 6);
+  assert_eq!(week_of_month(datetime_to_julian_day("2024-09-30").unwrap(), 0, WeekStart::Sunday, rows), 5);
+  assert_eq!(week_of_month(datetime_to_julian_day("2024-09-07").unwrap(), 0, WeekStart::Saturday, rows), 2);
+  // 1 October 01:00 UTC is still 30 September in New York (UTC-4)
+  assert_eq!(week_of_month(datetime_to_julian_day("2024-10-01 01:00").unwrap(), -14400, WeekStart::Monday, rows), 6);
+  let blocks = WeekOfMonthRule::SevenDayBlocks;
+  assert_eq!(week_of_month(datetime_to_julian_day("2024-09-15").unwrap(), 0, WeekStart::Monday, blocks), 3);
+  assert_eq!(week_of_month(datetime_to_julian_day("2024-09-29").unwrap(), 0, WeekStart::Sunday, blocks), 5);
+}
+
+#[test]
+fn test_julian_day_value() {
+  let jd = JulianDayValue::new(2460558.0).unwrap();
+  assert_eq!(jd.to_string(), "2460558");
+  assert_eq!("2460558.0".parse::<JulianDayValue>(), Ok(jd));
+  assert_eq!("NaN".parse::<JulianDayValue>(), Err(JulianDayError::InvalidFormat));
+  assert_eq!("2024-09-04".parse::<JulianDayValue>(), Ok(JulianDayValue::new(2460557.5).unwrap()));
+  assert_eq!(JulianDayValue::new(f64::INFINITY), None);
+  assert_eq!(jd.mjd(), 60557.5);
+  assert_eq!(JulianDayValue::from_mjd(60557.5), Some(jd));
+  assert_eq!(JulianDayValue::from_unixtime(jd.to_unixtime()), Some(jd));
+  assert_eq!(JulianDayValue::from_unixtime(i64::MAX), None);
+  assert_eq!(JulianDayValue::new(MAX_SUPPORTED_JD + 1.0), None);
+  // arithmetic saturates at the ends of the supported range
+  assert_eq!((jd + 1e12).value(), MAX_SUPPORTED_JD);
+  assert_eq!((jd - f64::INFINITY).value(), MIN_SUPPORTED_JD);
+  assert_eq!((jd + f64::NAN).value(), MIN_SUPPORTED_JD);
+  assert_eq!((jd + chrono::Duration::MAX).value(), MAX_SUPPORTED_JD);
+  assert_eq!(jd.checked_add_days(1e12), None);
+  assert_eq!(jd.checked_sub_days(0.5).map(|jd| jd.value()), Some(2460557.5));
+  assert_eq!(jd.to_datetime().unwrap().to_string(), "2024-09-04 12:00:00");
+  assert_eq!(JulianDayValue::from_datetime_str("2024-09-04T12:00:00"), Some(jd));
+  assert_eq!(jd.weekday_index(0), 3);
+  assert!(jd < jd + 0.25);
+  assert_eq!(jd + 1.5 - 1.5, jd);
+  assert_eq!((jd + chrono::Duration::hours(6)).value(), 2460558.25);
+  assert_eq!((jd - chrono::Duration::days(2)).value(), 2460556.0);
+  let mut moving = jd;
+  moving += 2.0;
+  moving -= 0.5;
+  assert_eq!(f64::from(moving), 2460559.5);
+}
+
+#[test]
+fn test_two_part_julian_day() {
+  let jd = TwoPartJD::from_jd(2460558.75);
+  assert_eq!(jd, TwoPartJD { jd1: 2460558.5, jd2: 0.25 });
+  assert_eq!(jd.jd(), 2460558.75);
+  assert_eq!(TwoPartJD::new(2460560.0, -1.25), TwoPartJD { jd1: 2460558.5, jd2: 0.25 });
+  // one nanosecond survives the round trip, which a single f64 cannot hold
+  let nanos = 1_725_451_200_000_000_001;
+  let precise = TwoPartJD::from_unix_nanos(nanos);
+  assert_eq!(precise.to_unix_nanos(), nanos);
+  assert_eq!((precise + 1.0).to_unix_nanos(), nanos + 86_400_000_000_000);
+  assert_eq!((precise - 0.5).to_unix_nanos(), nanos - 43_200_000_000_000);
+  assert_eq!(((precise + 3.0) - precise), 3.0);
+  // before 1970
+  assert_eq!(TwoPartJD::from_unix_nanos(-1).to_unix_nanos(), -1);
+  assert_eq!(TwoPartJD::from_unix_nanos(-1).jd1, 2440586.5);
+  assert_eq!(TwoPartJD::from(JULIAN_DAY_UNIX_EPOCH_DAYS).to_unix_nanos(), 0);
+}
+
+#[test]
+fn test_jdn_split_and_merge() {
+  // either side of midnight and noon UTC
+  assert_eq!(split_jd(2460558.499).0, Jdn(2460558));
+  assert_eq!(split_jd(2460557.5), (Jdn(2460558), 0.0));
+  assert_eq!(split_jd(2460558.25).1, 0.75);
+  assert_eq!(split_jd(-0.75), (Jdn(-1), 0.75));
+  for jd in [2460558.25, 2460558.5, 2460558.75, -12.125] {
+    let (jdn, fraction) = split_jd(jd);
+    assert!((0.0..1.0).contains(&fraction));
+    assert_eq!(merge_jd(jdn, fraction), jd);
+  }
+  let jdn = Jdn::from_gregorian(2024, 2, 29).unwrap();
+  assert_eq!(jdn.to_gregorian(), (2024, 2, 29));
+  assert_eq!(Jdn::from_gregorian(2023, 2, 29), None);
+  assert_eq!((jdn + 1).to_gregorian(), (2024, 3, 1));
+  assert_eq!(Jdn::from_gregorian(2025, 2, 28).unwrap() - jdn, 365);
+  assert_eq!(Jdn::from_jd(jdn.to_jd() + 0.9), jdn);
+  assert_eq!(jdn.weekday_index(), 4);
+}
+
+#[test]
+fn test_fixed_point_julian_day() {
+  use std::collections::HashSet;
+  let epoch = FixedPointJd::from_unix_micros(0).unwrap();
+  assert_eq!(epoch.to_jd(), JULIAN_DAY_UNIX_EPOCH_DAYS);
+  assert_eq!(FixedPointJd::from_jd(JULIAN_DAY_UNIX_EPOCH_DAYS), Some(epoch));
+  // microsecond timestamps either side of 1970 round-trip exactly
+  for micros in [1, -1, 1_725_451_200_999_999, -6_106_060_800_000_001] {
+    assert_eq!(FixedPointJd::from_unix_micros(micros).unwrap().to_unix_micros(), Some(micros));
+  }
+  // instants that differ only by float noise hash to the same key once fixed
+  let a = FixedPointJd::from_jd(2460558.1).unwrap();
+  let b = FixedPointJd::from_jd(2460558.0 + 0.1).unwrap();
+  assert_eq!(HashSet::from([a, b]).len(), 1);
+  assert!(a < FixedPointJd::from_jd(2460558.100001).unwrap());
+  assert_eq!(FixedPointJd::from_jd(f64::NAN), None);
+  assert_eq!(FixedPointJd::from_jd(1e15), None);
+  assert_eq!(FixedPointJd::from_unix_micros(i64::MAX), None);
+  assert_eq!(FixedPointJd(-43_200_000_000).to_jd(), -0.5);
+}
+
+#[test]
+fn test_i128_nanosecond_conversions() {
+  for nanos in [0i128, 1, -1, i64::MAX as i128, i64::MIN as i128, 1_000_000 * 86_400_000_000_000 + 7] {
+    assert_eq!(julian_day_parts_to_unix_nanos(&unix_nanos_to_julian_day_parts(nanos)), nanos);
+  }
+  let parts = unix_nanos_to_julian_day_parts(-86_400_000_000_000 - 1);
+  assert_eq!(parts.jd1, JULIAN_DAY_UNIX_EPOCH_DAYS - 2.0);
+  // an unnormalised split gives the same instant
+  assert_eq!(julian_day_parts_to_unix_nanos(&TwoPartJD { jd1: 2440588.0, jd2: 0.0 }), 43_200_000_000_000);
+}
+
+#[test]
+fn test_unix_micros_conversions() {
+  assert_eq!(unix_micros_to_julian_day(0), JULIAN_DAY_UNIX_EPOCH_DAYS);
+  assert_eq!(unix_micros_to_julian_day(-43_200_000_000), JULIAN_DAY_UNIX_EPOCH_DAYS - 0.5);
+  assert_eq!(julian_day_to_unix_micros(JULIAN_DAY_UNIX_EPOCH_DAYS - 0.5), -43_200_000_000);
+  // 18:00:00 does not come back as 17:59:59.999999
+  let evening = datetime_to_julian_day("2024-09-04 18:00").unwrap();
+  assert_eq!(julian_day_to_unix_micros(evening), 1_725_472_800_000_000);
+  // round trips are exact to the resolution of an f64 Julian Day
+  let micros = 1_725_451_200_123_456;
+  assert!((julian_day_to_unix_micros(unix_micros_to_julian_day(micros)) - micros).abs() <= 20);
+  // results beyond the i64 range saturate
+  assert_eq!(julian_day_to_unix_micros(1e300), i64::MAX);
+  assert_eq!(julian_day_to_unix_micros(-1e300), i64::MIN);
+}
+
+#[test]
+fn test_unix_millis_round_trips() {
+  // 18:00:00 does not come back as 17:59:59.999
+  let evening = datetime_to_julian_day("2024-09-04 18:00").unwrap();
+  assert_eq!(julian_day_to_unix_millis(evening), 1_725_472_800_000);
+  assert_eq!(julian_day_to_unix_millis(JULIAN_DAY_UNIX_EPOCH_DAYS - 0.5), -43_200_000);
+  // exact to the millisecond within the documented range
+  for ms in [0, 1, -1, 999, 1_725_472_800_123, -62_135_596_800_001, 150_000_000_000_000] {
+    assert_eq!(julian_day_to_unix_millis(unix_millis_to_julian_day(ms)), ms);
+  }
+  // within a second across the i64 range
+  for ms in [i64::MAX, i64::MIN, i64::MAX / 3, i64::MIN / 7] {
+    let round_trip = julian_day_to_unix_millis(unix_millis_to_julian_day(ms));
+    assert!((round_trip as i128 - ms as i128).abs() < 1000);
+  }
+}
+
+#[test]
+fn test_rounding_modes() {
+  // 0.4 seconds before and after 18:00:00
+  let before = datetime_to_julian_day("2024-09-04 18:00").unwrap() - 0.4 / 86400.0;
+  let after = before + 0.8 / 86400.0;
+  assert_eq!(julian_day_to_unixtime_rounded(before, RoundingMode::HalfEven), 1_725_472_800);
+  assert_eq!(julian_day_to_unixtime_rounded(before, RoundingMode::Truncate), 1_725_472_799);
+  assert_eq!(julian_day_to_unixtime_rounded(after, RoundingMode::Floor), 1_725_472_800);
+  assert_eq!(julian_day_to_unixtime_rounded(after, RoundingMode::Ceil), 1_725_472_801);
+  assert_eq!(julian_day_to_unix_millis_rounded(before, RoundingMode::HalfEven), 1_725_472_799_600);
+  // before 1970 truncation goes toward zero but flooring goes to the earlier second
+  let pre_epoch = JULIAN_DAY_UNIX_EPOCH_DAYS - 1.5 / 86400.0;
+  assert_eq!(julian_day_to_unixtime_rounded(pre_epoch, RoundingMode::Truncate), -1);
+  assert_eq!(julian_day_to_unixtime_rounded(pre_epoch, RoundingMode::Floor), -2);
+  assert_eq!(julian_day_to_unixtime_rounded(pre_epoch, RoundingMode::Ceil), -1);
+  assert_eq!(julian_day_to_unixtime_rounded(pre_epoch, RoundingMode::HalfEven), -2);
+  assert_eq!(RoundingMode::default(), RoundingMode::HalfEven);
+}
+
+#[test]
+fn test_round_and_truncate_julian_days() {
+  let noisy = datetime_to_julian_day("2024-09-04 18:29:31").unwrap() + 1e-9;
+  assert_eq!(round_jd_to(noisy, TimeUnit::Second), datetime_to_julian_day("2024-09-04 18:29:31").unwrap());
+  assert_eq!(round_jd_to(noisy, TimeUnit::Minute), datetime_to_julian_day("2024-09-04 18:30").unwrap());
+  assert_eq!(truncate_jd_to(noisy, TimeUnit::Minute), datetime_to_julian_day("2024-09-04 18:29").unwrap());
+  assert_eq!(round_jd_to(noisy, TimeUnit::Hour), datetime_to_julian_day("2024-09-04 18:00").unwrap());
+  assert_eq!(truncate_jd_to(noisy, TimeUnit::Day), datetime_to_julian_day("2024-09-04").unwrap());
+  assert_eq!(round_jd_to(noisy, TimeUnit::Day), datetime_to_julian_day("2024-09-05").unwrap());
+  // values just short of a whole unit are cleaned up
+  let almost = datetime_to_julian_day("2024-09-04 18:00").unwrap() - 1e-10;
+  assert_eq!(round_jd_to(almost, TimeUnit::Millisecond), datetime_to_julian_day("2024-09-04 18:00").unwrap());
+  // before 1970 truncation goes to the start of the unit
+  assert_eq!(truncate_jd_to(JULIAN_DAY_UNIX_EPOCH_DAYS - 0.1, TimeUnit::Day), JULIAN_DAY_UNIX_EPOCH_DAYS - 1.0);
+}
+
+#[test]
+fn test_floored_unixtime_before_1970() {
+  // 1969-12-31 23:59:59.5 belongs to the second starting at -1
+  let jd = JULIAN_DAY_UNIX_EPOCH_DAYS - 0.5 / 86400.0;
+  assert_eq!(julian_day_to_unixtime(jd), -1);
+  assert_eq!(julian_day_to_datetime(jd).unwrap().to_string(), "1969-12-31 23:59:59");
+  assert_eq!(julian_day_to_unixtime(JULIAN_DAY_UNIX_EPOCH_DAYS + 0.5 / 86400.0), 0);
+  assert_eq!(julian_day_to_unixtime(JULIAN_DAY_UNIX_EPOCH_DAYS - 1.0 - 0.25 / 86400.0), -86401);
+  // whole seconds either side of the epoch round-trip
+  for ts in [-1, 0, 1, -86_400, -86_401, -2_208_988_800, 1_725_472_799] {
+    assert_eq!(julian_day_to_unixtime(unixtime_to_julian_day(ts)), ts);
+  }
+  // float noise below a whole second does not drop to the previous second
+  assert_eq!(julian_day_to_unixtime(unixtime_to_julian_day(-2_208_988_800) - 1e-9), -2_208_988_800);
+  assert_eq!(julian_day_to_unixtime(unixtime_to_julian_day(1_725_472_799) - 1e-9), 1_725_472_799);
+}
+
+#[test]
+fn test_checked_conversions() {
+  assert_eq!(checked_julian_day_to_unixtime(2460558.25), Ok(1_725_472_800));
+  assert_eq!(checked_julian_day_to_unix_micros(2460558.25), Ok(1_725_472_800_000_000));
+  // microseconds overflow i64 about 292,000 years from 1970, long before seconds do
+  assert_eq!(checked_julian_day_to_unix_micros(2e8), Err(JulianDayError::Overflow));
+  assert!(checked_julian_day_to_unixtime(2e8).is_ok());
+  assert_eq!(checked_julian_day_to_unix_millis(-2e11), Err(JulianDayError::Overflow));
+  // the saturating functions still clamp
+  assert_eq!(julian_day_to_unix_millis(2e11), i64::MAX);
+  assert_eq!(checked_julian_day_to_unixtime(1e300), Err(JulianDayError::Overflow));
+  assert_eq!(julian_day_to_unixtime(-1e300), i64::MIN);
+  assert_eq!(checked_unix_millis_to_julian_day(-1), Ok(JULIAN_DAY_UNIX_EPOCH_DAYS - 1.0 / 86_400_000.0));
+  assert_eq!(checked_unix_millis_to_julian_day(i64::MIN), Err(JulianDayError::PrecisionLoss));
+}
+
+#[test]
+fn test_non_finite_julian_days() {
+  for jd in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+    assert!(!is_finite_jd(jd));
+    assert!(julian_day_to_datetime(jd).is_err());
+    assert_eq!(NaiveDateTime::from_jd(jd), None);
+    assert_eq!(checked_julian_day_to_unixtime(jd), Err(JulianDayError::NonFinite));
+    assert_eq!(checked_julian_day_to_unix_millis(jd), Err(JulianDayError::NonFinite));
+    assert_eq!(ProlepticGregorian.julian_day_to_date(jd), None);
+    assert_eq!(ProlepticJulian.format_julian_day(jd), None);
+    assert_eq!(CopticCalendar.julian_day_to_date(jd), None);
+    assert_eq!(PersianCalendar::default().format_julian_day(jd), None);
+    assert_eq!(julian_day_to_chinese(jd), None);
+    assert_eq!(julian_day_to_japanese_era_string(jd), None);
+    assert_eq!(JulianDayValue::new(jd), None);
+    assert_eq!(FixedPointJd::from_jd(jd), None);
+    assert_eq!(julian_day_to_civil(jd), None);
+    assert_eq!(julian_day_to_historical_string(jd), None);
+    assert_eq!(format_julian_day_with_era(jd, EraNotation::BceCe), None);
+    assert_eq!(julian_day_to_era_string(jd, YearNumbering::BuddhistEra), None);
+    assert_eq!(julian_day_to_iso_week_date(jd), None);
+    assert_eq!(julian_day_to_week_number(jd, 0, WeekRule::Us), None);
+    assert_eq!(format_iso_week_date(jd), None);
+    assert_eq!(julian_day_to_ordinal_date(jd), None);
+    assert_eq!(julian_day_to_fractional_ordinal(jd), None);
+    assert_eq!(julian_day_to_julian_calendar(jd), None);
+    assert_eq!(julian_day_to_islamic(jd), None);
+    assert_eq!(julian_day_to_persian(jd), None);
+    assert_eq!(julian_day_to_coptic(jd), None);
+    assert_eq!(julian_day_to_ethiopic(jd, EthiopicEra::AmeteMihret), None);
+    assert_eq!(julian_day_to_bahai(jd), None);
+    assert_eq!(GregorianReform::BRITAIN.julian_day_to_date(jd), None);
+    assert_eq!(GregorianReform::BRITAIN.format_dual(jd), None);
+    assert_eq!(julian_day_to_long_count(jd, GMT_CORRELATION), None);
+    assert_eq!(julian_day_to_tzolkin(jd, GMT_CORRELATION), None);
+    assert_eq!(julian_day_to_haab(jd, GMT_CORRELATION), None);
+    assert_eq!(JdBounds::EXTENDED.to_civil(jd), Err(JulianDayError::NonFinite));
+  }
+  #[cfg(feature = "solar")]
+  assert_eq!(sunrise_jd(f64::NAN, 51.5, 0.0), None);
+  assert!(is_finite_jd(-1e12));
+}
+
+#[test]
+fn test_unsupported_julian_days() {
+  // every conversion returning Option or Result rejects non-finite and far out-of-range Julian Days without panicking
+  for jd in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e300, -1e300] {
+    assert!(julian_day_to_datetime(jd).is_err());
+    assert_eq!(NaiveDateTime::from_jd(jd), None);
+    assert!(NaiveDateTime::try_from_jd(jd).is_err());
+    assert!(checked_julian_day_to_unixtime(jd).is_err());
+    assert!(checked_julian_day_to_unix_millis(jd).is_err());
+    assert!(checked_julian_day_to_unix_micros(jd).is_err());
+    assert!(checked_julian_day_to_datetime(jd).is_err());
+    assert!(julian_day_to_rfc3339(jd, 0).is_none());
+    assert!(julian_day_to_rfc2822(jd, 0).is_none());
+    assert!(julian_day_to_http_date(jd).is_none());
+    assert!(julian_day_to_excel_serial(jd).is_none());
+    assert!(julian_day_to_civil(jd).is_none());
+    assert!(JdBounds::EXTENDED.to_civil(jd).is_err());
+    assert!(computus_at(jd).is_none());
+    assert!(lunation_number(jd).is_none());
+    assert!(next_new_moon_jd(jd).is_none());
+    assert!(next_full_moon_jd(jd).is_none());
+    assert!(julian_day_to_julian_calendar(jd).is_none());
+    assert!(GregorianReform::BRITAIN.julian_day_to_date(jd).is_none());
+    assert!(GregorianReform::BRITAIN.format(jd).is_none());
+    assert!(GregorianReform::BRITAIN.format_dual(jd).is_none());
+    assert!(julian_day_to_islamic(jd).is_none());
+    assert!(TabularIslamic::default().julian_day_to_date(jd).is_none());
+    assert!(julian_day_to_umm_al_qura(jd).is_none());
+    assert!(julian_day_to_persian(jd).is_none());
+    assert!(julian_day_to_coptic(jd).is_none());
+    assert!(julian_day_to_ethiopic(jd, EthiopicEra::AmeteAlem).is_none());
+    assert!(julian_day_to_long_count(jd, GMT_CORRELATION).is_none());
+    assert!(julian_day_to_tzolkin(jd, GMT_CORRELATION).is_none());
+    assert!(julian_day_to_haab(jd, GMT_CORRELATION).is_none());
+    assert!(julian_day_to_bahai(jd).is_none());
+    assert!(julian_day_to_chinese(jd).is_none());
+    assert!(julian_day_to_era_string(jd, YearNumbering::Minguo).is_none());
+    assert!(JapaneseEraTable::default().era_year(jd).is_none());
+    assert!(julian_day_to_japanese_era_string(jd).is_none());
+    assert!(julian_day_to_iso_week_date(jd).is_none());
+    assert!(julian_day_to_iso_week(jd, 0).is_none());
+    assert!(julian_day_to_week_number(jd, 0, WeekRule::Us).is_none());
+    assert!(julian_day_to_week_number(jd, 0, WeekRule::MiddleEastern).is_none());
+    assert!(format_iso_week_date(jd).is_none());
+    assert!(julian_day_to_ordinal_date(jd).is_none());
+    assert!(julian_day_to_fractional_ordinal(jd).is_none());
+    assert!(format_ordinal_date(jd).is_none());
+    assert!(julian_day_to_historical_string(jd).is_none());
+    assert!(format_julian_day_with_era(jd, EraNotation::BcAd).is_none());
+    assert!(weekday_name(jd, 0, &LocaleNames::ENGLISH).is_none());
+    assert!(month_name(jd, 0, &LocaleNames::ENGLISH).is_none());
+    assert!(days_in_year_at(jd, &ProlepticGregorian).is_none());
+    assert!(JulianDayValue::new(jd).is_none());
+    assert!(FixedPointJd::from_jd(jd).is_none());
+    assert!(JdKey::new(jd, TimeUnit::Second).is_none());
+    assert!(fraction_between(jd, 2460558.0, 2460560.0).is_none());
+    assert!(JdInterval::new(jd, jd).is_none());
+    assert!(calendar_diff(2460558.5, jd).is_none());
+    for calendar in [&ProlepticGregorian as &dyn Calendar, &ProlepticJulian, &UmmAlQura, &PersianCalendar::default(),
+      &CopticCalendar, &EthiopicCalendar::default(), &BahaiCalendar, &ChineseCalendar] {
+      assert!(calendar.julian_day_to_date(jd).is_none());
+      assert!(calendar.format_julian_day(jd).is_none());
+    }
+    #[cfg(feature = "solar")]
+    assert!(sunrise_jd(jd, 51.5, 0.0).is_none() && sunset_jd(jd, 51.5, 0.0).is_none());
+    #[cfg(feature = "tz")]
+    assert!(utc_offset_secs_in_tz(jd, "Europe/London").is_none() && weekday_index_in_tz(jd, "Europe/London").is_none());
+  }
+}
+
+#[test]
+fn test_checked_conversion_errors() {
+  assert_eq!(checked_unixtime_to_julian_day(-1), Ok(JULIAN_DAY_UNIX_EPOCH_DAYS - 1.0 / 86400.0));
+  assert_eq!(checked_unixtime_to_julian_day(i64::MIN), Err(JulianDayError::PrecisionLoss));
+  assert_eq!(checked_julian_day_to_datetime(2460558.25).unwrap().to_string(), "2024-09-04 18:00:00");
+  assert_eq!(checked_julian_day_to_datetime(f64::INFINITY), Err(JulianDayError::NonFinite));
+  assert_eq!(checked_julian_day_to_datetime(-1e9), Err(JulianDayError::OutOfRange));
+  assert_eq!(checked_datetime_to_julian_day("2024-09-04 18:00"), Ok(2460558.25));
+  assert_eq!(checked_datetime_to_julian_day("the day after tomorrow"), Err(JulianDayError::InvalidFormat));
+  assert_eq!(NaiveDateTime::try_from_jd(2460558.25).map(|dt| dt.to_jd()), Ok(2460558.25));
+  assert_eq!(NaiveDateTime::try_from_jd(f64::NAN), Err(JulianDayError::NonFinite));
+  assert_eq!(NaiveDateTime::try_from_jd(1e12), Err(JulianDayError::OutOfRange));
+  // the try_ names are aliases of the checked_ conversions
+  assert_eq!(try_unixtime_to_julian_day(i64::MIN), checked_unixtime_to_julian_day(i64::MIN));
+  assert_eq!(try_julian_day_to_datetime(f64::NAN), Err(JulianDayError::NonFinite));
+  assert_eq!(try_datetime_to_julian_day("2024-09-04 18:00"), Ok(2460558.25));
+}
+
+#[test]
+fn test_saturating_conversions() {
+  assert_eq!(julian_day_to_datetime_saturating(2460558.25).to_string(), "2024-09-04 18:00:00");
+  assert_eq!(julian_day_to_datetime_saturating(-1e15), NaiveDateTime::MIN);
+  assert_eq!(julian_day_to_datetime_saturating(f64::INFINITY).to_string(), "+262142-12-31 23:59:59");
+  assert_eq!(julian_day_to_datetime_saturating(f64::NAN), NaiveDateTime::MIN);
+  assert_eq!(julian_day_to_datetime(MAX_SUPPORTED_JD).unwrap().to_jd(), MAX_SUPPORTED_JD);
+  assert_eq!(julian_day_to_datetime(MIN_SUPPORTED_JD).unwrap(), NaiveDateTime::MIN);
+  assert_eq!(julian_day_to_unixtime_saturating(1e12), 8_210_266_876_799);
+  assert_eq!(julian_day_to_unixtime_saturating(2460558.25), 1_725_472_800);
+  assert_eq!(unixtime_to_julian_day_saturating(i64::MAX), MAX_SUPPORTED_JD);
+  assert_eq!(unixtime_to_julian_day_saturating(i64::MIN), MIN_SUPPORTED_JD);
+  assert_eq!(unixtime_to_julian_day_saturating(0), JULIAN_DAY_UNIX_EPOCH_DAYS);
+}
+
+#[test]
+fn test_supported_jd_range() {
+  let range = supported_jd_range();
+  assert_eq!((*range.start(), *range.end()), (MIN_SUPPORTED_JD, MAX_SUPPORTED_JD));
+  // every supported Julian Day converts, and the limits are tight
+  for jd in [MIN_SUPPORTED_JD, 0.0, -4e7, 2460558.5, MAX_SUPPORTED_JD] {
+    assert!(is_supported_jd(jd));
+    assert!(julian_day_to_datetime(jd).is_ok());
+  }
+  assert!(!is_supported_jd(MIN_SUPPORTED_JD - 1.0));
+  assert!(julian_day_to_datetime(MIN_SUPPORTED_JD - 1.0).is_err());
+  assert!(!is_supported_jd(MAX_SUPPORTED_JD + 1.0 / 86400.0));
+  assert!(julian_day_to_datetime(MAX_SUPPORTED_JD + 1.0 / 86400.0).is_err());
+  assert!(!is_supported_jd(f64::NAN));
+  assert_eq!(clamp_jd(f64::NEG_INFINITY), MIN_SUPPORTED_JD);
+  assert_eq!(clamp_jd(f64::NAN), MIN_SUPPORTED_JD);
+  assert_eq!(clamp_jd(-4e7), -4e7);
+}
+
+#[test]
+fn test_extended_and_custom_bounds() {
+  assert_eq!(JdBounds::default(), JdBounds::CHRONO);
+  assert_eq!(JdBounds::CHRONO.range(), supported_jd_range());
+  // the civil decomposition handles dates far beyond chrono
+  let far_past = JdBounds::EXTENDED.to_civil(-1e14).unwrap();
+  assert!(far_past.year < -273_000_000_000);
+  assert_eq!(far_past.to_julian_day(), Some(-1e14));
+  assert_eq!(JdBounds::EXTENDED.to_civil(MAX_EXTENDED_JD).map(|civil| civil.to_julian_day()), Ok(Some(MAX_EXTENDED_JD)));
+  assert_eq!(JdBounds::EXTENDED.to_civil(2e15), Err(JulianDayError::OutOfRange));
+  assert_eq!(JdBounds::EXTENDED.to_civil(f64::NAN), Err(JulianDayError::NonFinite));
+  // custom bounds for validation
+  let bounds = JdBounds::new(2440587.5, 2460676.5).unwrap();
+  assert!(bounds.contains(2450000.0));
+  assert!(!bounds.contains(2460676.6));
+  assert_eq!(bounds.clamp(f64::NAN), 2440587.5);
+  assert_eq!(bounds.to_civil(2460676.5).map(|civil| civil.year), Ok(2025));
+  assert_eq!(JdBounds::new(2.0, 1.0), None);
+  assert_eq!((bounds.min_jd(), bounds.max_jd()), (2440587.5, 2460676.5));
+  assert_eq!(JdBounds::new(f64::NEG_INFINITY, 1.0), None);
+}
+
+#[test]
+fn test_julian_day_tolerance() {
+  // the same instant from two sources rarely matches exactly
+  let from_string = datetime_to_julian_day("2024-09-04 18:29:31").unwrap();
+  let from_parts = civil_to_julian_day(2024, 9, 4, 18, 29, 31, 0).unwrap();
+  assert!(jd_approx_eq(from_string, from_parts, 0.001));
+  assert!(JdTolerance::default().approx_eq(from_string, from_parts));
+  assert!(!jd_approx_eq(from_string, from_string + 1.0 / 86400.0, 0.5));
+  assert!(jd_approx_eq(from_string, from_string - 1.0 / 86400.0, -1.5));
+  assert!(!jd_approx_eq(f64::NAN, f64::NAN, 1.0));
+  assert!(JdTolerance::MINUTE.approx_eq(from_string, from_string + 59.0 / 86400.0));
+  assert_eq!(JdTolerance::from_millis(1500.0).secs(), 1.5);
+  assert_eq!(JdTolerance::from_days(0.5).secs(), 43200.0);
+  assert!(JdTolerance::SECOND > JdTolerance::MILLISECOND);
+}
+
+#[test]
+fn test_ordered_julian_days() {
+  use std::collections::BTreeMap;
+  let mut jds: Vec<OrderedJd> = [2460558.5, f64::NAN, 2451545.0, -0.5, f64::INFINITY].into_iter().map(OrderedJd).collect();
+  jds.sort();
+  let sorted: Vec<f64> = jds.iter().take(4).map(|jd| jd.value()).collect();
+  assert_eq!(sorted, vec![-0.5, 2451545.0, 2460558.5, f64::INFINITY]);
+  assert!(jds[4].value().is_nan());
+  let mut events = BTreeMap::new();
+  events.insert(OrderedJd::from(2460558.5), "later");
+  events.insert(OrderedJd::from(2451545.0), "J2000");
+  events.insert(OrderedJd::from(2460558.5), "replaced");
+  assert_eq!(events.values().copied().collect::<Vec<_>>(), vec!["J2000", "replaced"]);
+  assert_eq!(OrderedJd(f64::NAN), OrderedJd(f64::NAN));
+  assert!(OrderedJd(-0.0) < OrderedJd(0.0));
+}
+
+#[test]
+fn test_hashable_julian_day_keys() {
+  use std::collections::HashSet;
+  let from_string = datetime_to_julian_day("2024-09-04 18:29:31").unwrap();
+  let from_parts = civil_to_julian_day(2024, 9, 4, 18, 29, 31, 0).unwrap();
+  let keys: HashSet<JdKey> = [from_string, from_parts, from_string + 0.0004 / 86400.0]
+    .into_iter()
+    .filter_map(|jd| JdKey::new(jd, TimeUnit::Millisecond))
+    .collect();
+  assert_eq!(keys.len(), 1);
+  let key = JdKey::new(from_string, TimeUnit::Second).unwrap();
+  assert_eq!(key.units(), 1_725_474_571);
+  assert_eq!(key.to_jd(), from_string);
+  assert_eq!(key.resolution(), TimeUnit::Second);
+  // coarser resolutions merge nearby instants
+  assert_eq!(JdKey::new(from_string, TimeUnit::Hour), JdKey::new(from_string - 0.01, TimeUnit::Hour));
+  assert_eq!(JdKey::new(f64::NAN, TimeUnit::Second), None);
+  assert_eq!(JdKey::new(1e300, TimeUnit::Second), None);
+  assert_eq!(JdKey::new(JULIAN_DAY_UNIX_EPOCH_DAYS - 0.25, TimeUnit::Day).map(|key| key.to_jd()), Some(JULIAN_DAY_UNIX_EPOCH_DAYS));
+}
+
+#[test]
+fn test_julian_day_value_duration_arithmetic() {
+  use chrono::Duration;
+  let start = JulianDayValue::from_datetime_str("2024-09-04 18:29:31").unwrap();
+  let end = start + Duration::hours(36) + Duration::milliseconds(250);
+  assert_eq!(end - start, Duration::hours(36) + Duration::milliseconds(250));
+  assert_eq!(start - end, -(Duration::hours(36) + Duration::milliseconds(250)));
+  let mut moving = start;
+  moving += Duration::days(7);
+  moving -= Duration::seconds(1);
+  assert_eq!(moving - start, Duration::days(7) - Duration::seconds(1));
+  // pre-1970 and BCE Julian Days
+  let ancient = JulianDayValue::new(0.0).unwrap();
+  assert_eq!((ancient + Duration::days(-1)).value(), -1.0);
+  assert_eq!(ancient - JulianDayValue::new(-1.5).unwrap(), Duration::hours(36));
+  // the whole supported range fits in a Duration
+  let span = JulianDayValue::new(MAX_SUPPORTED_JD).unwrap() - JulianDayValue::new(MIN_SUPPORTED_JD).unwrap();
+  assert_eq!(span, Duration::seconds(((MAX_SUPPORTED_JD - MIN_SUPPORTED_JD) * 86400.0).round() as i64));
+}
+
+#[test]
+fn test_signed_differences() {
+  let start = datetime_to_julian_day("2024-09-04 18:29:31").unwrap();
+  let end = datetime_to_julian_day("2024-09-06 06:29:32").unwrap();
+  // float subtraction leaves noise that the integer path removes
+  assert_eq!(millis_between(start, end), 129_601_000);
+  assert_eq!(seconds_between(start, end), 129_601);
+  assert_eq!(seconds_between(end, start), -129_601);
+  assert_eq!(days_between(start, end), 129_601_000.0 / 86_400_000.0);
+  assert_eq!(days_between(start, start), 0.0);
+  // across the unix epoch and before the common era
+  assert_eq!(millis_between(JULIAN_DAY_UNIX_EPOCH_DAYS - 0.5 / 86400.0, JULIAN_DAY_UNIX_EPOCH_DAYS + 0.5 / 86400.0), 1000);
+  assert_eq!(seconds_between(-0.5, 0.5), 86400);
+  assert_eq!(seconds_between(start, start + 0.9 / 86400.0), 0);
+}
+
+#[test]
+fn test_interpolation_between_julian_days() {
+  let start = 2460558.25;
+  let end = 2460559.75;
+  assert_eq!(lerp_jd(start, end, 0.0), start);
+  assert_eq!(lerp_jd(start, end, 1.0), end);
+  assert_eq!(lerp_jd(start, end, 0.5), 2460559.0);
+  assert_eq!(lerp_jd(start, end, -1.0), 2460556.75);
+  assert_eq!(fraction_between(2460559.0, start, end), Some(0.5));
+  assert_eq!(fraction_between(2460556.75, start, end), Some(-1.0));
+  assert_eq!(fraction_between(start, start, start), None);
+  assert_eq!(fraction_between(f64::NAN, start, end), None);
+  assert_eq!(fraction_between(start, -1e300, 1e300), None);
+  let t = fraction_between(2460558.6, start, end).unwrap();
+  assert!((lerp_jd(start, end, t) - 2460558.6).abs() < 1e-9);
+}
+
+#[test]
+fn test_julian_day_intervals() {
+  let week = JdInterval::new(2460557.5, 2460564.5).unwrap();
+  let weekend = JdInterval::new(2460562.5, 2460564.5).unwrap();
+  let next_week = JdInterval::new(2460564.5, 2460571.5).unwrap();
+  assert!(JdInterval::new(2460564.5, 2460557.5).is_none());
+  assert!(JdInterval::new(f64::NAN, 2460557.5).is_none());
+  assert!(JdInterval::new(-1e300, 2460557.5).is_none());
+  assert!(JdInterval::new(2460557.5, 2460557.5).unwrap().is_empty());
+  assert_eq!(week.duration_days(), 7.0);
+  assert!(week.contains(2460557.5) && !week.contains(2460564.5));
+  assert!(week.overlaps(&weekend));
+  assert!(!week.overlaps(&next_week));
+  assert_eq!(week.intersect(&weekend), Some(weekend));
+  assert_eq!(week.intersect(&next_week), None);
+  assert_eq!(week.union(&next_week), JdInterval::new(2460557.5, 2460571.5));
+  assert_eq!(weekend.union(&JdInterval::new(2460570.5, 2460571.5).unwrap()), None);
+  let days: Vec<f64> = weekend.into_iter().collect();
+  assert_eq!(days, vec![2460562.5, 2460563.5]);
+  assert_eq!(week.days().count(), 7);
+  assert_eq!(JdInterval::new(2460557.5, 2460558.0).unwrap().days().count(), 1);
+}
+
+#[test]
+fn test_julian_day_range_iterator() {
+  // a year of minutes lands exactly on the end rather than drifting
+  let start = 2460310.5;
+  let minutes = JdRangeIter::new(start, start + 366.0).step_duration(chrono::Duration::minutes(1));
+  assert_eq!(minutes.clone().count(), 366 * 1440);
+  assert_eq!(minutes.last(), Some(start + 366.0 - 1.0 / 1440.0));
+  let days: Vec<f64> = JdRangeIter::new(start, start + 3.0).collect();
+  assert_eq!(days, vec![start, start + 1.0, start + 2.0]);
+  let countdown: Vec<f64> = JdRangeIter::new(start, start - 1.0).step_days(-0.5).collect();
+  assert_eq!(countdown, vec![start, start - 0.5]);
+  assert_eq!(JdRangeIter::new(start, start + 1.0).step_days(0.0).count(), 0);
+  assert_eq!(JdRangeIter::new(start, start - 1.0).count(), 0);
+  let first = JdRangeIter::new(start, start + 1.0).step_days(1.0 / 3.0).datetimes().nth(1).unwrap();
+  assert_eq!(first.to_string(), "2024-01-01 08:00:00");
+}
+
+#[test]
+fn test_iso_interval_parsing() {
+  let start = datetime_to_julian_day("2024-01-31 09:00").unwrap();
+  let end = datetime_to_julian_day("2024-02-29 09:00").unwrap();
+  assert_eq!(iso_interval_to_jd_interval("2024-01-31T09:00:00Z/2024-02-29T09:00:00Z"), JdInterval::new(start, end));
+  // month arithmetic is clamped to the end of the shorter month
+  assert_eq!(iso_interval_to_jd_interval("2024-01-31T09:00/P1M"), JdInterval::new(start, end));
+  assert_eq!(iso_interval_to_jd_interval("P4W1D/2024-02-29T09:00"), JdInterval::new(start, end));
+  let quarter_hours = iso_interval_to_jd_interval("2024-01-31T09:00/PT0,25H").unwrap();
+  assert!(JdTolerance::MILLISECOND.approx_eq(quarter_hours.duration_days(), 1.0 / 96.0));
+  assert_eq!(iso_interval_to_jd_interval("P1Y2M/P1D"), None);
+  assert_eq!(iso_interval_to_jd_interval("2024-02-29/2024-01-31"), None);
+  assert_eq!(iso_interval_to_jd_interval("2024-01-31/-P1D"), None);
+  assert_eq!(iso_interval_to_jd_interval("2024-01-31/P1.5M"), None);
+  assert_eq!(iso_interval_to_jd_interval("2024-01-31/PT"), None);
+  assert_eq!(iso_interval_to_jd_interval("2024-01-31/P1D2Y"), None);
+  assert_eq!(iso_interval_to_jd_interval("2024-01-31"), None);
+}
+
+#[test]
+fn test_iso_duration_days() {
+  assert_eq!(iso_duration_to_days("P1DT6H"), Some(1.25));
+  assert_eq!(iso_duration_to_days("P2W"), Some(14.0));
+  assert_eq!(iso_duration_to_days("-PT12H"), Some(-0.5));
+  assert_eq!(iso_duration_to_days("PT1,5H"), Some(0.0625));
+  assert_eq!(iso_duration_to_days("P1Y2M"), None);
+  assert_eq!(iso_duration_to_days("1D"), None);
+  assert_eq!(iso_duration_to_days("P"), None);
+  assert_eq!(iso_duration_to_days_with_policy("P1D", MonthYearPolicy::Nominal), Some(1.0));
+  assert_eq!(iso_duration_to_days_with_policy("-P1Y6M", MonthYearPolicy::Nominal), Some(-1.5 * NOMINAL_YEAR_DAYS));
+  assert_eq!(iso_duration_to_days_with_policy("P1M", MonthYearPolicy::Reject), None);
+  assert_eq!(days_to_iso_duration(1.25), "P1DT6H");
+  assert_eq!(days_to_iso_duration(3.0), "P3D");
+  assert_eq!(days_to_iso_duration(-(1.0 + 1.0 / 1440.0)), "-P1DT1M");
+  assert_eq!(days_to_iso_duration(90.125 / 86400.0), "PT1M30.125S");
+  for days in [0.0, 0.75, 12.5 + 7.0 / 86400.0, -400.0] {
+    assert!(JdTolerance::MILLISECOND.approx_eq(iso_duration_to_days(&days_to_iso_duration(days)).unwrap(), days));
+  }
+}
+
+#[test]
+fn test_human_duration_parsing() {
+  assert_eq!(human_duration_to_days("3d 4h 30m"), Some(3.0 + 4.5 / 24.0));
+  assert_eq!(human_duration_to_days("3d4h30m"), human_duration_to_days("3 days 4 hours 30 minutes"));
+  assert_eq!(human_duration_to_days("1 Week"), Some(7.0));
+  assert_eq!(human_duration_to_days("90 secs"), Some(90.0 / 86400.0));
+  assert_eq!(human_duration_to_duration("1h 500ms"), Some(chrono::Duration::milliseconds(3_600_500)));
+  assert_eq!(human_duration_to_duration("0.5s"), Some(chrono::Duration::milliseconds(500)));
+  assert_eq!(human_duration_to_days("2 months"), None);
+  assert_eq!(human_duration_to_days("3"), None);
+  assert_eq!(human_duration_to_days("h"), None);
+  assert_eq!(human_duration_to_days(""), None);
+  // the parsed duration works directly with Julian Day arithmetic
+  let jd = JulianDayValue::new(2460558.25).unwrap() + human_duration_to_duration("6h").unwrap();
+  assert_eq!(jd.value(), 2460558.5);
+}
+
+#[test]
+fn test_relative_time_formatting() {
+  let reference_jd = datetime_to_julian_day("2024-09-04 12:00:00").unwrap();
+  let at = |dt: &str| datetime_to_julian_day(dt).unwrap();
+  assert_eq!(format_relative(at("2024-09-01 11:00:00"), reference_jd), "3 days ago");
+  assert_eq!(format_relative(at("2024-09-03 12:00:00"), reference_jd), "1 day ago");
+  assert_eq!(format_relative(at("2024-09-04 14:59:59"), reference_jd), "in 2 hours");
+  assert_eq!(format_relative(at("2024-09-04 12:01:00"), reference_jd), "in 1 minute");
+  assert_eq!(format_relative(reference_jd, reference_jd), "now");
+  assert_eq!(format_relative(reference_jd - 0.25 / 86400.0, reference_jd), "now");
+  assert_eq!(format_relative_with_granularity(reference_jd - 0.25 / 86400.0, reference_jd, TimeUnit::Millisecond), "250 milliseconds ago");
+  assert_eq!(format_relative_with_granularity(at("2024-09-04 17:00:00"), reference_jd, TimeUnit::Day), "now");
+  assert_eq!(format_relative_with_granularity(at("2024-09-14 17:00:00"), reference_jd, TimeUnit::Day), "in 10 days");
+}
+
+#[test]
+fn test_calendar_diff() {
+  let at = |dt: &str| datetime_to_julian_day(dt).unwrap();
+  assert_eq!(calendar_diff(at("2024-01-31"), at("2024-02-29")), Some((0, 1, 0, (0, 0, 0))));
+  assert_eq!(calendar_diff(at("2024-01-31"), at("2024-03-01")), Some((0, 1, 1, (0, 0, 0))));
+  assert_eq!(calendar_diff(at("2024-02-29"), at("2025-02-28")), Some((1, 0, 0, (0, 0, 0))));
+  assert_eq!(calendar_diff(at("2024-02-29"), at("2025-02-27")), Some((0, 11, 29, (0, 0, 0))));
+  // the time of day can take a day off the count
+  assert_eq!(calendar_diff(at("1990-06-15 12:00"), at("2024-06-15 11:59:59")), Some((33, 11, 30, (23, 59, 59))));
+  assert_eq!(calendar_diff(at("1990-06-15 12:00"), at("2024-06-15 12:00")), Some((34, 0, 0, (0, 0, 0))));
+  assert_eq!(calendar_diff(at("-0043-03-15"), at("0001-01-01")), Some((43, 9, 17, (0, 0, 0))));
+  assert_eq!(calendar_diff(at("2024-09-04"), at("2024-09-04")), Some((0, 0, 0, (0, 0, 0))));
+  assert_eq!(calendar_diff(at("2024-09-05"), at("2024-09-04")), None);
+  assert_eq!(calendar_diff(f64::NAN, at("2024-09-04")), None);
+  assert_eq!(calendar_diff(at("2024-09-04"), 1e300), None);
+}
+
+#[test]
+fn test_fuzzy_utc_offsets() {
+  let utc = NaiveDateTime::parse_from_str("2022-09-04 13:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+  for dt_str in ["2022-09-04T13:00:00Z", "2022-09-04 13:00 UTC", "2022-09-04T18:30:00+05:30", "2022-09-04 05:00 -0800", "2022-09-04T14+01", "2022-09-04 18:30:00.250+05:30", "2022-09-04 18:30 UTC+05:30"] {
+    assert_eq!(NaiveDateTime::from_fuzzy_iso_string(dt_str), Some(utc), "{}", dt_str);
+  }
+  // the offset can move the date
+  assert_eq!(datetime_to_julian_day("2022-09-04 20:00-05:00"), Ok(2459827.5 + 1.0 / 24.0));
+  // without a time a trailing number is still part of the date
+  assert_eq!(NaiveDateTime::from_fuzzy_iso_string("2022-09-04").unwrap().to_string(), "2022-09-04 00:00:00");
+  assert_eq!(NaiveDateTime::from_fuzzy_iso_string("-0043-03-15 12:00").unwrap().to_string(), "-0043-03-15 12:00:00");
+  assert_eq!(NaiveDateTime::from_fuzzy_iso_string("2022-09-04 13:00 +25:00"), None);
+  assert_eq!(parse_utc_offset("+0530"), Some(19800));
+  assert_eq!(parse_utc_offset("-03"), Some(-10800));
+  assert_eq!(parse_utc_offset("05:30"), None);
+  assert_eq!(parse_utc_offset("+5:30"), None);
+}
+
+#[test]
+fn test_fuzzy_twelve_hour_clock() {
+  let parse = |dt_str: &str| NaiveDateTime::from_fuzzy_iso_string(dt_str).map(|dt| dt.to_string());
+  assert_eq!(parse("2022-09-04 6:30 PM"), Some("2022-09-04 18:30:00".to_string()));
+  assert_eq!(parse("2022-09-04 6.30pm"), Some("2022-09-04 18:30:00".to_string()));
+  assert_eq!(parse("2022-09-04T06:30:15 a.m."), Some("2022-09-04 06:30:15".to_string()));
+  assert_eq!(parse("2022-09-04 11:59:59.5 pm"), Some("2022-09-04 23:59:59".to_string()));
+  assert_eq!(parse("2022-09-04 9am"), Some("2022-09-04 09:00:00".to_string()));
+  // midnight and noon
+  assert_eq!(parse("2022-09-04 12 AM"), Some("2022-09-04 00:00:00".to_string()));
+  assert_eq!(parse("2022-09-04 12:30 am"), Some("2022-09-04 00:30:00".to_string()));
+  assert_eq!(parse("2022-09-04 12 PM"), Some("2022-09-04 12:00:00".to_string()));
+  assert_eq!(parse("2022-09-04 6:30 PM +05:30"), Some("2022-09-04 13:00:00".to_string()));
+  assert_eq!(parse("2022-09-04 0:30 am"), None);
+  assert_eq!(parse("2022-09-04 13:30 pm"), None);
+}
+
+#[test]
+fn test_fuzzy_month_names() {
+  let parse = |dt_str: &str| NaiveDateTime::from_fuzzy_iso_string(dt_str).map(|dt| dt.to_string());
+  let expected = Some("2022-09-04 00:00:00".to_string());
+  for dt_str in ["4 Sep 2022", "September 4th, 2022", "04-SEP-2022", "Sunday, 4 September 2022", "Sept. 4 2022", "2022 Sep 4", "4/sep/2022"] {
+    assert_eq!(parse(dt_str), expected, "{}", dt_str);
+  }
+  assert_eq!(parse("Sep 4 2022 6:30 pm"), Some("2022-09-04 18:30:00".to_string()));
+  assert_eq!(parse("Sun, 04 Sep 2022 18:30:00 +0200"), Some("2022-09-04 16:30:00".to_string()));
+  assert_eq!(parse("March 2022"), Some("2022-03-01 00:00:00".to_string()));
+  assert_eq!(parse("1st March 0044 BC"), Some("-0043-03-01 00:00:00".to_string()));
+  assert_eq!(parse("31 Sep 2022"), None);
+  assert_eq!(parse("4 Sep"), None);
+  assert_eq!(parse_month_name("may", &LocaleNames::ENGLISH), Some(5));
+  assert_eq!(parse_month_name("juillet", &LocaleNames::FRENCH), Some(7));
+  assert_eq!(parse_month_name("Ma", &LocaleNames::ENGLISH), None);
+  assert_eq!(parse_month_name("Sunday", &LocaleNames::ENGLISH), None);
+}
+
+#[test]
+fn test_fuzzy_slash_date_order() {
+  let parse = |dt_str: &str, options: FuzzyParseOptions| iso_fuzzy_string_to_datetime_with(dt_str, &options).ok().map(|dt| dt.to_string());
+  let no_hint = FuzzyParseOptions::default();
+  assert_eq!(parse("25/12/2022", no_hint), Some("2022-12-25 00:00:00".to_string()));
+  assert_eq!(parse("12/25/2022 18:30", no_hint), Some("2022-12-25 18:30:00".to_string()));
+  assert_eq!(parse("2022/12/25", no_hint), Some("2022-12-25 00:00:00".to_string()));
+  assert_eq!(parse("07/07/2022", no_hint), Some("2022-07-07 00:00:00".to_string()));
+  assert_eq!(parse("04/11/1877", no_hint), None);
+  let dmy = FuzzyParseOptions::default().with_date_order(DateOrder::DMY);
+  let mdy = FuzzyParseOptions::default().with_date_order(DateOrder::MDY);
+  assert_eq!(parse("04/11/1877", dmy), Some("1877-11-04 00:00:00".to_string()));
+  assert_eq!(parse("04/11/1877 6pm", mdy), Some("1877-04-11 18:00:00".to_string()));
+  // the hint is applied even when it makes the date invalid
+  assert_eq!(parse("25/12/2022", mdy), None);
+  assert_eq!(NaiveDateTime::from_fuzzy_iso_string("ROC 113/09/04").unwrap().to_string(), "2024-09-04 00:00:00");
+}
+
+#[test]
+fn test_fuzzy_relative_keywords() {
+  let options = FuzzyParseOptions::default().with_reference_jd(2460558.25); // 2024-09-04 18:00 UTC
+  let parse = |dt_str: &str| iso_fuzzy_string_to_datetime_with(dt_str, &options).ok().map(|dt| dt.to_string());
+  assert_eq!(parse("now"), Some("2024-09-04 18:00:00".to_string()));
+  assert_eq!(parse("Today"), Some("2024-09-04 00:00:00".to_string()));
+  assert_eq!(parse("yesterday"), Some("2024-09-03 00:00:00".to_string()));
+  assert_eq!(parse("tomorrow"), Some("2024-09-05 00:00:00".to_string()));
+  assert_eq!(parse("+3 days"), Some("2024-09-07 18:00:00".to_string()));
+  assert_eq!(parse("-2 weeks"), Some("2024-08-21 18:00:00".to_string()));
+  assert_eq!(parse("in 6h 30m"), Some("2024-09-05 00:30:00".to_string()));
+  assert_eq!(parse("2 days ago"), Some("2024-09-02 18:00:00".to_string()));
+  assert_eq!(parse("+3 months"), None);
+  assert_eq!(parse("-0043-03-15"), Some("-0043-03-15 00:00:00".to_string()));
+  // an out-of-range reference Julian Day keeps the earlier reference
+  assert_eq!(options.with_reference_jd(f64::NAN).reference, options.reference);
+  assert_eq!(FuzzyParseOptions::default().with_reference_jd(1e12).reference, None);
+  // without a reference the current time is used
+  let now_jd = datetime_to_julian_day("now").unwrap();
+  assert!(now_jd > 2460558.25);
+}
+
+#[test]
+fn test_fuzzy_partial_date_policy() {
+  let parse = |dt_str: &str, policy: PartialDatePolicy| {
+    iso_fuzzy_string_to_datetime_with(dt_str, &FuzzyParseOptions::default().with_partial_date(policy)).ok().map(|dt| dt.to_string())
+  };
+  assert_eq!(parse("1999", PartialDatePolicy::StartOf), Some("1999-01-01 00:00:00".to_string()));
+  assert_eq!(parse("1999", PartialDatePolicy::MiddleOf), Some("1999-07-02 12:00:00".to_string()));
+  assert_eq!(parse("1999", PartialDatePolicy::EndOf), Some("1999-12-31 23:59:59".to_string()));
+  assert_eq!(parse("2024-02", PartialDatePolicy::MiddleOf), Some("2024-02-15 12:00:00".to_string()));
+  assert_eq!(parse("2024-02", PartialDatePolicy::EndOf), Some("2024-02-29 23:59:59".to_string()));
+  assert_eq!(parse("June 1999", PartialDatePolicy::EndOf), Some("1999-06-30 23:59:59".to_string()));
+  assert_eq!(parse("-0043", PartialDatePolicy::EndOf), Some("-0043-12-31 23:59:59".to_string()));
+  // full dates are not affected
+  assert_eq!(parse("1999-06-15", PartialDatePolicy::EndOf), Some("1999-06-15 00:00:00".to_string()));
+  assert_eq!(iso_fuzzy_string_to_datetime("1999").unwrap().to_string(), "1999-01-01 00:00:00");
+}
+
+#[test]
+fn test_fuzzy_two_digit_year_window() {
+  let parse = |dt_str: &str, options: FuzzyParseOptions| iso_fuzzy_string_to_datetime_with(dt_str, &options).ok().map(|dt| dt.to_string());
+  let default = FuzzyParseOptions::default();
+  assert_eq!(parse("24-05-07", default), Some("2024-05-07 00:00:00".to_string()));
+  assert_eq!(parse("70-05-07 18:20", default), Some("1970-05-07 18:20:00".to_string()));
+  assert_eq!(parse("69-05", default), Some("2069-05-01 00:00:00".to_string()));
+  assert_eq!(parse("4 Sep 22", default), Some("2022-09-04 00:00:00".to_string()));
+  assert_eq!(parse("12/25/99", default), Some("1999-12-25 00:00:00".to_string()));
+  // four-digit and astronomical years are not changed
+  assert_eq!(parse("0024-05-07", default), Some("0024-05-07 00:00:00".to_string()));
+  assert_eq!(parse("-24-05-07", default), Some("-0024-05-07 00:00:00".to_string()));
+  assert_eq!(parse("24-05-07", default.with_two_digit_year_pivot(Some(1900))), Some("1924-05-07 00:00:00".to_string()));
+  assert_eq!(parse("24-05-07", default.with_two_digit_year_pivot(None)), Some("0024-05-07 00:00:00".to_string()));
+  assert_eq!(expand_two_digit_year(69, 1970), 2069);
+  assert_eq!(expand_two_digit_year(0, 1999), 2000);
+}
+
+#[test]
+fn test_two_digit_years_in_calendar_parsers() {
+  // the two-digit year window only applies to Gregorian fuzzy parsing
+  assert_eq!(era_string_to_julian_day("13-09-04", YearNumbering::Minguo), era_string_to_julian_day("1924-09-04", YearNumbering::CommonEra));
+  assert_eq!(ProlepticJulian.parse_julian_day("44-03-15"), ProlepticJulian.date_to_julian_day(44, 3, 15));
+  assert_eq!(TabularIslamic::default().parse_julian_day("45-01-01"), TabularIslamic::default().date_to_julian_day(45, 1, 1));
+  assert_eq!(GregorianReform::ROME.parse("44-03-15").ok(), GregorianReform::ROME.date_to_julian_day(44, 3, 15).ok());
+  assert!(ProlepticJulian.parse_julian_day("44-03-15").unwrap() < 1800000.0);
+}
+
+#[test]
+fn test_fuzzy_tz_abbreviations() {
+  let options = FuzzyParseOptions::default().with_tz_abbreviations(TzAbbreviationTable::COMMON);
+  let parse = |dt_str: &str, options: &FuzzyParseOptions| iso_fuzzy_string_to_datetime_with(dt_str, options).ok().map(|dt| dt.to_string());
+  assert_eq!(parse("2024-09-04 14:00 EDT", &options), Some("2024-09-04 18:00:00".to_string()));
+  assert_eq!(parse("Wed, 04 Sep 2024 20:00:00 CEST", &options), Some("2024-09-04 18:00:00".to_string()));
+  assert_eq!(parse("2024-09-04 18:00 gmt", &options), Some("2024-09-04 18:00:00".to_string()));
+  // ambiguous abbreviations are rejected rather than guessed
+  assert_eq!(parse("2024-09-04 23:30 IST", &options), None);
+  const INDIA: TzAbbreviationTable = TzAbbreviationTable { entries: &[("IST", 19800)] };
+  assert_eq!(parse("2024-09-04 23:30 IST", &options.with_tz_abbreviations(INDIA)), Some("2024-09-04 18:00:00".to_string()));
+  // without a table only the names of UTC are read, and other abbreviations are rejected rather than treated as UTC
+  let default = FuzzyParseOptions::default();
+  assert_eq!(parse("2024-09-04 18:00 GMT", &default), Some("2024-09-04 18:00:00".to_string()));
+  assert_eq!(parse("2023-11-15 17:53 EST", &default), None);
+  assert_eq!(parse("2023-11-15 17:53 PST", &default), None);
+  assert_eq!(parse("2024-09-04 14:00 XYZ", &options), None);
+  // 12-hour clock and era labels are not abbreviations
+  assert_eq!(parse("2024-09-04 6:30 PM", &options), Some("2024-09-04 18:30:00".to_string()));
+  assert_eq!(parse("0044-03-15 12:00 BC", &default), Some("-0043-03-15 12:00:00".to_string()));
+  assert_eq!(TzAbbreviationTable::COMMON.lookup("XYZ"), Err(TzAbbreviationError::Unknown));
+  assert_eq!(TzAbbreviationTable::COMMON.lookup("cst"), Err(TzAbbreviationError::Ambiguous));
+  assert_eq!(TzAbbreviationTable::COMMON.lookup("JST"), Ok(32400));
+}
+
+#[test]
+fn test_fuzzy_epoch_strings() {
+  assert_eq!(datetime_to_julian_day("@1662314400"), Ok(2459827.25));
+  assert_eq!(datetime_to_julian_day("1662314400"), Ok(2459827.25));
+  assert_eq!(datetime_to_julian_day("1662310800500"), Ok(unix_millis_to_julian_day(1_662_310_800_500)));
+  assert_eq!(datetime_to_julian_day("@-86400"), Ok(JULIAN_DAY_UNIX_EPOCH_DAYS - 1.0));
+  assert_eq!(iso_fuzzy_string_to_datetime("@1662310800.25").unwrap().to_string(), "2022-09-04 17:00:00.250");
+  assert_eq!(epoch_string_to_julian_day("@-0.5"), Some(unix_millis_to_julian_day(-500)));
+  assert_eq!(epoch_string_to_julian_day("166231080"), None);
+  assert_eq!(epoch_string_to_julian_day("@abc"), None);
+  assert!(iso_fuzzy_string_to_datetime("@99999999999999999").is_err());
+  // shorter numbers are still years
+  assert_eq!(iso_fuzzy_string_to_datetime("2022").unwrap().to_string(), "2022-01-01 00:00:00");
+}
+
+#[test]
+fn test_fuzzy_string_to_julian_day() {
+  assert_eq!(fuzzy_string_to_julian_day("2022-09-04T18:00:00Z"), Ok(2459827.25));
+  assert_eq!(fuzzy_string_to_julian_day("Sunday, 4 September 2022 20:00 +02:00"), Ok(2459827.25));
+  assert_eq!(fuzzy_string_to_julian_day("@1662314400.5"), Ok(unix_millis_to_julian_day(1_662_314_400_500)));
+  assert_eq!(fuzzy_string_to_julian_day("2022-13-01"), Err(JulianDayError::OutOfRange));
+  assert_eq!(fuzzy_string_to_julian_day(""), Err(JulianDayError::InvalidFormat));
+  assert_eq!(checked_datetime_to_julian_day("2022-09-04 18:00"), Ok(2459827.25));
+  let options = FuzzyParseOptions::default().with_date_order(DateOrder::MDY);
+  assert_eq!(fuzzy_string_to_julian_day_with("09/04/2022 18:00", &options), Ok(2459827.25));
+  assert_eq!(fuzzy_string_to_julian_day("09/04/2022 18:00"), Err(JulianDayError::OutOfRange));
+}
+
+#[test]
+fn test_rfc3339_and_rfc2822() {
+  assert_eq!(rfc3339_to_julian_day("2022-09-04T18:00:00Z"), Ok(2459827.25));
+  assert_eq!(rfc3339_to_julian_day("2022-09-04T12:30:00.5-05:30"), Ok(unix_millis_to_julian_day(1_662_314_400_500)));
+  assert_eq!(rfc3339_to_julian_day("2022-09-04T18:00:00"), Err(JulianDayError::InvalidFormat));
+  assert_eq!(rfc2822_to_julian_day("Sun, 4 Sep 2022 13:00:00 -0500"), Ok(2459827.25));
+  assert_eq!(rfc2822_to_julian_day("4 Sep 2022 18:00:00 GMT"), Ok(2459827.25));
+  assert_eq!(rfc2822_to_julian_day("2022-09-04T18:00:00Z"), Err(JulianDayError::InvalidFormat));
+  let jd = unix_millis_to_julian_day(1_662_314_400_250);
+  let rfc3339 = julian_day_to_rfc3339(jd, -18000).unwrap();
+  assert_eq!(rfc3339, "2022-09-04T13:00:00.250-05:00");
+  assert_eq!(rfc3339_to_julian_day(&rfc3339), Ok(jd));
+  let rfc2822 = julian_day_to_rfc2822(2459827.25, 0).unwrap();
+  assert_eq!(rfc2822, "Sun, 4 Sep 2022 18:00:00 +0000");
+  assert_eq!(rfc2822_to_julian_day(&rfc2822), Ok(2459827.25));
+  assert_eq!(julian_day_to_rfc2822(0.0, 0), None);
+  assert_eq!(julian_day_to_rfc3339(f64::NAN, 0), None);
+  assert_eq!(julian_day_to_rfc3339(2459827.25, 86400), None);
+}
+
+#[test]
+fn test_http_dates() {
+  let jd = datetime_to_julian_day("1994-11-06 08:49:37").unwrap();
+  assert_eq!(http_date_to_julian_day("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(jd));
+  assert_eq!(http_date_to_julian_day("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(jd));
+  assert_eq!(http_date_to_julian_day("Sun Nov  6 08:49:37 1994"), Ok(jd));
+  assert_eq!(http_date_to_julian_day("Sun Nov 6 08:49:37 1994"), Ok(jd));
+  assert_eq!(julian_day_to_http_date(jd), Some("Sun, 06 Nov 1994 08:49:37 GMT".to_string()));
+  assert_eq!(http_date_to_julian_day(&julian_day_to_http_date(jd).unwrap()), Ok(jd));
+  // a weekday that does not match the date is rejected
+  assert_eq!(http_date_to_julian_day("Mon, 06 Nov 1994 08:49:37 GMT"), Err(JulianDayError::InvalidFormat));
+  assert_eq!(http_date_to_julian_day("Sun, 06 Nov 1994 08:49:37 +0000"), Err(JulianDayError::InvalidFormat));
+  // age of a resource in days
+  let age = days_between(http_date_to_julian_day("Wed, 04 Sep 2024 06:00:00 GMT").unwrap(), 2460558.25);
+  assert_eq!(age, 0.5);
+  assert_eq!(julian_day_to_http_date(0.0), None);
+}
+
+#[test]
+fn test_jd_literal_strings() {
+  assert_eq!(jd_literal_to_julian_day("2459827.25"), Ok(2459827.25));
+  assert_eq!(jd_literal_to_julian_day(" jd:2459827.25 "), Ok(2459827.25));
+  assert_eq!(jd_literal_to_julian_day("JD=-0.5"), Ok(-0.5));
+  assert_eq!(jd_literal_to_julian_day("MJD 59826.75"), Ok(2459827.25));
+  assert_eq!(jd_literal_to_julian_day("mjd 0"), Ok(MODIFIED_JULIAN_DAY_OFFSET));
+  assert_eq!(jd_literal_to_julian_day("TJD 19826.75"), Ok(2459827.25));
+  assert_eq!(jd_literal_to_julian_day("RJD 59827.25"), Ok(2459827.25));
+  assert_eq!(jd_literal_to_julian_day("JD"), Err(JulianDayError::InvalidFormat));
+  assert_eq!(jd_literal_to_julian_day("JD inf"), Err(JulianDayError::InvalidFormat));
+  assert_eq!(jd_literal_to_julian_day("2022-09-04"), Err(JulianDayError::InvalidFormat));
+  assert_eq!(jd_literal_to_julian_day("1.2.3"), Err(JulianDayError::InvalidFormat));
+  assert_eq!(jd_literal_to_julian_day("XJD 1"), Err(JulianDayError::InvalidFormat));
+}
+
+#[test]
+fn test_julian_day_value_notations() {
+  let jd = JulianDayValue::new(2459827.25).unwrap();
+  for (input, notation) in [
+    ("2459827.25", JdNotation::JdLiteral),
+    ("JD 2459827.25", JdNotation::JdLiteral),
+    ("MJD 59826.75", JdNotation::JdLiteral),
+    ("2022-09-04T18:00:00Z", JdNotation::IsoDateTime),
+    ("4 Sep 2022 8pm +02:00", JdNotation::IsoDateTime),
+    ("@1662314400", JdNotation::UnixTime),
+  ] {
+    assert_eq!(JulianDayValue::parse_with_notation(input), Some((jd, notation)), "{}", input);
+    assert_eq!(input.parse::<JulianDayValue>(), Ok(jd));
+  }
+  // bare numbers are read as Julian Days unless they are beyond the supported range
+  assert_eq!(JulianDayValue::parse_with_notation("2460558").map(|(_, notation)| notation), Some(JdNotation::JdLiteral));
+  assert_eq!(JulianDayValue::parse_with_notation("1662314400").map(|(_, notation)| notation), Some(JdNotation::UnixTime));
+  assert_eq!(JulianDayValue::parse_with_notation("next week"), None);
+  assert_eq!("".parse::<JulianDayValue>(), Err(JulianDayError::InvalidFormat));
+}
+
+#[test]
+fn test_parse_with_formats() {
+  let formats = ["%d/%m/%Y %H:%M", "%Y%m%d%H%M%S", "%d.%m.%Y", "%Y-%m-%d %H:%M %z"];
+  assert_eq!(parse_with_formats("04/09/2022 18:00", &formats), Ok(2459827.25));
+  assert_eq!(parse_with_formats("20220904180000", &formats), Ok(2459827.25));
+  assert_eq!(parse_with_formats("04.09.2022", &formats), Ok(2459826.5));
+  assert_eq!(parse_with_formats("2022-09-04 20:00 +0200", &formats), Ok(2459827.25));
+  // the first matching format wins over the fuzzy parser's reading
+  assert_eq!(parse_with_formats("04/09/2022 18:00", &["%m/%d/%Y %H:%M"]), Ok(2459679.25));
+  assert_eq!(parse_with_formats("Sep 4 2022 6pm", &formats), Ok(2459827.25));
+  assert_eq!(parse_with_formats("31/02/2022 18:00", &formats), Err(JulianDayError::OutOfRange));
+  assert_eq!(parse_with_formats("someday", &formats), Err(JulianDayError::InvalidFormat));
+  assert_eq!(parse_with_formats("2022-09-04 18:00", &[]), Ok(2459827.25));
+}
+
+#[test]
+fn test_fuzzy_parse_errors() {
+  let options = FuzzyParseOptions::default();
+  let error = |dt_str: &str, options: &FuzzyParseOptions| {
+    let error = parse_fuzzy_datetime(dt_str, options).unwrap_err();
+    (error.position, error.token, error.expected)
+  };
+  assert_eq!(error("2022-09-31", &options), (8, "31".to_string(), "a day that exists in the month"));
+  assert_eq!(error("2022-09-04T25:00", &options), (11, "25".to_string(), "an hour from 0 to 23"));
+  assert_eq!(error("2022-09-04 18:61:00", &options), (14, "61".to_string(), "a minute from 0 to 59"));
+  // chrono accepts a leap second as second 60
+  assert!(parse_fuzzy_datetime("2022-09-04 18:00:60", &options).is_ok());
+  assert_eq!(error("2022-09-04 18:00:61", &options), (17, "61".to_string(), "a second from 0 to 60"));
+  assert_eq!(error("2022-09-04 18:00+25:00", &options), (16, "+25:00".to_string(), "a UTC offset from -23:59 to +23:59"));
+  assert_eq!(error("31 Feb 2023", &options), (0, "31".to_string(), "a day that exists in the month"));
+  assert_eq!(error("  04/11/1877", &options), (2, "04/11/1877".to_string(), "a date order for a day and month that could be swapped"));
+  assert_eq!(error("soon", &options), (0, "soon".to_string(), "a year"));
+  assert_eq!(error("", &options), (0, "".to_string(), "a date"));
+  let tz_options = options.with_tz_abbreviations(TzAbbreviationTable::COMMON);
+  assert_eq!(error("2024-09-04 23:30 IST", &tz_options), (17, "IST".to_string(), "an unambiguous timezone abbreviation"));
+  assert_eq!(error("2022-09-04 17:53 XYZ", &options), (17, "XYZ".to_string(), "a timezone abbreviation in the table set with with_tz_abbreviations()"));
+  assert_eq!(parse_fuzzy_datetime("2022-09-04 18:00", &options).unwrap().to_string(), "2022-09-04 18:00:00");
+}
+
+#[test]
+fn test_parse_candidates() {
+  let options = FuzzyParseOptions::default();
+  let readings = |dt_str: &str| -> Vec<(f64, Option<DateOrder>)> {
+    parse_candidates(dt_str, &options).iter().map(|candidate| (candidate.jd, candidate.date_order)).collect()
+  };
+  assert_eq!(readings("04/09/2022 18:00"), vec![(2459827.25, Some(DateOrder::DMY)), (2459679.25, Some(DateOrder::MDY))]);
+  // only one order gives a valid date
+  assert_eq!(readings("25/12/2022"), vec![(2459938.5, Some(DateOrder::DMY))]);
+  // the same date in either order is a single candidate
+  assert_eq!(readings("07/07/2022"), vec![(2459767.5, Some(DateOrder::DMY))]);
+  // a two-digit year first is also a reading
+  assert_eq!(readings("12/11/10").len(), 3);
+  // slash dates with a Buddhist Era label are read in each order too
+  assert_eq!(readings("พ.ศ. 04/09/2567"), vec![(2460557.5, Some(DateOrder::DMY)), (2460409.5, Some(DateOrder::MDY))]);
+  assert_eq!(readings("2022-09-04 18:00"), vec![(2459827.25, None)]);
+  assert_eq!(readings("4 Sep 2022 6pm"), vec![(2459827.25, None)]);
+  assert!(readings("someday").is_empty());
+}
+
+#[test]
+fn test_fuzzy_parse_report() {
+  let options = FuzzyParseOptions::default();
+  let report = |dt_str: &str| parse_fuzzy_datetime_report(dt_str, &options).unwrap();
+  let exact = report("2022-09-04T20:00:00+02:00");
+  assert_eq!(exact.normalized, "2022-09-04T20:00:00+02:00");
+  assert_eq!(exact.offset_secs, Some(7200));
+  assert_eq!(exact.jd, unix_millis_to_julian_day(1_662_314_400_000));
+  assert!(exact.flags.is_empty());
+  let local = report("2022-09-04 18:00");
+  assert_eq!(local.normalized, "2022-09-04T18:00:00Z");
+  assert_eq!(local.flags, ParseFlags::ASSUMED_UTC);
+  assert_eq!(report("4 Sep 2022").flags, ParseFlags::ASSUMED_UTC | ParseFlags::ASSUMED_START_OF_DAY);
+  assert_eq!(report("2022-09-04 00:00Z").flags, ParseFlags::NONE);
+  assert_eq!(report("1999-06").flags, ParseFlags::ASSUMED_UTC | ParseFlags::COMPLETED_PARTIAL_DATE);
+  assert_eq!(report("12/25/2022 10:00 -05:00").flags, ParseFlags::GUESSED_MONTH_ORDER);
+  let with_order = parse_fuzzy_datetime_report("12/25/2022 10:00", &options.with_date_order(DateOrder::MDY)).unwrap();
+  assert_eq!(with_order.flags, ParseFlags::ASSUMED_UTC);
+  assert_eq!(report("22-09-04 18:00Z").flags, ParseFlags::EXPANDED_TWO_DIGIT_YEAR);
+  assert_eq!(report("@1662314400").flags, ParseFlags::NONE);
+  assert_eq!(report("@1662314400").normalized, "2022-09-04T18:00:00Z");
+  assert_eq!(ParseFlags::GUESSED_MONTH_ORDER.bits(), 4);
+  assert!(parse_fuzzy_datetime_report("2022-13-01", &options).is_err());
+}
+
+#[test]
+fn test_fuzzy_excel_serial_hint() {
+  let options = FuzzyParseOptions::default().with_numeric_hint(NumericHint::ExcelSerial);
+  let dt = iso_fuzzy_string_to_datetime_with("44808.75", &options).unwrap();
+  assert_eq!(dt.to_string(), "2022-09-04 18:00:00");
+  assert_eq!(fuzzy_string_to_julian_day_with(" 45658 ", &options), Ok(2460676.5));
+  assert_eq!(fuzzy_string_to_julian_day_with("59", &options), Ok(2415078.5));
+  assert_eq!(fuzzy_string_to_julian_day_with("60", &options), Err(JulianDayError::OutOfRange));
+  // outside 1 to 80000 and other strings keep their usual reading
+  assert_eq!(fuzzy_string_to_julian_day_with("1662314400", &options), Ok(2459827.25));
+  assert_eq!(fuzzy_string_to_julian_day_with("2022-09-04 18:00", &options), Ok(2459827.25));
+  assert!(fuzzy_string_to_julian_day_with("80001", &options).is_err());
+  assert!(fuzzy_string_to_julian_day("44808.75").is_err());
+  let report = parse_fuzzy_datetime_report("44808.75", &options).unwrap();
+  assert!(report.flags.is_empty());
+  for serial in [1.0, 59.5, 61.0, 44808.75] {
+    assert_eq!(excel_serial_to_julian_day(serial).and_then(julian_day_to_excel_serial), Some(serial));
+  }
+  assert_eq!(julian_day_to_excel_serial(2415018.5), None);
+  assert_eq!(julian_day_to_excel_serial(1e300), None);
+}